@@ -106,6 +106,17 @@ impl CrawlerError {
             ErrorKind::MultipleParseError { key, json, .. } => (json.to_string(), key),
         }
     }
+    /// Return the key (JSON pointer notation) at the location of the error.
+    pub fn key(&self) -> &str {
+        match self.inner.as_ref() {
+            ErrorKind::Navigation { key, .. }
+            | ErrorKind::Parsing { key, .. }
+            | ErrorKind::PathNotFoundInArray { key, .. }
+            | ErrorKind::PathsNotFound { key, .. }
+            | ErrorKind::ArraySize { key, .. }
+            | ErrorKind::MultipleParseError { key, .. } => key,
+        }
+    }
     pub(crate) fn multiple_parse_error(
         key: impl Into<String>,
         json: Arc<String>,
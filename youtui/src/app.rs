@@ -312,8 +312,9 @@ async fn init_tracing(debug: bool, logging: bool) -> Result<()> {
     } else {
         (tracing::Level::INFO, tui_logger::LevelFilter::Info)
     };
-    let context_layer =
-        tracing_subscriber::filter::Targets::new().with_target("youtui", tracing_log_level);
+    let context_layer = tracing_subscriber::filter::Targets::new()
+        .with_target("youtui", tracing_log_level)
+        .with_target("ytmapi_rs", tracing_log_level);
     if logging {
         let (log_file, log_file_name) = get_limited_sequential_file(
             &get_data_dir()?,
@@ -333,8 +334,9 @@ async fn init_tracing(debug: bool, logging: bool) -> Result<()> {
             .init();
         info!("Logging to {:?}.", log_file_name);
     } else {
-        let context_layer =
-            tracing_subscriber::filter::Targets::new().with_target("youtui", tracing_log_level);
+        let context_layer = tracing_subscriber::filter::Targets::new()
+            .with_target("youtui", tracing_log_level)
+            .with_target("ytmapi_rs", tracing_log_level);
         tracing_subscriber::registry()
             .with(tui_logger_layer)
             .with(context_layer)
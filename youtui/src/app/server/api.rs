@@ -189,7 +189,7 @@ pub async fn get_search_suggestions(
     tracing::info!("Getting search suggestions for {text}");
     let query = ytmapi_rs::query::GetSearchSuggestionsQuery::new(&text);
     let results = query_api_with_retry(&api, query).await?;
-    Ok((results, text))
+    Ok((results.suggestions, text))
 }
 
 pub enum GetArtistSongsProgressUpdate {
@@ -14,7 +14,7 @@ use ytmapi_rs::common::{
 use ytmapi_rs::parse::{
     AlbumSong, ParsedSongAlbum, ParsedSongArtist, ParsedUploadArtist, ParsedUploadSongAlbum,
     PlaylistEpisode, PlaylistItem, PlaylistSong, PlaylistUploadSong, PlaylistVideo,
-    SearchResultSong,
+    SearchResultSong, parse_count, parse_duration,
 };
 
 pub trait SongListComponent {
@@ -228,6 +228,24 @@ impl ListSong {
     ) -> [Cow<'_, str>; N] {
         fields.map(|field| self.get_field(field))
     }
+    /// Compare the field of two songs. Numeric fields are compared by value
+    /// where both can be parsed, otherwise fields are compared as strings.
+    pub fn cmp_field(&self, other: &Self, field: ListSongDisplayableField) -> std::cmp::Ordering {
+        let numeric = match field {
+            ListSongDisplayableField::Plays => parse_count(&self.plays)
+                .zip(parse_count(&other.plays))
+                .map(|(a, b)| a.cmp(&b)),
+            ListSongDisplayableField::Duration => parse_duration(&self.duration_string)
+                .zip(parse_duration(&other.duration_string))
+                .map(|(a, b)| a.cmp(&b)),
+            _ => None,
+        };
+        numeric.unwrap_or_else(|| {
+            self.get_field(field)
+                .partial_cmp(&other.get_field(field))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+    }
     pub fn get_field(&self, field: ListSongDisplayableField) -> Cow<'_, str> {
         match field {
             ListSongDisplayableField::DownloadStatus =>
@@ -295,14 +313,8 @@ impl BrowserSongsList {
     }
     pub fn sort(&mut self, field: ListSongDisplayableField, direction: SortDirection) {
         self.list.sort_by(|a, b| match direction {
-            SortDirection::Asc => a
-                .get_field(field)
-                .partial_cmp(&b.get_field(field))
-                .unwrap_or(std::cmp::Ordering::Equal),
-            SortDirection::Desc => b
-                .get_field(field)
-                .partial_cmp(&a.get_field(field))
-                .unwrap_or(std::cmp::Ordering::Equal),
+            SortDirection::Asc => a.cmp_field(b, field),
+            SortDirection::Desc => b.cmp_field(a, field),
         });
     }
     pub fn clear(&mut self) {
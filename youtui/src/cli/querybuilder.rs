@@ -7,7 +7,9 @@ use ytmapi_rs::auth::noauth::NoAuthToken;
 use ytmapi_rs::auth::{BrowserToken, OAuthToken};
 use ytmapi_rs::common::{
     AlbumID, ArtistChannelID, BrowseParams, EpisodeID, FeedbackTokenAddToLibrary,
-    FeedbackTokenRemoveFromHistory, LikeStatus, LyricsID, MoodCategoryParams, PlaylistID,
+    FeedbackTokenMarkPlayed, FeedbackTokenMarkUnplayed, FeedbackTokenPinToListenAgain,
+    FeedbackTokenRemoveFromHistory, FeedbackTokenRemoveSearchSuggestion,
+    FeedbackTokenUnpinFromListenAgain, LikeStatus, LyricsID, MoodCategoryParams, PlaylistID,
     PodcastChannelID, PodcastChannelParams, PodcastID, SetVideoID, SongTrackingUrl, TasteToken,
     TasteTokenImpression, TasteTokenSelection, UploadAlbumID, UploadArtistID, UploadEntityID,
     UserChannelID, UserPlaylistsParams, UserVideosParams, VideoID, YoutubeID,
@@ -22,20 +24,24 @@ use ytmapi_rs::query::search::{
     AlbumsFilter, ArtistsFilter, CommunityPlaylistsFilter, EpisodesFilter, FeaturedPlaylistsFilter,
     PlaylistsFilter, PodcastsFilter, ProfilesFilter, SongsFilter, VideosFilter,
 };
-use ytmapi_rs::query::song::GetSongTrackingUrlQuery;
+use ytmapi_rs::query::song::{GetSongQuery, GetSongTrackingUrlQuery};
 use ytmapi_rs::query::{
     AddHistoryItemQuery, AddPlaylistItemsQuery, CreatePlaylistQuery, DeletePlaylistQuery,
-    DeleteUploadEntityQuery, EditPlaylistQuery, EditSongLibraryStatusQuery, GetAlbumQuery,
-    GetArtistAlbumsQuery, GetArtistQuery, GetChannelEpisodesQuery, GetChannelQuery,
-    GetContinuationsQuery, GetEpisodeQuery, GetHistoryQuery, GetLibraryAlbumsQuery,
-    GetLibraryArtistSubscriptionsQuery, GetLibraryArtistsQuery, GetLibraryPlaylistsQuery,
-    GetLibrarySongsQuery, GetLibraryUploadAlbumQuery, GetLibraryUploadAlbumsQuery,
-    GetLibraryUploadArtistQuery, GetLibraryUploadArtistsQuery, GetLibraryUploadSongsQuery,
-    GetLyricsIDQuery, GetLyricsQuery, GetMoodCategoriesQuery, GetMoodPlaylistsQuery,
-    GetNewEpisodesQuery, GetPlaylistTracksQuery, GetPodcastQuery, GetSearchSuggestionsQuery,
+    DeleteUploadEntityQuery, EditPlaylistQuery, EditSongLibraryStatusQuery, EditUploadSongQuery,
+    GetAlbumQuery, GetArtistAlbumsQuery, GetArtistQuery, GetChannelEpisodesQuery, GetChannelQuery,
+    GetContinuationsQuery, GetEpisodeQuery, GetExploreQuery, GetHistoryQuery,
+    GetLibraryAlbumsQuery, GetLibraryArtistSubscriptionsQuery, GetLibraryArtistsQuery,
+    GetLibraryPlaylistsQuery, GetLibrarySongsQuery, GetLibraryUploadAlbumQuery,
+    GetLibraryUploadAlbumsQuery, GetLibraryUploadArtistQuery, GetLibraryUploadArtistsQuery,
+    GetLibraryUploadSongsQuery, GetListenAgainQuery, GetLyricsIDQuery, GetLyricsQuery,
+    GetMoodCategoriesQuery, GetMoodPlaylistsQuery, GetNewEpisodesQuery, GetNewMusicVideosQuery,
+    GetNewReleasesQuery, GetPlaylistTracksQuery, GetPodcastQuery, GetSearchSuggestionsQuery,
     GetTasteProfileQuery, GetUserPlaylistsQuery, GetUserQuery, GetUserVideosQuery,
-    GetWatchPlaylistQuery, PostQuery, Query, RemoveHistoryItemsQuery, RemovePlaylistItemsQuery,
-    SearchQuery, SetTasteProfileQuery, SubscribeArtistQuery, UnsubscribeArtistsQuery,
+    GetWatchPlaylistQuery, MarkEpisodePlayedQuery, MarkEpisodeUnplayedQuery, PinToListenAgainQuery,
+    PostQuery, Query, RemoveHistoryItemsQuery, RemovePlaylistItemsQuery,
+    RemoveSearchSuggestionsQuery, SearchQuery, SetTasteProfileQuery, SubscribeArtistQuery,
+    SubscribePodcastQuery, UnpinFromListenAgainQuery, UnsubscribeArtistsQuery,
+    UnsubscribePodcastQuery,
 };
 
 pub struct CliQuery {
@@ -368,6 +374,18 @@ pub async fn command_to_query(
             )
             .await
         }
+        Command::RemoveSearchSuggestions { feedback_tokens } => {
+            get_string_output_of_query_browser_or_oauth(
+                yt,
+                RemoveSearchSuggestionsQuery::new(
+                    feedback_tokens
+                        .iter()
+                        .map(FeedbackTokenRemoveSearchSuggestion::from_raw),
+                ),
+                cli_query,
+            )
+            .await
+        }
         Command::RateSong {
             video_id,
             like_status,
@@ -477,6 +495,17 @@ pub async fn command_to_query(
             )
             .await
         }
+        Command::EditUploadSong {
+            upload_entity_id,
+            title,
+            artist,
+            album,
+        } => {
+            let Some(query) = edit_upload_song_query(upload_entity_id, title, artist, album) else {
+                bail!("At least one of --title, --artist or --album must be provided")
+            };
+            get_string_output_of_query_browser_or_oauth(yt, query, cli_query).await
+        }
         Command::GetTasteProfile => {
             get_string_output_of_query(yt, GetTasteProfileQuery, cli_query).await
         }
@@ -497,6 +526,15 @@ pub async fn command_to_query(
         Command::GetMoodCategories => {
             get_string_output_of_query(yt, GetMoodCategoriesQuery, cli_query).await
         }
+        Command::GetExplore => get_string_output_of_query(yt, GetExploreQuery, cli_query).await,
+        Command::GetNewReleases { max_pages } => {
+            get_string_output_of_streaming_query(yt, GetNewReleasesQuery, cli_query, max_pages)
+                .await
+        }
+        Command::GetNewMusicVideos { max_pages } => {
+            get_string_output_of_streaming_query(yt, GetNewMusicVideosQuery, cli_query, max_pages)
+                .await
+        }
         Command::GetMoodPlaylists {
             mood_category_params,
         } => {
@@ -525,6 +563,14 @@ pub async fn command_to_query(
             )
             .await
         }
+        Command::GetSong { video_id } => {
+            get_string_output_of_query(
+                yt,
+                GetSongQuery::new(VideoID::from_raw(video_id))?,
+                cli_query,
+            )
+            .await
+        }
         Command::GetChannel { channel_id } => {
             get_string_output_of_query(
                 yt,
@@ -566,6 +612,59 @@ pub async fn command_to_query(
         Command::GetNewEpisodes => {
             get_string_output_of_query(yt, GetNewEpisodesQuery, cli_query).await
         }
+        Command::SubscribePodcast { podcast_id } => {
+            get_string_output_of_query_browser_or_oauth(
+                yt,
+                SubscribePodcastQuery::new(PodcastID::from_raw(podcast_id)),
+                cli_query,
+            )
+            .await
+        }
+        Command::UnsubscribePodcast { podcast_id } => {
+            get_string_output_of_query_browser_or_oauth(
+                yt,
+                UnsubscribePodcastQuery::new(PodcastID::from_raw(podcast_id)),
+                cli_query,
+            )
+            .await
+        }
+        Command::MarkEpisodePlayed { feedback_token } => {
+            get_string_output_of_query_browser_or_oauth(
+                yt,
+                MarkEpisodePlayedQuery::new(FeedbackTokenMarkPlayed::from_raw(feedback_token)),
+                cli_query,
+            )
+            .await
+        }
+        Command::MarkEpisodeUnplayed { feedback_token } => {
+            get_string_output_of_query_browser_or_oauth(
+                yt,
+                MarkEpisodeUnplayedQuery::new(FeedbackTokenMarkUnplayed::from_raw(feedback_token)),
+                cli_query,
+            )
+            .await
+        }
+        Command::GetListenAgain => {
+            get_string_output_of_query_browser_or_oauth(yt, GetListenAgainQuery, cli_query).await
+        }
+        Command::PinToListenAgain { feedback_token } => {
+            get_string_output_of_query_browser_or_oauth(
+                yt,
+                PinToListenAgainQuery::new(FeedbackTokenPinToListenAgain::from_raw(feedback_token)),
+                cli_query,
+            )
+            .await
+        }
+        Command::UnpinFromListenAgain { feedback_token } => {
+            get_string_output_of_query_browser_or_oauth(
+                yt,
+                UnpinFromListenAgainQuery::new(FeedbackTokenUnpinFromListenAgain::from_raw(
+                    feedback_token,
+                )),
+                cli_query,
+            )
+            .await
+        }
         Command::GetLyricsID { video_id } => {
             get_string_output_of_query(
                 yt,
@@ -605,28 +704,32 @@ pub async fn command_to_query(
         Command::GetUserPlaylists {
             user_channel_id,
             browse_params,
+            max_pages,
         } => {
-            get_string_output_of_query(
+            get_string_output_of_streaming_query(
                 yt,
                 GetUserPlaylistsQuery::new(
                     UserChannelID::from_raw(user_channel_id),
                     UserPlaylistsParams::from_raw(browse_params),
                 ),
                 cli_query,
+                max_pages,
             )
             .await
         }
         Command::GetUserVideos {
             user_channel_id,
             browse_params,
+            max_pages,
         } => {
-            get_string_output_of_query(
+            get_string_output_of_streaming_query(
                 yt,
                 GetUserVideosQuery::new(
                     UserChannelID::from_raw(user_channel_id),
                     UserVideosParams::from_raw(browse_params),
                 ),
                 cli_query,
+                max_pages,
             )
             .await
         }
@@ -672,6 +775,28 @@ where
     }
 }
 
+fn edit_upload_song_query(
+    upload_entity_id: String,
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+) -> Option<EditUploadSongQuery<'static>> {
+    if title.is_none() && artist.is_none() && album.is_none() {
+        return None;
+    }
+    let mut query = EditUploadSongQuery::new(UploadEntityID::from_raw(upload_entity_id));
+    if let Some(title) = title {
+        query = query.with_new_title(title);
+    }
+    if let Some(artist) = artist {
+        query = query.with_new_artist(artist);
+    }
+    if let Some(album) = album {
+        query = query.with_new_album(album);
+    }
+    Some(query)
+}
+
 async fn get_string_output_of_query_browser_or_oauth<Q, O>(
     yt: DynamicYtMusic,
     q: impl Borrow<Q>,
@@ -257,6 +257,9 @@ enum Command {
     RemoveHistoryItems {
         feedback_tokens: Vec<String>,
     },
+    RemoveSearchSuggestions {
+        feedback_tokens: Vec<String>,
+    },
     RateSong {
         video_id: String,
         like_status: String,
@@ -298,6 +301,17 @@ enum Command {
     DeleteUploadEntity {
         upload_entity_id: String,
     },
+    /// Edit the metadata of an uploaded song. At least one of title, artist or
+    /// album must be provided.
+    EditUploadSong {
+        upload_entity_id: String,
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long)]
+        artist: Option<String>,
+        #[arg(long)]
+        album: Option<String>,
+    },
     GetTasteProfile,
     // Simple implementation - only allows a single set per command.
     SetTasteProfile {
@@ -305,6 +319,17 @@ enum Command {
         selection_token: String,
     },
     GetMoodCategories,
+    GetExplore,
+    GetNewReleases {
+        /// Maximum number of pages that the API is allowed to return.
+        #[arg(default_value_t = 1)]
+        max_pages: usize,
+    },
+    GetNewMusicVideos {
+        /// Maximum number of pages that the API is allowed to return.
+        #[arg(default_value_t = 1)]
+        max_pages: usize,
+    },
     GetMoodPlaylists {
         mood_category_params: String,
     },
@@ -314,6 +339,9 @@ enum Command {
     GetSongTrackingUrl {
         video_id: String,
     },
+    GetSong {
+        video_id: String,
+    },
     GetLyrics {
         lyrics_id: String,
     },
@@ -341,16 +369,41 @@ enum Command {
         video_id: String,
     },
     GetNewEpisodes,
+    SubscribePodcast {
+        podcast_id: String,
+    },
+    UnsubscribePodcast {
+        podcast_id: String,
+    },
+    MarkEpisodePlayed {
+        feedback_token: String,
+    },
+    MarkEpisodeUnplayed {
+        feedback_token: String,
+    },
+    GetListenAgain,
+    PinToListenAgain {
+        feedback_token: String,
+    },
+    UnpinFromListenAgain {
+        feedback_token: String,
+    },
     GetUser {
         user_channel_id: String,
     },
     GetUserPlaylists {
         user_channel_id: String,
         browse_params: String,
+        /// Maximum number of pages that the API is allowed to return.
+        #[arg(default_value_t = 1)]
+        max_pages: usize,
     },
     GetUserVideos {
         user_channel_id: String,
         browse_params: String,
+        /// Maximum number of pages that the API is allowed to return.
+        #[arg(default_value_t = 1)]
+        max_pages: usize,
     },
 }
 
@@ -0,0 +1,151 @@
+//! Structural comparison of json documents.
+//!
+//! Only the shape of a document is compared - values are ignored, and all
+//! elements of an array are merged under a single `*` path segment.
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// The segment used in place of array indices.
+pub const ARRAY_WILDCARD: &str = "*";
+
+/// Paths (JSON pointer notation) added and removed between two documents.
+#[derive(Debug, Default, PartialEq)]
+pub struct StructuralDiff {
+    /// Paths present in the new document but not the old one.
+    pub added: Vec<String>,
+    /// Paths present in the old document but not the new one.
+    pub removed: Vec<String>,
+}
+
+impl StructuralDiff {
+    pub fn new(old: &Value, new: &Value) -> Self {
+        let old = structural_paths(old);
+        let new = structural_paths(new);
+        Self {
+            added: minimal_difference(&new, &old),
+            removed: minimal_difference(&old, &new),
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+    /// Return the removed path that `key` depends on, if any.
+    /// `key` may contain array indices - these are normalised before
+    /// comparison.
+    pub fn vanished_dependency(&self, key: &str) -> Option<&str> {
+        let key = normalise_pointer(key);
+        self.removed
+            .iter()
+            .find(|removed| is_same_or_descendant(&key, removed))
+            .map(String::as_str)
+    }
+}
+
+/// Return every path in the document, with array indices replaced by
+/// [`ARRAY_WILDCARD`]. The root is represented by the empty string.
+pub fn structural_paths(json: &Value) -> BTreeSet<String> {
+    let mut paths = BTreeSet::new();
+    collect_paths(json, String::new(), &mut paths);
+    paths
+}
+
+/// Replace all numeric segments of a JSON pointer with [`ARRAY_WILDCARD`].
+pub fn normalise_pointer(pointer: &str) -> String {
+    pointer
+        .split('/')
+        .map(|segment| {
+            if !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit()) {
+                ARRAY_WILDCARD
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn collect_paths(json: &Value, path: String, paths: &mut BTreeSet<String>) {
+    match json {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let escaped = key.replace('~', "~0").replace('/', "~1");
+                collect_paths(value, format!("{path}/{escaped}"), paths);
+            }
+        }
+        Value::Array(array) => {
+            for value in array {
+                collect_paths(value, format!("{path}/{ARRAY_WILDCARD}"), paths);
+            }
+        }
+        _ => (),
+    }
+    paths.insert(path);
+}
+
+/// Paths in `a` but not `b`, excluding those whose parent is also in `a` but
+/// not `b` - i.e, only the roots of the differing subtrees.
+fn minimal_difference(a: &BTreeSet<String>, b: &BTreeSet<String>) -> Vec<String> {
+    a.difference(b)
+        .filter(|path| parent(path).is_none_or(|parent| b.contains(parent)))
+        .cloned()
+        .collect()
+}
+
+fn parent(path: &str) -> Option<&str> {
+    path.rsplit_once('/').map(|(parent, _)| parent)
+}
+
+fn is_same_or_descendant(path: &str, ancestor: &str) -> bool {
+    path.strip_prefix(ancestor)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StructuralDiff, normalise_pointer, structural_paths};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn test_structural_paths_merges_array_elements() {
+        let json = json!({"a": [{"b": 1}, {"c": 2}], "d/e": null});
+        let paths: Vec<_> = structural_paths(&json).into_iter().collect();
+        assert_eq!(paths, ["", "/a", "/a/*", "/a/*/b", "/a/*/c", "/d~1e"]);
+    }
+    #[test]
+    fn test_diff_reports_only_subtree_roots() {
+        let old = json!({"header": {"title": {"runs": [{"text": "a"}]}}, "contents": []});
+        let new = json!({"header": {"subtitle": "b"}, "contents": [], "footer": {"x": 1}});
+        let diff = StructuralDiff::new(&old, &new);
+        assert_eq!(
+            diff,
+            StructuralDiff {
+                added: vec!["/footer".to_string(), "/header/subtitle".to_string()],
+                removed: vec!["/header/title".to_string()],
+            }
+        );
+    }
+    #[test]
+    fn test_diff_ignores_values() {
+        let old = json!({"a": [1, 2, 3], "b": "x"});
+        let new = json!({"a": [4], "b": "y"});
+        assert!(StructuralDiff::new(&old, &new).is_empty());
+    }
+    #[test]
+    fn test_vanished_dependency() {
+        let old = json!({"header": {"title": {"runs": [{"text": "a"}]}}});
+        let new = json!({"header": {}});
+        let diff = StructuralDiff::new(&old, &new);
+        assert_eq!(
+            diff.vanished_dependency("/header/title/runs/0/text"),
+            Some("/header/title")
+        );
+        assert_eq!(diff.vanished_dependency("/header/titles"), None);
+        assert_eq!(diff.vanished_dependency("/header"), None);
+    }
+    #[test]
+    fn test_normalise_pointer() {
+        assert_eq!(normalise_pointer("/a/0/b/12/c"), "/a/*/b/*/c");
+        assert_eq!(normalise_pointer(""), "");
+    }
+}
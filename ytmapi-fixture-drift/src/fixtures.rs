@@ -0,0 +1,433 @@
+//! The manifest of fixtures to check, and the queries that produced them.
+use futures::FutureExt;
+use futures::future::LocalBoxFuture;
+use std::marker::PhantomData;
+use ytmapi_rs::YtMusic;
+use ytmapi_rs::auth::noauth::NoAuthToken;
+use ytmapi_rs::auth::{AuthToken, BrowserToken};
+use ytmapi_rs::common::{
+    AlbumID, ArtistChannelID, BrowseParams, EpisodeID, LyricsID, MoodCategoryParams, PlaylistID,
+    PodcastChannelID, PodcastID, UploadAlbumID, UploadArtistID, UserChannelID, VideoID, YoutubeID,
+};
+use ytmapi_rs::query::playlist::GetWatchPlaylistQuery;
+use ytmapi_rs::query::search::{
+    AlbumsFilter, ArtistsFilter, CommunityPlaylistsFilter, EpisodesFilter, FeaturedPlaylistsFilter,
+    PlaylistsFilter, PodcastsFilter, ProfilesFilter, SongsFilter, VideosFilter,
+};
+use ytmapi_rs::query::*;
+
+/// Clients used to fetch live responses.
+#[derive(Default)]
+pub struct Clients {
+    pub unauthenticated: Option<YtMusic<NoAuthToken>>,
+    pub browser: Option<YtMusic<BrowserToken>>,
+}
+
+/// Where a fresh response for a fixture can come from.
+pub enum Source {
+    /// Can be fetched live without credentials.
+    Public,
+    /// Can be fetched live using browser credentials.
+    LoggedIn,
+    /// Can only be compared against a recorded response - the query that
+    /// produced the fixture is stateful, or its inputs were not recorded.
+    RecordedOnly { reason: &'static str },
+}
+
+pub struct Fixture {
+    /// File name of the fixture, relative to the fixture directory.
+    pub file: &'static str,
+    /// Short name of the query used to parse the fixture.
+    pub query_name: String,
+    pub source: Source,
+    query: Box<dyn DynQuery>,
+}
+
+impl Fixture {
+    fn public<Q: Query<NoAuthToken> + 'static>(file: &'static str, query: Q) -> Self {
+        Self::new::<Q, NoAuthToken>(file, query, Source::Public)
+    }
+    fn logged_in<Q: Query<BrowserToken> + 'static>(file: &'static str, query: Q) -> Self {
+        Self::new::<Q, BrowserToken>(file, query, Source::LoggedIn)
+    }
+    fn recorded_only<Q: Query<BrowserToken> + 'static>(
+        file: &'static str,
+        query: Q,
+        reason: &'static str,
+    ) -> Self {
+        Self::new::<Q, BrowserToken>(file, query, Source::RecordedOnly { reason })
+    }
+    fn new<Q: Query<A> + 'static, A: ClientToken + 'static>(
+        file: &'static str,
+        query: Q,
+        source: Source,
+    ) -> Self {
+        Self {
+            file,
+            query_name: short_type_name(std::any::type_name::<Q>()),
+            source,
+            query: Box::new(FixtureQuery {
+                query,
+                token: PhantomData::<A>,
+            }),
+        }
+    }
+    /// Parse a response using the fixture's query.
+    pub fn parse(&self, json: String) -> ytmapi_rs::Result<()> {
+        self.query.parse(json)
+    }
+    /// Fetch a live response for the fixture's query, if a suitable client is
+    /// available.
+    pub fn fetch<'a>(
+        &'a self,
+        clients: &'a Clients,
+    ) -> Option<LocalBoxFuture<'a, ytmapi_rs::Result<String>>> {
+        match self.source {
+            Source::Public | Source::LoggedIn => self.query.fetch(clients),
+            Source::RecordedOnly { .. } => None,
+        }
+    }
+}
+
+/// Auth tokens that the drift checker holds a client for.
+trait ClientToken: AuthToken {
+    fn client(clients: &Clients) -> Option<&YtMusic<Self>>;
+}
+impl ClientToken for NoAuthToken {
+    fn client(clients: &Clients) -> Option<&YtMusic<Self>> {
+        clients.unauthenticated.as_ref()
+    }
+}
+impl ClientToken for BrowserToken {
+    fn client(clients: &Clients) -> Option<&YtMusic<Self>> {
+        clients.browser.as_ref()
+    }
+}
+
+/// Object safe wrapper over a query and the token used to send it.
+trait DynQuery {
+    fn parse(&self, json: String) -> ytmapi_rs::Result<()>;
+    fn fetch<'a>(
+        &'a self,
+        clients: &'a Clients,
+    ) -> Option<LocalBoxFuture<'a, ytmapi_rs::Result<String>>>;
+}
+
+struct FixtureQuery<Q, A> {
+    query: Q,
+    token: PhantomData<A>,
+}
+
+impl<Q: Query<A>, A: ClientToken> DynQuery for FixtureQuery<Q, A> {
+    fn parse(&self, json: String) -> ytmapi_rs::Result<()> {
+        ytmapi_rs::process_json::<Q, A>(json, &self.query).map(|_| ())
+    }
+    fn fetch<'a>(
+        &'a self,
+        clients: &'a Clients,
+    ) -> Option<LocalBoxFuture<'a, ytmapi_rs::Result<String>>> {
+        let yt = A::client(clients)?;
+        Some(yt.raw_json_query::<Q>(&self.query).boxed_local())
+    }
+}
+
+/// Strip module paths from a type name, e.g
+/// `ytmapi_rs::query::album::GetAlbumQuery` becomes `GetAlbumQuery`.
+fn short_type_name(type_name: &str) -> String {
+    let mut short = String::new();
+    let mut segment = String::new();
+    for c in type_name.chars() {
+        match c {
+            c if c.is_alphanumeric() || c == '_' => segment.push(c),
+            ':' => segment.clear(),
+            c => {
+                short.push_str(&segment);
+                segment.clear();
+                short.push(c);
+            }
+        }
+    }
+    short.push_str(&segment);
+    short
+}
+
+const STATEFUL: &str = "query modifies account state";
+const UNKNOWN_INPUT: &str = "query inputs were not recorded";
+
+/// All fixtures that represent a complete response to a query.
+/// Continuation fixtures are not included, as they can't be requested
+/// independently, and neither are hand-written `_mock` fixtures, as there is no
+/// real response to compare them against.
+#[allow(deprecated)]
+pub fn all_fixtures() -> Vec<Fixture> {
+    vec![
+        // Albums
+        Fixture::public(
+            "get_album_20240724.json",
+            GetAlbumQuery::new(AlbumID::from_raw("MPREb_JGVwpblcYUg")),
+        ),
+        Fixture::recorded_only(
+            "get_album_not_signed_in_20250611.json",
+            GetAlbumQuery::new(AlbumID::from_raw("")),
+            UNKNOWN_INPUT,
+        ),
+        Fixture::recorded_only(
+            "get_album_various_artists_no_thumbnail_20240818.json",
+            GetAlbumQuery::new(AlbumID::from_raw("")),
+            UNKNOWN_INPUT,
+        ),
+        // Artists
+        Fixture::public(
+            "get_artist_20240705.json",
+            GetArtistQuery::new(ArtistChannelID::from_raw("UCcSL2nYSJp_IgdzH0xBBdcg")),
+        ),
+        Fixture::public(
+            "get_artist_20250310.json",
+            GetArtistQuery::new(ArtistChannelID::from_raw("UC2XdaAVUannpujzv32jcouQ")),
+        ),
+        Fixture::recorded_only(
+            "browse_artist_albums.json",
+            GetArtistAlbumsQuery::new(ArtistChannelID::from_raw(""), BrowseParams::from_raw("")),
+            UNKNOWN_INPUT,
+        ),
+        Fixture::recorded_only(
+            "subscribe_artist_20250704.json",
+            SubscribeArtistQuery::new(ArtistChannelID::from_raw("")),
+            STATEFUL,
+        ),
+        Fixture::recorded_only(
+            "unsubscribe_artists_20250704.json",
+            UnsubscribeArtistsQuery::new([]),
+            STATEFUL,
+        ),
+        // History
+        Fixture::logged_in("get_history_20240713.json", GetHistoryQuery),
+        Fixture::recorded_only(
+            "remove_history_items_20240704.json",
+            RemoveHistoryItemsQuery::new(Vec::new()),
+            STATEFUL,
+        ),
+        // Library
+        Fixture::logged_in("get_library_playlists.json", GetLibraryPlaylistsQuery),
+        Fixture::logged_in(
+            "get_library_artists.json",
+            GetLibraryArtistsQuery::default(),
+        ),
+        Fixture::logged_in(
+            "get_library_albums_20240701.json",
+            GetLibraryAlbumsQuery::default(),
+        ),
+        Fixture::logged_in(
+            "get_library_songs_20240701.json",
+            GetLibrarySongsQuery::default(),
+        ),
+        Fixture::logged_in(
+            "get_library_artist_subscriptions_20240701.json",
+            GetLibraryArtistSubscriptionsQuery::default(),
+        ),
+        Fixture::logged_in(
+            "get_library_podcasts_20250626.json",
+            GetLibraryPodcastsQuery::default(),
+        ),
+        Fixture::logged_in(
+            "get_library_channels_20250626.json",
+            GetLibraryChannelsQuery::default(),
+        ),
+        // Playlists
+        Fixture::public(
+            "get_playlist_20250604.json",
+            GetPlaylistDetailsQuery::new(PlaylistID::from_raw(
+                "VLPL0jp-uZ7a4g9FQWW5R_u0pz4yzV4RiOXu",
+            )),
+        ),
+        Fixture::recorded_only(
+            "get_playlist_20240624.json",
+            GetPlaylistDetailsQuery::new(PlaylistID::from_raw("")),
+            UNKNOWN_INPUT,
+        ),
+        Fixture::recorded_only(
+            "get_playlist_no_channel_thumbnail_20240818.json",
+            GetPlaylistDetailsQuery::new(PlaylistID::from_raw("")),
+            UNKNOWN_INPUT,
+        ),
+        Fixture::public(
+            "get_watch_playlist_20250630.json",
+            GetWatchPlaylistQuery::new_from_video_id(VideoID::from_raw("lYBUbBu4W08")),
+        ),
+        Fixture::recorded_only(
+            "add_playlist_items_20240626.json",
+            AddPlaylistItemsQuery::new_from_playlist(
+                PlaylistID::from_raw(""),
+                PlaylistID::from_raw(""),
+            ),
+            STATEFUL,
+        ),
+        Fixture::recorded_only(
+            "edit_playlist_title_20240626.json",
+            EditPlaylistQuery::new_title(PlaylistID::from_raw(""), ""),
+            STATEFUL,
+        ),
+        // Podcasts
+        Fixture::public(
+            "get_channel_20240830.json",
+            GetChannelQuery::new(PodcastChannelID::from_raw("UCzYLos4qc2oC4r0Efd-tSuw")),
+        ),
+        Fixture::public(
+            "get_podcast_20240830.json",
+            GetPodcastQuery::new(PodcastID::from_raw(
+                "MPSPPLWnnGn_Lw9os50MbtFCouWYsArlq2s8ct",
+            )),
+        ),
+        Fixture::recorded_only(
+            "get_episode_20240830.json",
+            GetEpisodeQuery::new(EpisodeID::from_raw("")),
+            UNKNOWN_INPUT,
+        ),
+        Fixture::logged_in("get_new_episodes_20240830.json", GetNewEpisodesQuery),
+        // Rating
+        Fixture::recorded_only(
+            "rate_song_like_20240710.json",
+            RateSongQuery::new(VideoID::from_raw(""), ytmapi_rs::common::LikeStatus::Liked),
+            STATEFUL,
+        ),
+        Fixture::recorded_only(
+            "rate_playlist_like_20240710.json",
+            RatePlaylistQuery::new(
+                PlaylistID::from_raw(""),
+                ytmapi_rs::common::LikeStatus::Liked,
+            ),
+            STATEFUL,
+        ),
+        // Recommendations
+        Fixture::public("get_mood_categories_20240723.json", GetMoodCategoriesQuery),
+        Fixture::recorded_only(
+            "get_mood_playlists_20240723.json",
+            GetMoodPlaylistsQuery::new(MoodCategoryParams::from_raw("")),
+            UNKNOWN_INPUT,
+        ),
+        Fixture::logged_in("get_taste_profile_20240722.json", GetTasteProfileQuery),
+        // Search
+        Fixture::public(
+            "search_artists_20231226.json",
+            SearchQuery::new("Beatles").with_filter(ArtistsFilter),
+        ),
+        Fixture::public(
+            "search_albums_20231226.json",
+            SearchQuery::new("Beatles").with_filter(AlbumsFilter),
+        ),
+        Fixture::public(
+            "search_songs_20231226.json",
+            SearchQuery::new("Beatles").with_filter(SongsFilter),
+        ),
+        Fixture::public(
+            "search_videos_20240612.json",
+            SearchQuery::new("Beatles").with_filter(VideosFilter),
+        ),
+        Fixture::public(
+            "search_playlists_20231228.json",
+            SearchQuery::new("Beatles").with_filter(PlaylistsFilter),
+        ),
+        Fixture::public(
+            "search_featured_playlists_20231226.json",
+            SearchQuery::new("Beatles").with_filter(FeaturedPlaylistsFilter),
+        ),
+        Fixture::public(
+            "search_community_playlists_20231226.json",
+            SearchQuery::new("Beatles").with_filter(CommunityPlaylistsFilter),
+        ),
+        Fixture::public(
+            "search_episodes_20231226.json",
+            SearchQuery::new("Beatles").with_filter(EpisodesFilter),
+        ),
+        Fixture::public(
+            "search_podcasts_20231226.json",
+            SearchQuery::new("Beatles").with_filter(PodcastsFilter),
+        ),
+        Fixture::public(
+            "search_profiles_20231226.json",
+            SearchQuery::new("Beatles").with_filter(ProfilesFilter),
+        ),
+        Fixture::recorded_only(
+            "search_basic_top_result_card_20240721.json",
+            SearchQuery::new(""),
+            UNKNOWN_INPUT,
+        ),
+        Fixture::recorded_only(
+            "search_basic_with_podcast_community_playlists_20250605.json",
+            SearchQuery::new(""),
+            UNKNOWN_INPUT,
+        ),
+        Fixture::recorded_only(
+            "search_basic_no_results_20240721.json",
+            SearchQuery::new(""),
+            UNKNOWN_INPUT,
+        ),
+        // Songs
+        Fixture::public(
+            "get_lyrics_20231219.json",
+            GetLyricsQuery::new(LyricsID::from_raw("MPLYt_iF0uEXwERPq-1")),
+        ),
+        // Uploads
+        Fixture::logged_in(
+            "get_library_upload_songs_20240712.json",
+            GetLibraryUploadSongsQuery::default(),
+        ),
+        Fixture::logged_in(
+            "get_library_upload_albums_20240712.json",
+            GetLibraryUploadAlbumsQuery::default(),
+        ),
+        Fixture::logged_in(
+            "get_library_upload_artists_20240712.json",
+            GetLibraryUploadArtistsQuery::default(),
+        ),
+        Fixture::recorded_only(
+            "get_library_upload_artist_20240712.json",
+            GetLibraryUploadArtistQuery::new(UploadArtistID::from_raw("")),
+            UNKNOWN_INPUT,
+        ),
+        Fixture::recorded_only(
+            "get_library_upload_album_20240712.json",
+            GetLibraryUploadAlbumQuery::new(UploadAlbumID::from_raw("")),
+            UNKNOWN_INPUT,
+        ),
+        // Users
+        Fixture::public(
+            "get_user_20250707.json",
+            GetUserQuery::new(UserChannelID::from_raw("UCj0boSvCVfTmO9JHlclA8eQ")),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all_fixtures, short_type_name};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(
+            short_type_name("ytmapi_rs::query::album::GetAlbumQuery"),
+            "GetAlbumQuery"
+        );
+        assert_eq!(
+            short_type_name(
+                "ytmapi_rs::query::search::SearchQuery<ytmapi_rs::query::search::FilteredSearch<ytmapi_rs::query::search::filteredsearch::SongsFilter>>"
+            ),
+            "SearchQuery<FilteredSearch<SongsFilter>>"
+        );
+    }
+    #[tokio::test]
+    async fn test_all_fixtures_exist_and_parse() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../ytmapi-rs/test_json");
+        let mut seen = HashSet::new();
+        for fixture in all_fixtures() {
+            assert!(seen.insert(fixture.file), "{} listed twice", fixture.file);
+            let json = tokio::fs::read_to_string(format!("{dir}/{}", fixture.file))
+                .await
+                .unwrap_or_else(|e| panic!("Unable to read {}: {e}", fixture.file));
+            fixture
+                .parse(json)
+                .unwrap_or_else(|e| panic!("Unable to parse {}: {e}", fixture.file));
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Development tool to re-validate the ytmapi-rs json fixtures against fresh
+//! responses from YouTube Music.
+//!
+//! For each fixture, a fresh response is fetched live (or read from a
+//! directory of recorded responses), both are parsed using the fixture's
+//! query, and a structural diff of json paths added / removed is reported.
+//! Where the fresh response no longer parses, the parser is flagged if the
+//! path it failed at has vanished from the response.
+use clap::Parser;
+use diff::StructuralDiff;
+use fixtures::{Clients, Fixture, Source};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use ytmapi_rs::YtMusic;
+use ytmapi_rs::error::ErrorKind;
+
+mod diff;
+mod fixtures;
+
+const DEFAULT_FIXTURE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../ytmapi-rs/test_json");
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Arguments {
+    /// Directory containing the json fixtures.
+    #[arg(long, default_value = DEFAULT_FIXTURE_DIR)]
+    fixture_dir: PathBuf,
+    /// Directory of recorded responses, named the same as the fixtures they
+    /// replace. Where a recorded response exists, it's used instead of a live
+    /// one.
+    #[arg(long)]
+    recorded_dir: Option<PathBuf>,
+    /// Directory to save live responses to - can be used as a `recorded_dir`
+    /// later, or to update fixtures.
+    #[arg(long)]
+    save_dir: Option<PathBuf>,
+    /// Browser cookie file, used to fetch fixtures that require logging in.
+    #[arg(long)]
+    cookie_file: Option<PathBuf>,
+    /// Only check fixtures whose file name contains this string.
+    #[arg(long)]
+    filter: Option<String>,
+}
+
+/// Outcome of checking a single fixture.
+enum Outcome {
+    Skipped(String),
+    Checked {
+        diff: StructuralDiff,
+        parser: ParserOutcome,
+    },
+    /// The check itself couldn't be completed, e.g the fixture no longer
+    /// parses or the live query failed.
+    Error(String),
+}
+
+enum ParserOutcome {
+    Ok,
+    /// Parser failed at a path that has vanished from the response.
+    DependsOnVanishedPath {
+        vanished: String,
+        key: String,
+    },
+    /// Parser failed for a reason other than a vanished path.
+    Failed(String),
+}
+
+impl Outcome {
+    fn is_failure(&self) -> bool {
+        match self {
+            Outcome::Skipped(_) => false,
+            Outcome::Checked { parser, .. } => !matches!(parser, ParserOutcome::Ok),
+            Outcome::Error(_) => true,
+        }
+    }
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    let args = Arguments::parse();
+    let fixtures: Vec<_> = fixtures::all_fixtures()
+        .into_iter()
+        .filter(|f| args.filter.as_ref().is_none_or(|s| f.file.contains(s)))
+        .collect();
+    let clients = create_clients(&args, &fixtures).await;
+    let mut failures = 0;
+    for fixture in &fixtures {
+        let outcome = check_fixture(fixture, &args, &clients).await;
+        if outcome.is_failure() {
+            failures += 1;
+        }
+        print_outcome(fixture, &outcome);
+    }
+    println!("{} fixtures checked, {failures} failed", fixtures.len());
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Only create the clients that will be needed to fetch live responses. If a
+/// client can't be created, the fixtures that need it will report an error.
+async fn create_clients(args: &Arguments, fixtures: &[Fixture]) -> Clients {
+    let needs_live = |source: fn(&Source) -> bool| {
+        fixtures
+            .iter()
+            .any(|f| source(&f.source) && recorded_path(args, f).is_none())
+    };
+    let mut clients = Clients::default();
+    if needs_live(|s| matches!(s, Source::Public)) {
+        clients.unauthenticated = YtMusic::new_unauthenticated()
+            .await
+            .inspect_err(|e| eprintln!("Error creating unauthenticated client: {e}"))
+            .ok();
+    }
+    if let Some(cookie_file) = &args.cookie_file
+        && needs_live(|s| matches!(s, Source::LoggedIn))
+    {
+        clients.browser = YtMusic::from_cookie_file(cookie_file)
+            .await
+            .inspect_err(|e| eprintln!("Error creating browser client: {e}"))
+            .ok();
+    }
+    clients
+}
+
+fn recorded_path(args: &Arguments, fixture: &Fixture) -> Option<PathBuf> {
+    args.recorded_dir
+        .as_ref()
+        .map(|dir| dir.join(fixture.file))
+        .filter(|path| path.exists())
+}
+
+async fn check_fixture(fixture: &Fixture, args: &Arguments, clients: &Clients) -> Outcome {
+    let fixture_json = match read(&args.fixture_dir.join(fixture.file)).await {
+        Ok(json) => json,
+        Err(e) => return Outcome::Error(e),
+    };
+    let fresh_json = if let Some(path) = recorded_path(args, fixture) {
+        match read(&path).await {
+            Ok(json) => json,
+            Err(e) => return Outcome::Error(e),
+        }
+    } else {
+        let Some(fetch) = fixture.fetch(clients) else {
+            return match fixture.source {
+                Source::RecordedOnly { reason } => {
+                    Outcome::Skipped(format!("no recorded response - {reason}"))
+                }
+                Source::LoggedIn if args.cookie_file.is_none() => {
+                    Outcome::Skipped("requires --cookie-file".to_string())
+                }
+                Source::Public | Source::LoggedIn => {
+                    Outcome::Error("no client available to fetch live response".to_string())
+                }
+            };
+        };
+        let json = match fetch.await {
+            Ok(json) => json,
+            Err(e) => return Outcome::Error(format!("live query failed: {e}")),
+        };
+        if let Some(save_dir) = &args.save_dir
+            && let Err(e) = tokio::fs::write(save_dir.join(fixture.file), &json).await
+        {
+            return Outcome::Error(format!("unable to save live response: {e}"));
+        }
+        json
+    };
+    if let Err(e) = fixture.parse(fixture_json.clone()) {
+        return Outcome::Error(format!("fixture no longer parses: {e}"));
+    }
+    let diff = match (
+        serde_json::from_str(&fixture_json),
+        serde_json::from_str(&fresh_json),
+    ) {
+        (Ok(old), Ok(new)) => StructuralDiff::new(&old, &new),
+        (Err(e), _) | (_, Err(e)) => return Outcome::Error(format!("invalid json: {e}")),
+    };
+    let parser = match fixture.parse(fresh_json) {
+        Ok(()) => ParserOutcome::Ok,
+        Err(e) => match e.kind() {
+            ErrorKind::JsonParsing(e) => match diff.vanished_dependency(e.key()) {
+                Some(vanished) => ParserOutcome::DependsOnVanishedPath {
+                    vanished: vanished.to_string(),
+                    key: e.key().to_string(),
+                },
+                None => ParserOutcome::Failed(e.to_string()),
+            },
+            _ => ParserOutcome::Failed(e.to_string()),
+        },
+    };
+    Outcome::Checked { diff, parser }
+}
+
+async fn read(path: &Path) -> Result<String, String> {
+    tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| format!("unable to read {}: {e}", path.display()))
+}
+
+fn print_outcome(fixture: &Fixture, outcome: &Outcome) {
+    println!("== {} ({}) ==", fixture.file, fixture.query_name);
+    match outcome {
+        Outcome::Skipped(reason) => println!("  skipped: {reason}"),
+        Outcome::Error(e) => println!("  ERROR: {e}"),
+        Outcome::Checked { diff, parser } => {
+            if diff.is_empty() {
+                println!("  no structural changes");
+            }
+            for path in &diff.added {
+                println!("  + {path}");
+            }
+            for path in &diff.removed {
+                println!("  - {path}");
+            }
+            match parser {
+                ParserOutcome::Ok => println!("  parser: ok"),
+                ParserOutcome::DependsOnVanishedPath { vanished, key } => println!(
+                    "  parser: FLAGGED - depends on vanished path {vanished} (failed at {key})"
+                ),
+                ParserOutcome::Failed(e) => println!("  parser: FAILED - {e}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Rewriting of continuation tokens, so that a client streaming a query is
+//! served each fixture in a chain in turn.
+use serde_json::Value;
+
+const TOKEN_PREFIX: &str = "ytmapi-mock-server";
+
+/// Token for the page at `page_idx` of the route at `route_idx`.
+pub(crate) fn continuation_token(route_idx: usize, page_idx: usize) -> String {
+    format!("{TOKEN_PREFIX}:{route_idx}:{page_idx}")
+}
+
+/// Parse a token generated by [`continuation_token`] into `(route_idx,
+/// page_idx)`.
+pub(crate) fn parse_continuation_token(token: &str) -> Option<(usize, usize)> {
+    let mut parts = token
+        .strip_prefix(TOKEN_PREFIX)?
+        .strip_prefix(':')?
+        .split(':');
+    let route_idx = parts.next()?.parse().ok()?;
+    let page_idx = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((route_idx, page_idx))
+}
+
+/// Replace all continuation tokens in `json` with `token`, or remove the
+/// continuations entirely if `token` is None.
+pub(crate) fn replace_continuations(json: &mut Value, token: Option<&str>) {
+    match json {
+        Value::Object(map) => {
+            if token.is_none() {
+                map.remove("continuations");
+            }
+            for (key, value) in map.iter_mut() {
+                // e.g nextContinuationData, nextRadioContinuationData.
+                let token_key = if key.ends_with("ContinuationData") {
+                    Some("continuation")
+                } else if key == "continuationCommand" {
+                    Some("token")
+                } else {
+                    None
+                };
+                if let (Some(token_key), Some(token)) = (token_key, token)
+                    && let Some(old_token @ Value::String(_)) = value.get_mut(token_key)
+                {
+                    *old_token = token.into();
+                }
+                replace_continuations(value, token);
+            }
+        }
+        Value::Array(array) => {
+            if token.is_none() {
+                array.retain(|item| item.get("continuationItemRenderer").is_none());
+            }
+            for item in array {
+                replace_continuations(item, token);
+            }
+        }
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{continuation_token, parse_continuation_token, replace_continuations};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn page() -> serde_json::Value {
+        json!({
+            "contents": [
+                {"musicResponsiveListItemRenderer": {}},
+                {"continuationItemRenderer": {
+                    "continuationEndpoint": {"continuationCommand": {"token": "abc"}}
+                }}
+            ],
+            "continuations": [{"nextContinuationData": {"continuation": "def"}}]
+        })
+    }
+
+    #[test]
+    fn test_continuation_token_round_trip() {
+        assert_eq!(
+            parse_continuation_token(&continuation_token(3, 1)),
+            Some((3, 1))
+        );
+        assert_eq!(parse_continuation_token("4qmFsgKlARIM"), None);
+        assert_eq!(parse_continuation_token("ytmapi-mock-server:3:1:2"), None);
+    }
+    #[test]
+    fn test_replace_continuations() {
+        let mut json = page();
+        replace_continuations(&mut json, Some("new"));
+        let expected = json!({
+            "contents": [
+                {"musicResponsiveListItemRenderer": {}},
+                {"continuationItemRenderer": {
+                    "continuationEndpoint": {"continuationCommand": {"token": "new"}}
+                }}
+            ],
+            "continuations": [{"nextContinuationData": {"continuation": "new"}}]
+        });
+        assert_eq!(json, expected);
+    }
+    #[test]
+    fn test_remove_continuations() {
+        let mut json = page();
+        replace_continuations(&mut json, None);
+        let expected = json!({
+            "contents": [
+                {"musicResponsiveListItemRenderer": {}},
+            ],
+        });
+        assert_eq!(json, expected);
+    }
+}
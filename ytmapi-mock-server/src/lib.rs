@@ -0,0 +1,312 @@
+//! A local stand-in for the YouTube Music Innertube API, for running
+//! ytmapi-rs integration tests offline.
+//!
+//! [`MockServer`] serves json fixtures (such as those in `ytmapi-rs/test_json`)
+//! in response to Innertube queries, according to a set of [`Routes`].
+//! Point ytmapi-rs at it using `YtMusicBuilder::with_base_url` or
+//! `Client::with_base_url`.
+//!
+//! As well as the Innertube endpoints under `/youtubei/v1/`, the server
+//! emulates:
+//! - The YouTube Music homepage, used to create `BrowserToken` and
+//!   `NoAuthToken`.
+//! - The Google OAuth device code and token endpoints.
+//! - The song upload endpoint.
+//! # Usage
+//! ```no_run
+//! use ytmapi_mock_server::{MockServer, Route, Routes};
+//! # async {
+//! let routes = Routes::new().with_route(
+//!     Route::post("browse")
+//!         .with_body_field("/browseId", "FEmusic_explore")
+//!         .respond_with_file("../ytmapi-rs/test_json/get_explore_mock.json"),
+//! );
+//! let server = MockServer::start(routes).await?;
+//! println!("Serving Innertube at {}", server.base_url());
+//! # Ok::<(), std::io::Error>(())
+//! # };
+//! ```
+use bytes::Bytes;
+use continuations::{continuation_token, parse_continuation_token, replace_continuations};
+use http_body_util::{BodyExt, Full};
+use hyper::body::Incoming;
+use hyper::header::{AUTHORIZATION, CONTENT_TYPE, HeaderValue};
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use routes::RouteResponse;
+pub use routes::{Route, Routes};
+use serde_json::{Value, json};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+mod continuations;
+mod routes;
+
+/// Client version served on the homepage.
+pub const CLIENT_VERSION: &str = "1.20261013.01.00";
+/// Visitor data served on the homepage.
+pub const VISITOR_DATA: &str = "CgtZdG1hcGlNb2NrKICAgICAgICAgAE%3D";
+/// Access token returned by the OAuth token endpoint.
+pub const ACCESS_TOKEN: &str = "ytmapi-mock-server-access-token";
+
+const INNERTUBE_PATH: &str = "/youtubei/v1/";
+const UPLOAD_PATH: &str = "/upload/usermusic/http";
+
+/// A running mock server. The server is stopped when this is dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+struct ServerState {
+    routes: Routes,
+    base_url: String,
+}
+
+impl MockServer {
+    /// Start serving `routes` on a free local port.
+    /// # Panics
+    /// Must be called from within a tokio runtime - the server runs on that
+    /// runtime.
+    pub async fn start(routes: Routes) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(ServerState {
+            routes,
+            base_url: format!("http://{addr}"),
+        });
+        let task = tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    continue;
+                };
+                let state = state.clone();
+                tokio::spawn(async move {
+                    let service = service_fn(|request| handle(state.clone(), request));
+                    // Connection errors are the client's concern.
+                    let _ = hyper::server::conn::http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await;
+                });
+            }
+        });
+        Ok(Self { addr, task })
+    }
+    /// Base url to send requests to, e.g `http://127.0.0.1:43123`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn handle(
+    state: Arc<ServerState>,
+    request: Request<Incoming>,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+    let path = request.uri().path().to_owned();
+    let response = match (request.method(), path.as_str()) {
+        (&Method::GET, "/") => homepage(),
+        (&Method::POST, "/token") => oauth_token(),
+        (&Method::POST, "/o/oauth2/device/code") => oauth_device_code(),
+        (&Method::POST, UPLOAD_PATH) => upload(&state, request).await,
+        (&Method::POST, path) if path.starts_with(INNERTUBE_PATH) => {
+            let endpoint = path.trim_start_matches(INNERTUBE_PATH).to_owned();
+            innertube(&state, &endpoint, request).await
+        }
+        (method, path) => innertube_error(404, format!("No mock route for {method} {path}")),
+    };
+    Ok(response)
+}
+
+async fn innertube(
+    state: &ServerState,
+    endpoint: &str,
+    request: Request<Incoming>,
+) -> Response<Full<Bytes>> {
+    if state.routes.expired_auth && request.headers().contains_key(AUTHORIZATION) {
+        return innertube_error(
+            401,
+            "Request had invalid authentication credentials. Expected OAuth 2 access token, login cookie or other valid authentication credential.",
+        );
+    }
+    let continuation = request.uri().query().and_then(|query| {
+        url::form_urlencoded::parse(query.as_bytes())
+            .find(|(key, _)| key == "ctoken" || key == "continuation")
+            .map(|(_, token)| token.into_owned())
+    });
+    if let Some(token) = continuation {
+        let Some((route_idx, page_idx)) = parse_continuation_token(&token) else {
+            return innertube_error(400, format!("Unknown continuation token {token}"));
+        };
+        return match state.routes.routes.get(route_idx) {
+            Some(route) => route_response(route_idx, &route.response, page_idx).await,
+            None => innertube_error(400, format!("Unknown continuation token {token}")),
+        };
+    }
+    let headers = request.headers().clone();
+    let body = match request.into_body().collect().await {
+        Ok(body) => body.to_bytes(),
+        Err(e) => return innertube_error(400, format!("Error reading request body: {e}")),
+    };
+    let Ok(body) = serde_json::from_slice::<Value>(&body) else {
+        return innertube_error(400, "Invalid JSON payload received.");
+    };
+    match state.routes.find(endpoint, &headers, &body) {
+        Some((route_idx, route)) => route_response(route_idx, &route.response, 0).await,
+        None => innertube_error(
+            404,
+            format!("No mock route for endpoint {endpoint} with body {body}"),
+        ),
+    }
+}
+
+async fn route_response(
+    route_idx: usize,
+    response: &RouteResponse,
+    page_idx: usize,
+) -> Response<Full<Bytes>> {
+    let pages = match response {
+        RouteResponse::Pages(pages) => pages,
+        RouteResponse::Error { code, message } => return innertube_error(*code, message),
+    };
+    if pages.is_empty() {
+        return json_response(StatusCode::OK, json!({}));
+    }
+    let Some(path) = pages.get(page_idx) else {
+        return innertube_error(400, format!("No page {page_idx} for continuation"));
+    };
+    let mut fixture = match tokio::fs::read_to_string(path).await {
+        Ok(fixture) => fixture,
+        Err(e) => {
+            return innertube_error(
+                500,
+                format!("Error reading fixture {}: {e}", path.display()),
+            );
+        }
+    };
+    // Fixtures that aren't valid json are served as-is.
+    if let Ok(mut json) = serde_json::from_str::<Value>(&fixture) {
+        let next_token =
+            (page_idx + 1 < pages.len()).then(|| continuation_token(route_idx, page_idx + 1));
+        replace_continuations(&mut json, next_token.as_deref());
+        fixture = json.to_string();
+    }
+    text_response(StatusCode::OK, "application/json; charset=UTF-8", fixture)
+}
+
+async fn upload(state: &ServerState, request: Request<Incoming>) -> Response<Full<Bytes>> {
+    let command = request
+        .headers()
+        .get("x-goog-upload-command")
+        .and_then(|command| command.to_str().ok())
+        .unwrap_or_default()
+        .to_owned();
+    // Drain the body, which may be the song file.
+    let _ = request.into_body().collect().await;
+    if command == "start" {
+        let mut response = text_response(StatusCode::OK, "text/plain", String::new());
+        let upload_url = format!(
+            "{}{UPLOAD_PATH}?upload_id=ytmapi-mock-server",
+            state.base_url
+        );
+        if let Ok(upload_url) = HeaderValue::from_str(&upload_url) {
+            response
+                .headers_mut()
+                .insert("x-goog-upload-url", upload_url);
+        }
+        response
+            .headers_mut()
+            .insert("x-goog-upload-status", HeaderValue::from_static("active"));
+        return response;
+    }
+    json_response(StatusCode::OK, json!({"status": "STATUS_SUCCEEDED"}))
+}
+
+fn homepage() -> Response<Full<Bytes>> {
+    let ytcfg = json!({
+        "INNERTUBE_CLIENT_NAME": "WEB_REMIX",
+        "INNERTUBE_CLIENT_VERSION": CLIENT_VERSION,
+        "VISITOR_DATA": VISITOR_DATA,
+    });
+    let html = format!(
+        "<!DOCTYPE html><html><head><script>ytcfg.set({ytcfg});</script></head><body></body></html>"
+    );
+    text_response(StatusCode::OK, "text/html; charset=utf-8", html)
+}
+
+fn oauth_token() -> Response<Full<Bytes>> {
+    json_response(
+        StatusCode::OK,
+        json!({
+            "access_token": ACCESS_TOKEN,
+            "expires_in": 3599,
+            "refresh_token": "ytmapi-mock-server-refresh-token",
+            "scope": "https://www.googleapis.com/auth/youtube",
+            "token_type": "Bearer",
+        }),
+    )
+}
+
+fn oauth_device_code() -> Response<Full<Bytes>> {
+    json_response(
+        StatusCode::OK,
+        json!({
+            "device_code": "ytmapi-mock-server-device-code",
+            "expires_in": 1800,
+            "interval": 5,
+            "user_code": "MOCK-CODE",
+            "verification_url": "https://www.google.com/device",
+        }),
+    )
+}
+
+/// An error in the format returned by Innertube.
+fn innertube_error(code: u16, message: impl Into<String>) -> Response<Full<Bytes>> {
+    let status = StatusCode::from_u16(code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let status_name = match code {
+        400 => "INVALID_ARGUMENT",
+        401 => "UNAUTHENTICATED",
+        403 => "PERMISSION_DENIED",
+        404 => "NOT_FOUND",
+        _ => "UNKNOWN",
+    };
+    json_response(
+        status,
+        json!({
+            "error": {
+                "code": code,
+                "message": message.into(),
+                "status": status_name,
+            }
+        }),
+    )
+}
+
+fn json_response(status: StatusCode, json: Value) -> Response<Full<Bytes>> {
+    text_response(status, "application/json; charset=UTF-8", json.to_string())
+}
+
+fn text_response(
+    status: StatusCode,
+    content_type: &'static str,
+    text: String,
+) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::from(text)));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    response
+}
@@ -0,0 +1,180 @@
+//! Routes mapping Innertube requests to fixture responses.
+use hyper::HeaderMap;
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// The set of routes served by a [`MockServer`](crate::MockServer).
+#[derive(Debug, Clone, Default)]
+pub struct Routes {
+    pub(crate) routes: Vec<Route>,
+    pub(crate) expired_auth: bool,
+}
+
+/// A route for POST requests to an Innertube endpoint.
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub(crate) endpoint: String,
+    pub(crate) body_matchers: Vec<(String, Value)>,
+    pub(crate) header_matchers: Vec<(String, String)>,
+    pub(crate) response: RouteResponse,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum RouteResponse {
+    /// Fixtures for the initial request and each continuation, in order.
+    Pages(Vec<PathBuf>),
+    Error {
+        code: u16,
+        message: String,
+    },
+}
+
+impl Routes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Add a route.
+    /// If more than one route matches a request, the route with the most body
+    /// fields and headers is used. If that is still ambiguous, the first added
+    /// is used.
+    pub fn with_route(mut self, route: Route) -> Self {
+        self.routes.push(route);
+        self
+    }
+    /// Respond to every Innertube request that has an `Authorization` header
+    /// with the error Innertube returns for expired credentials.
+    pub fn with_expired_auth(mut self) -> Self {
+        self.expired_auth = true;
+        self
+    }
+    /// Find the route for a request to `endpoint`, returning its index.
+    pub(crate) fn find(
+        &self,
+        endpoint: &str,
+        headers: &HeaderMap,
+        body: &Value,
+    ) -> Option<(usize, &Route)> {
+        self.routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route)| route.matches(endpoint, headers, body))
+            // max_by_key returns the last maximum, so iterate in reverse to
+            // prefer the first added route.
+            .rev()
+            .max_by_key(|(_, route)| route.body_matchers.len() + route.header_matchers.len())
+    }
+}
+
+impl Route {
+    /// Route for POST requests to Innertube endpoint `endpoint`, e.g `browse`
+    /// or `music/get_search_suggestions`.
+    /// Responds with an empty json object until a response is set.
+    pub fn post(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            body_matchers: Vec::new(),
+            header_matchers: Vec::new(),
+            response: RouteResponse::Pages(Vec::new()),
+        }
+    }
+    /// Only match requests where the json body contains `value` at `pointer`,
+    /// e.g `("/browseId", "FEmusic_explore")`.
+    pub fn with_body_field(mut self, pointer: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.body_matchers.push((pointer.into(), value.into()));
+        self
+    }
+    /// Only match requests with header `name` set to `value`, e.g
+    /// `("User-Agent", "ytmapi-rs")`. Header names are case insensitive.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.header_matchers.push((name.into(), value.into()));
+        self
+    }
+    /// Respond with the contents of a json fixture.
+    /// Any continuations in the fixture are removed.
+    pub fn respond_with_file(self, path: impl Into<PathBuf>) -> Self {
+        self.respond_with_pages([path])
+    }
+    /// Respond with a chain of json fixtures - the first is returned for the
+    /// initial request, and the rest for each continuation in turn.
+    /// The continuation tokens in each served fixture are replaced so that the
+    /// client will request the next fixture in the chain, and are removed from
+    /// the last fixture.
+    pub fn respond_with_pages(
+        mut self,
+        paths: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Self {
+        self.response = RouteResponse::Pages(paths.into_iter().map(Into::into).collect());
+        self
+    }
+    /// Respond with an Innertube error, e.g `(400, "Request contains an
+    /// invalid argument.")`.
+    pub fn respond_with_error(mut self, code: u16, message: impl Into<String>) -> Self {
+        self.response = RouteResponse::Error {
+            code,
+            message: message.into(),
+        };
+        self
+    }
+    fn matches(&self, endpoint: &str, headers: &HeaderMap, body: &Value) -> bool {
+        self.endpoint == endpoint
+            && self
+                .body_matchers
+                .iter()
+                .all(|(pointer, value)| body.pointer(pointer) == Some(value))
+            && self.header_matchers.iter().all(|(name, value)| {
+                headers
+                    .get_all(name.as_str())
+                    .iter()
+                    .any(|header_value| header_value == value.as_str())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Route, Routes};
+    use hyper::HeaderMap;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn test_find_most_specific_route() {
+        let routes = Routes::new()
+            .with_route(Route::post("browse"))
+            .with_route(Route::post("browse").with_body_field("/browseId", "FEmusic_explore"))
+            .with_route(Route::post("browse").with_body_field("/browseId", "FEmusic_explore"))
+            .with_route(Route::post("search"));
+        let find_idx = |endpoint, body| {
+            routes
+                .find(endpoint, &HeaderMap::new(), &body)
+                .map(|(idx, _)| idx)
+        };
+        assert_eq!(
+            find_idx("browse", json!({"browseId": "FEmusic_explore"})),
+            Some(1)
+        );
+        assert_eq!(
+            find_idx("browse", json!({"browseId": "FEmusic_home"})),
+            Some(0)
+        );
+        assert_eq!(find_idx("search", json!({"query": "Beatles"})), Some(3));
+        assert_eq!(find_idx("next", json!({})), None);
+    }
+    #[test]
+    fn test_find_route_by_header() {
+        let routes = Routes::new()
+            .with_route(Route::post("browse"))
+            .with_route(Route::post("browse").with_header("X-Test", "value"));
+        let mut headers = HeaderMap::new();
+        let find_idx = |headers: &HeaderMap| {
+            routes
+                .find("browse", headers, &json!({}))
+                .map(|(idx, _)| idx)
+        };
+        assert_eq!(find_idx(&headers), Some(0));
+        headers.insert("x-test", "other".parse().unwrap());
+        assert_eq!(find_idx(&headers), Some(0));
+        headers.insert("x-test", "value".parse().unwrap());
+        assert_eq!(find_idx(&headers), Some(1));
+    }
+}
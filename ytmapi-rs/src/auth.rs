@@ -2,11 +2,13 @@
 use crate::Error;
 use crate::client::{Client, QueryResponse};
 use crate::error::Result;
+use crate::instrument::RequestTimer;
 use crate::parse::ProcessedResult;
 use crate::query::{GetQuery, PostQuery};
 use crate::utils::constants::{YTM_API_URL, YTM_PARAMS, YTM_PARAMS_KEY};
 pub use browser::BrowserToken;
 use chrono::Utc;
+pub use custom::{CustomToken, HeaderProvider};
 pub use oauth::{OAuthToken, OAuthTokenGenerator};
 use reqwest::Url;
 use serde_json::json;
@@ -14,6 +16,7 @@ use std::borrow::Cow;
 use std::marker::PhantomData;
 
 pub mod browser;
+pub mod custom;
 pub mod noauth;
 pub mod oauth;
 
@@ -65,13 +68,11 @@ pub(crate) async fn raw_query_post<'a, A: AuthToken, Q: PostQuery>(
     c: &Client,
 ) -> Result<RawResult<'a, Q, A>> {
     let url = format!("{YTM_API_URL}{}{YTM_PARAMS}{YTM_PARAMS_KEY}", q.path());
+    let query_profile = q.client_profile();
+    let profile = query_profile.as_ref().unwrap_or(c.client_profile());
     let mut body = json!({
         "context" : {
-            "client" : {
-                "clientName" : "WEB_REMIX",
-                "clientVersion" : tok.client_version(),
-                "user" : {},
-            },
+            "client" : profile.context(tok.client_version()),
         },
     });
     if let Some(body) = body.as_object_mut() {
@@ -79,9 +80,23 @@ pub(crate) async fn raw_query_post<'a, A: AuthToken, Q: PostQuery>(
     } else {
         unreachable!("Body created in this function as an object")
     };
-    let QueryResponse { text, .. } = c
-        .post_json_query(url, tok.headers()?, &body, &q.params())
-        .await?;
+    // The profile's User-Agent replaces the one provided by the token.
+    let headers = tok
+        .headers()?
+        .into_iter()
+        .filter(|(header, _)| {
+            profile.user_agent.is_none() || !header.eq_ignore_ascii_case("User-Agent")
+        })
+        .chain(
+            profile
+                .user_agent
+                .as_deref()
+                .map(|user_agent| ("User-Agent", user_agent.into())),
+        )
+        .collect::<Vec<_>>();
+    let timer = RequestTimer::start(q.path());
+    let QueryResponse { text, .. } = c.post_json_query(url, headers, &body, &q.params()).await?;
+    timer.finish(text.len());
     Ok(RawResult::from_raw(text, q))
 }
 
@@ -92,9 +107,12 @@ pub(crate) async fn raw_query_get<'a, Q: GetQuery, A: AuthToken>(
 ) -> Result<RawResult<'a, Q, A>> {
     let url = Url::parse_with_params(query.url(), query.params())
         .map_err(|e| Error::web(format!("{e}")))?;
-    let result = client
-        .get_query(url, tok.headers()?, &query.params())
-        .await?;
+    // Headers are collected so that the returned future is Send.
+    let headers = tok.headers()?.into_iter().collect::<Vec<_>>();
+    // Only the path is recorded, as the query string may identify the user.
+    let timer = RequestTimer::start(url.path());
+    let result = client.get_query(url, headers, &query.params()).await?;
+    timer.finish(result.text.len());
     let result = RawResult::from_raw(result.text, query);
     Ok(result)
 }
@@ -102,12 +120,37 @@ pub(crate) async fn raw_query_get<'a, Q: GetQuery, A: AuthToken>(
 /// Marker trait to mark an AuthToken as LoggedIn
 /// To allow Query implementors to write like
 /// `impl<A: LoggedIn> Query<A> for AddSongToPlaylistQuery`
-/// Since AuthToken is sealed, no-one else can implement this.
+/// To run logged in queries using your own credentials, use [`CustomToken`].
 pub trait LoggedIn: AuthToken {}
 
 impl LoggedIn for BrowserToken {}
 impl LoggedIn for OAuthToken {}
 
+/// Process a response, returning an error if the json contains an error code.
+fn process_error_codes<Q, A: AuthToken>(raw: RawResult<Q, A>) -> Result<ProcessedResult<Q>> {
+    let processed = ProcessedResult::try_from(raw)?;
+    // Guard against error codes in json response.
+    // TODO: Check for a response the reflects an expired Headers token
+    if let Some(error) = processed.get_json().pointer("/error") {
+        let Some(code) = error.pointer("/code").and_then(|v| v.as_u64()) else {
+            // TODO: Better error.
+            return Err(Error::response("API reported an error but no code"));
+        };
+        let message = error
+            .pointer("/message")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_default();
+        return Err(Error::other_code(code, message));
+    }
+    Ok(processed)
+}
+
+/// Returns true if a token created at `create_time` is older than `max_age`.
+fn is_stale(create_time: &chrono::DateTime<Utc>, max_age: chrono::TimeDelta) -> bool {
+    Utc::now().signed_duration_since(create_time) > max_age
+}
+
 /// Generate a dummy client version at the provided time.
 /// Original implementation: https://github.com/sigma67/ytmusicapi/blob/459bc40e4ce31584f9d87cf75838a1f404aa472d/ytmusicapi/helpers.py#L35C18-L35C31
 fn fallback_client_version(time: &chrono::DateTime<Utc>) -> String {
@@ -1,19 +1,26 @@
-use super::{AuthToken, RawResult};
+use super::{AuthToken, RawResult, is_stale, process_error_codes};
 use crate::client::Client;
 use crate::error::{Error, Result};
-use crate::parse::ProcessedResult;
 use crate::utils;
 use crate::utils::constants::{USER_AGENT, YTM_URL};
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::path::Path;
 
+/// Authentication token generated from a browser cookie.
+/// Can be serialized and deserialized, to be restored without making a web
+/// request - see [`BrowserToken::validate_or_refresh`].
 #[derive(Clone, Serialize, Deserialize)]
 pub struct BrowserToken {
     sapisid: String,
     client_version: String,
     cookies: String,
+    // Tokens serialized prior to this field being added are treated as created
+    // at the unix epoch, so they will be refreshed on next validation.
+    #[serde(default)]
+    create_time: chrono::DateTime<Utc>,
 }
 
 impl AuthToken for BrowserToken {
@@ -23,23 +30,7 @@ impl AuthToken for BrowserToken {
     fn deserialize_response<Q>(
         raw: RawResult<Q, Self>,
     ) -> Result<crate::parse::ProcessedResult<Q>> {
-        let processed = ProcessedResult::try_from(raw)?;
-        // Guard against error codes in json response.
-        // TODO: Check for a response the reflects an expired Headers token
-        // TODO: Add a test for this
-        if let Some(error) = processed.get_json().pointer("/error") {
-            let Some(code) = error.pointer("/code").and_then(|v| v.as_u64()) else {
-                // TODO: Better error.
-                return Err(Error::response("API reported an error but no code"));
-            };
-            let message = error
-                .pointer("/message")
-                .and_then(|s| s.as_str())
-                .map(|s| s.to_string())
-                .unwrap_or_default();
-            return Err(Error::other_code(code, message));
-        }
-        Ok(processed)
+        process_error_codes(raw)
     }
     fn headers(&self) -> Result<impl IntoIterator<Item = (&str, Cow<'_, str>)>> {
         let hash = utils::hash_sapisid(&self.sapisid);
@@ -90,6 +81,7 @@ impl BrowserToken {
             sapisid,
             client_version,
             cookies,
+            create_time: Utc::now(),
         })
     }
     pub async fn from_cookie_file<P>(path: P, client: &Client) -> Result<Self>
@@ -99,6 +91,28 @@ impl BrowserToken {
         let contents = tokio::fs::read_to_string(path).await?;
         BrowserToken::from_str(&contents, client).await
     }
+    /// The time the client version was obtained from YouTube Music.
+    pub fn create_time(&self) -> chrono::DateTime<Utc> {
+        self.create_time
+    }
+    /// Obtain a new token using the same cookie, fetching the latest client
+    /// version from YouTube Music.
+    pub async fn refresh(&self, client: &Client) -> Result<Self> {
+        BrowserToken::from_str(&self.cookies, client).await
+    }
+    /// Return the token if it was created within `max_age`, otherwise refresh
+    /// it. This allows a restored token to be used without making a web
+    /// request on startup.
+    pub async fn validate_or_refresh(
+        self,
+        client: &Client,
+        max_age: chrono::TimeDelta,
+    ) -> Result<Self> {
+        if is_stale(&self.create_time, max_age) {
+            return self.refresh(client).await;
+        }
+        Ok(self)
+    }
 }
 
 // Don't use default Debug implementation for BrowserToken - contents are
@@ -108,3 +122,21 @@ impl Debug for BrowserToken {
         write!(f, "Private BrowserToken")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BrowserToken;
+
+    #[test]
+    fn test_deserialize_browser_token_without_create_time() {
+        let token: BrowserToken = serde_json::from_str(
+            r#"{"sapisid":"abc","client_version":"1.20240101.01.00","cookies":"SAPISID=abc;"}"#,
+        )
+        .unwrap();
+        assert_eq!(token.create_time(), chrono::DateTime::UNIX_EPOCH);
+        assert!(super::is_stale(
+            &token.create_time(),
+            chrono::TimeDelta::days(1)
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+use super::{AuthToken, LoggedIn, RawResult, fallback_client_version, process_error_codes};
+use crate::error::Result;
+use crate::parse::ProcessedResult;
+use chrono::Utc;
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+/// Source of the credentials for a [`CustomToken`].
+/// Implement this to run logged in queries using credentials that ytmapi-rs
+/// doesn't manage itself, e.g a cookie jar kept up to date by a browser
+/// extension, or a token fetched from a secrets manager.
+pub trait HeaderProvider: Send + Sync {
+    /// HTTP headers to send with each request, e.g `Cookie` and
+    /// `Authorization`. Called for every request, so the provider can return
+    /// the latest credentials.
+    fn headers(&self) -> Result<impl IntoIterator<Item = (&str, Cow<'_, str>)>>;
+    /// Innertube client version to send with each request. By default, a
+    /// version based on the current date is used.
+    fn client_version(&self) -> Cow<'_, str> {
+        fallback_client_version(&Utc::now()).into()
+    }
+}
+
+/// Authentication token using credentials from a user-defined
+/// [`HeaderProvider`]. Responses are checked for errors the same way as
+/// [`BrowserToken`](super::BrowserToken).
+/// # Usage
+/// ```no_run
+/// use std::borrow::Cow;
+/// use ytmapi_rs::auth::{CustomToken, HeaderProvider};
+///
+/// struct SecretsManagerCookie {
+///     cookie: String,
+///     authorization: String,
+/// }
+/// impl HeaderProvider for SecretsManagerCookie {
+///     fn headers(
+///         &self,
+///     ) -> ytmapi_rs::Result<impl IntoIterator<Item = (&str, Cow<'_, str>)>> {
+///         Ok([
+///             ("Content-Type", "application/json".into()),
+///             ("Cookie", self.cookie.as_str().into()),
+///             ("Authorization", self.authorization.as_str().into()),
+///         ])
+///     }
+/// }
+/// # async {
+/// let token = CustomToken::new(SecretsManagerCookie {
+///     cookie: "SAPISID=abc;".to_string(),
+///     authorization: "SAPISIDHASH abc".to_string(),
+/// });
+/// let yt = ytmapi_rs::YtMusic::from_auth_token(token);
+/// let playlists = yt.get_library_playlists().await?;
+/// # Ok::<_, ytmapi_rs::Error>(())
+/// # };
+/// ```
+#[derive(Clone)]
+pub struct CustomToken<H> {
+    provider: H,
+}
+
+impl<H: HeaderProvider> CustomToken<H> {
+    pub fn new(provider: H) -> Self {
+        Self { provider }
+    }
+    pub fn provider(&self) -> &H {
+        &self.provider
+    }
+    pub fn provider_mut(&mut self) -> &mut H {
+        &mut self.provider
+    }
+    pub fn into_provider(self) -> H {
+        self.provider
+    }
+}
+
+impl<H: HeaderProvider> AuthToken for CustomToken<H> {
+    fn headers(&self) -> Result<impl IntoIterator<Item = (&str, Cow<'_, str>)>> {
+        self.provider.headers()
+    }
+    fn client_version(&self) -> Cow<'_, str> {
+        self.provider.client_version()
+    }
+    fn deserialize_response<Q>(raw: RawResult<Q, Self>) -> Result<ProcessedResult<Q>> {
+        process_error_codes(raw)
+    }
+}
+
+impl<H: HeaderProvider> LoggedIn for CustomToken<H> {}
+
+// Don't use default Debug implementation for CustomToken - provider may contain
+// credentials.
+impl<H> Debug for CustomToken<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Private CustomToken")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CustomToken, HeaderProvider};
+    use crate::auth::RawResult;
+    use crate::error::ErrorKind;
+    use crate::query::GetLibraryPlaylistsQuery;
+    use std::borrow::Cow;
+
+    struct StaticHeaders;
+    impl HeaderProvider for StaticHeaders {
+        fn headers(&self) -> crate::Result<impl IntoIterator<Item = (&str, Cow<'_, str>)>> {
+            Ok([("Cookie", "SAPISID=abc;".into())])
+        }
+    }
+
+    #[test]
+    fn test_custom_token_error_response() {
+        let json = r#"{"error":{"code":401,"message":"Request had invalid authentication credentials.","status":"UNAUTHENTICATED"}}"#;
+        let raw = RawResult::<_, CustomToken<StaticHeaders>>::from_raw(
+            json.to_string(),
+            &GetLibraryPlaylistsQuery,
+        );
+        let Err(error) = raw.process() else {
+            panic!("Expected an error")
+        };
+        assert!(matches!(
+            error.into_kind(),
+            ErrorKind::OtherErrorCodeInResponse { code: 401, .. }
+        ));
+    }
+}
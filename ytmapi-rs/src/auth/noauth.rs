@@ -1,4 +1,4 @@
-use super::{AuthToken, RawResult, fallback_client_version};
+use super::{AuthToken, RawResult, fallback_client_version, is_stale};
 use crate::client::Client;
 use crate::error::{Error, Result};
 use crate::parse::ProcessedResult;
@@ -7,6 +7,9 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
+/// Authentication token for unauthenticated use of the API.
+/// Can be serialized and deserialized, to be restored without making a web
+/// request - see [`NoAuthToken::validate_or_refresh`].
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct NoAuthToken {
     create_time: chrono::DateTime<Utc>,
@@ -46,6 +49,23 @@ impl NoAuthToken {
             visitor_id,
         })
     }
+    /// The time the visitor data was obtained from YouTube Music.
+    pub fn create_time(&self) -> chrono::DateTime<Utc> {
+        self.create_time
+    }
+    /// Return the token if it was created within `max_age`, otherwise obtain a
+    /// new one. This allows a restored token to be used without making a web
+    /// request on startup.
+    pub async fn validate_or_refresh(
+        self,
+        client: &Client,
+        max_age: chrono::TimeDelta,
+    ) -> Result<Self> {
+        if is_stale(&self.create_time, max_age) {
+            return NoAuthToken::new(client).await;
+        }
+        Ok(self)
+    }
 }
 
 impl AuthToken for NoAuthToken {
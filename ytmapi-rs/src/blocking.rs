@@ -0,0 +1,342 @@
+//! A blocking (synchronous) API handle, for use outside of an async runtime.
+//! [`YtMusic`] wraps the async [`crate::YtMusic`] and drives it on an
+//! internal runtime, mirroring its methods (including the simplified queries,
+//! if the `simplified-queries` feature is enabled).
+//! # Usage
+//! ```no_run
+//! use ytmapi_rs::blocking::YtMusic;
+//! let yt = YtMusic::from_cookie_file("./cookie.txt")?;
+//! let query = ytmapi_rs::query::GetLibrarySongsQuery::default();
+//! for page in yt.stream(&query).take(2) {
+//!     println!("{:?}", page?);
+//! }
+//! # Ok::<(), ytmapi_rs::Error>(())
+//! ```
+//! # Panics
+//! Methods on [`YtMusic`] must not be called from within an async runtime -
+//! use the async [`crate::YtMusic`] instead.
+//! # Optional
+//! To enable this module, feature `blocking` must be enabled.
+use crate::Result;
+use crate::auth::browser::BrowserToken;
+use crate::auth::noauth::NoAuthToken;
+use crate::auth::{AuthToken, LoggedIn, OAuthToken};
+use crate::common::{ApiOutcome, PlaylistID, VideoID};
+use crate::continuations::ParseFromContinuable;
+use crate::json::Json;
+use crate::playlist_sync::{PlaylistSyncPlan, PlaylistSyncReport};
+use crate::query::{PostQuery, Query};
+use futures::{Stream, StreamExt};
+use std::borrow::Borrow;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+#[cfg(feature = "simplified-queries")]
+mod simplified_queries;
+
+/// A blocking handle to the YouTube Music API, wrapping an async
+/// [`crate::YtMusic`] and the runtime used to drive it.
+/// Cloning is cheap - clones share the same runtime.
+/// # Documentation note
+/// See [`crate::YtMusic`] for the documentation of the methods on this struct.
+#[derive(Debug, Clone)]
+pub struct YtMusic<A: AuthToken> {
+    inner: crate::YtMusic<A>,
+    runtime: Arc<Runtime>,
+}
+
+/// A blocking iterator over the pages of a streamed query, returned by
+/// [`YtMusic::stream`], [`YtMusic::raw_json_stream`] and the `_stream`
+/// simplified queries.
+pub struct BlockingStream<'a, T> {
+    runtime: &'a Runtime,
+    stream: Pin<Box<dyn Stream<Item = Result<T>> + 'a>>,
+}
+
+impl<T> Iterator for BlockingStream<'_, T> {
+    type Item = Result<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+fn new_runtime() -> Result<Runtime> {
+    Ok(tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?)
+}
+
+impl YtMusic<NoAuthToken> {
+    /// Create a new unauthenticated API handle.
+    /// See [`crate::YtMusic::new_unauthenticated`].
+    pub fn new_unauthenticated() -> Result<Self> {
+        let runtime = new_runtime()?;
+        let inner = runtime.block_on(crate::YtMusic::new_unauthenticated())?;
+        Ok(Self::from_parts(inner, runtime))
+    }
+    /// Refresh the internal token if it's older than `max_age`, and return a
+    /// clone of it.
+    /// See [`crate::YtMusic::validate_or_refresh_token`].
+    pub fn validate_or_refresh_token(&mut self, max_age: chrono::TimeDelta) -> Result<NoAuthToken> {
+        self.runtime
+            .block_on(self.inner.validate_or_refresh_token(max_age))
+    }
+}
+impl YtMusic<BrowserToken> {
+    /// Create a new API handle using a real browser authentication cookie saved
+    /// to a file on disk.
+    /// See [`crate::YtMusic::from_cookie_file`].
+    pub fn from_cookie_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let runtime = new_runtime()?;
+        let inner = runtime.block_on(crate::YtMusic::from_cookie_file(path))?;
+        Ok(Self::from_parts(inner, runtime))
+    }
+    /// Create a new API handle using a real browser authentication cookie in a
+    /// String.
+    /// See [`crate::YtMusic::from_cookie`].
+    pub fn from_cookie<S: AsRef<str>>(cookie: S) -> Result<Self> {
+        let runtime = new_runtime()?;
+        let inner = runtime.block_on(crate::YtMusic::from_cookie(cookie))?;
+        Ok(Self::from_parts(inner, runtime))
+    }
+    /// Upload a song to your YouTube Music library. Only available using
+    /// Browser auth.
+    /// See [`crate::YtMusic::upload_song`].
+    pub fn upload_song(&self, file_path: impl AsRef<Path>) -> Result<ApiOutcome> {
+        self.runtime.block_on(self.inner.upload_song(file_path))
+    }
+    /// Refresh the internal browser token if it's older than `max_age`, and
+    /// return a clone of it.
+    /// See [`crate::YtMusic::validate_or_refresh_token`].
+    pub fn validate_or_refresh_token(
+        &mut self,
+        max_age: chrono::TimeDelta,
+    ) -> Result<BrowserToken> {
+        self.runtime
+            .block_on(self.inner.validate_or_refresh_token(max_age))
+    }
+}
+impl YtMusic<OAuthToken> {
+    /// Refresh the internal oauth token, and return a clone of it.
+    /// See [`crate::YtMusic::refresh_token`].
+    pub fn refresh_token(&mut self) -> Result<OAuthToken> {
+        self.runtime.block_on(self.inner.refresh_token())
+    }
+    /// Get a hash of the internal oauth token, for use in comparison
+    /// operations.
+    pub fn get_token_hash(&self) -> u64 {
+        self.inner.get_token_hash()
+    }
+}
+impl<A: AuthToken> YtMusic<A> {
+    /// Create a new API handle using a AuthToken.
+    /// See [`crate::YtMusic::from_auth_token`].
+    pub fn from_auth_token(token: A) -> Result<Self> {
+        Self::from_async(crate::YtMusic::from_auth_token(token))
+    }
+    /// Create a new blocking API handle from an async API handle, e.g one
+    /// created using [`YtMusicBuilder`](crate::YtMusicBuilder).
+    /// # Note
+    /// The async API handle should not have been used on another runtime,
+    /// since the connections in its pool are tied to the runtime they were
+    /// created on.
+    pub fn from_async(inner: crate::YtMusic<A>) -> Result<Self> {
+        Ok(Self::from_parts(inner, new_runtime()?))
+    }
+    fn from_parts(inner: crate::YtMusic<A>, runtime: Runtime) -> Self {
+        Self {
+            inner,
+            runtime: Arc::new(runtime),
+        }
+    }
+    /// Return the source JSON returned by YouTube music for the query, prior to
+    /// deserialization and error processing.
+    /// See [`crate::YtMusic::raw_json_query`].
+    pub fn raw_json_query<Q: Query<A>>(&self, query: impl Borrow<Q>) -> Result<String> {
+        self.runtime.block_on(self.inner.raw_json_query(query))
+    }
+    /// Return a result from YouTube music that has had errors removed and been
+    /// deserialized into parsable JSON.
+    /// See [`crate::YtMusic::json_query`].
+    pub fn json_query<Q: Query<A>>(&self, query: impl Borrow<Q>) -> Result<Json> {
+        self.runtime.block_on(self.inner.json_query(query))
+    }
+    /// Run a Query on the API returning its output.
+    /// # Usage
+    /// ```no_run
+    /// let yt = ytmapi_rs::blocking::YtMusic::from_cookie("")?;
+    /// let query = ytmapi_rs::query::SearchQuery::new("Beatles")
+    ///     .with_filter(ytmapi_rs::query::search::ArtistsFilter);
+    /// let result = yt.query(query)?;
+    /// assert_eq!(result[0].artist, "The Beatles");
+    /// # Ok::<(), ytmapi_rs::Error>(())
+    /// ```
+    pub fn query<Q: Query<A>>(&self, query: impl Borrow<Q>) -> Result<Q::Output> {
+        self.runtime.block_on(self.inner.query(query))
+    }
+    /// Stream a query that has 'continuations', i.e can continue to stream
+    /// results. Each page is fetched when the iterator is advanced.
+    /// See [`crate::YtMusic::stream`].
+    pub fn stream<'a, Q>(&'a self, query: &'a Q) -> BlockingStream<'a, Q::Output>
+    where
+        Q: Query<A>,
+        Q: PostQuery,
+        Q::Output: ParseFromContinuable<Q>,
+    {
+        BlockingStream {
+            runtime: &self.runtime,
+            stream: Box::pin(self.inner.stream(query)),
+        }
+    }
+    /// Return the source JSON from streaming a query that has 'continuations',
+    /// i.e can continue to stream results.
+    /// See [`crate::YtMusic::raw_json_stream`].
+    pub fn raw_json_stream<'a, Q>(&'a self, query: &'a Q) -> BlockingStream<'a, String>
+    where
+        Q: Query<A>,
+        Q: PostQuery,
+        Q::Output: ParseFromContinuable<Q>,
+    {
+        BlockingStream {
+            runtime: &self.runtime,
+            stream: Box::pin(self.inner.raw_json_stream(query)),
+        }
+    }
+    /// Return a reference to the wrapped async API handle.
+    pub fn as_async(&self) -> &crate::YtMusic<A> {
+        &self.inner
+    }
+}
+impl<A: LoggedIn> YtMusic<A> {
+    /// Compute the steps required to sync a playlist to the desired ordered
+    /// list of videos, without changing the playlist (dry run).
+    /// See [`crate::YtMusic::plan_playlist_sync`].
+    pub fn plan_playlist_sync<'a>(
+        &self,
+        playlist_id: PlaylistID<'a>,
+        desired: impl IntoIterator<Item = VideoID<'a>>,
+    ) -> Result<PlaylistSyncPlan<'a>> {
+        self.runtime
+            .block_on(self.inner.plan_playlist_sync(playlist_id, desired))
+    }
+    /// Execute a previously computed plan to sync a playlist, returning the
+    /// outcome of each step.
+    /// See [`crate::YtMusic::execute_playlist_sync`].
+    pub fn execute_playlist_sync<'a>(&self, plan: &PlaylistSyncPlan<'a>) -> PlaylistSyncReport<'a> {
+        self.runtime
+            .block_on(self.inner.execute_playlist_sync(plan))
+    }
+    /// Sync a playlist to the desired ordered list of videos, using the minimum
+    /// number of add, remove and move actions.
+    /// See [`crate::YtMusic::sync_playlist`].
+    pub fn sync_playlist<'a>(
+        &self,
+        playlist_id: PlaylistID<'a>,
+        desired: impl IntoIterator<Item = VideoID<'a>>,
+    ) -> Result<PlaylistSyncReport<'a>> {
+        self.runtime
+            .block_on(self.inner.sync_playlist(playlist_id, desired))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::YtMusic;
+    use crate::YtMusicBuilder;
+    use crate::auth::BrowserToken;
+    use crate::query::{GetLibrarySongsQuery, PostQuery};
+    use tokio::runtime::Runtime;
+    use ytmapi_mock_server::{MockServer, Route, Routes};
+
+    const MOCK_COOKIE: &str = "SAPISID=ytmapi-mock-server; __Secure-3PAPISID=ytmapi-mock-server;";
+    const LIBRARY_SONGS_PAGES: [&str; 2] = [
+        "test_json/get_library_songs_20240701.json",
+        "test_json/get_library_songs_continuation_20240910.json",
+    ];
+
+    /// The mock server is run on its own runtime, since the blocking client
+    /// can't be called from within one.
+    struct MockHarness {
+        yt: YtMusic<BrowserToken>,
+        _server: MockServer,
+        _runtime: Runtime,
+    }
+    fn mock_harness(routes: Routes) -> MockHarness {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start(routes)).unwrap();
+        let inner = runtime
+            .block_on(
+                YtMusicBuilder::new()
+                    .with_base_url(server.base_url())
+                    .with_browser_token_cookie(MOCK_COOKIE.to_string())
+                    .build(),
+            )
+            .unwrap();
+        MockHarness {
+            yt: YtMusic::from_async(inner).unwrap(),
+            _server: server,
+            _runtime: runtime,
+        }
+    }
+    fn library_songs_route() -> Route {
+        let query = GetLibrarySongsQuery::default();
+        query
+            .header()
+            .into_iter()
+            .fold(Route::post(query.path()), |route, (key, value)| {
+                route.with_body_field(format!("/{key}"), value)
+            })
+    }
+    #[test]
+    fn test_blocking_query() {
+        let harness = mock_harness(
+            Routes::new()
+                .with_route(library_songs_route().respond_with_file(LIBRARY_SONGS_PAGES[0])),
+        );
+        let expected = crate::process_json::<_, BrowserToken>(
+            std::fs::read_to_string(LIBRARY_SONGS_PAGES[0]).unwrap(),
+            GetLibrarySongsQuery::default(),
+        )
+        .unwrap();
+        let output = harness.yt.query(GetLibrarySongsQuery::default()).unwrap();
+        pretty_assertions::assert_eq!(output, expected);
+    }
+    #[test]
+    fn test_blocking_stream() {
+        let harness = mock_harness(
+            Routes::new().with_route(library_songs_route().respond_with_pages(LIBRARY_SONGS_PAGES)),
+        );
+        let query = GetLibrarySongsQuery::default();
+        let pages = harness
+            .yt
+            .stream(&query)
+            .collect::<crate::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(pages.len(), LIBRARY_SONGS_PAGES.len());
+        assert!(pages.iter().all(|page| !page.is_empty()));
+    }
+    #[cfg(feature = "simplified-queries")]
+    #[test]
+    fn test_blocking_simplified_queries() {
+        let harness = mock_harness(
+            Routes::new().with_route(library_songs_route().respond_with_pages(LIBRARY_SONGS_PAGES)),
+        );
+        let first_page = harness.yt.get_library_songs().unwrap();
+        let pages = harness
+            .yt
+            .get_library_songs_stream()
+            .collect::<crate::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(pages.len(), LIBRARY_SONGS_PAGES.len());
+        pretty_assertions::assert_eq!(pages[0], first_page);
+    }
+    #[test]
+    fn test_blocking_query_error() {
+        let harness = mock_harness(Routes::new().with_route(
+            library_songs_route().respond_with_error(400, "Request contains an invalid argument."),
+        ));
+        assert!(harness.yt.query(GetLibrarySongsQuery::default()).is_err());
+    }
+}
@@ -0,0 +1,405 @@
+//! Blocking versions of the simplified queries. See
+//! [`crate::simplified_queries`].
+use super::{BlockingStream, YtMusic};
+use crate::Result;
+use crate::auth::{AuthToken, LoggedIn};
+use crate::common::{
+    AlbumID, ApiOutcome, ArtistChannelID, BrowseParams, EpisodeID, FeedbackTokenMarkPlayed,
+    FeedbackTokenMarkUnplayed, FeedbackTokenPinToListenAgain, FeedbackTokenRemoveFromHistory,
+    FeedbackTokenRemoveSearchSuggestion, FeedbackTokenUnpinFromListenAgain, LikeStatus, LyricsID,
+    MoodCategoryParams, PlaylistID, PodcastChannelID, PodcastChannelParams, PodcastID, SetVideoID,
+    SongTrackingUrl, TasteToken, UploadAlbumID, UploadArtistID, UploadEntityID, UserChannelID,
+    UserPlaylistsParams, UserVideosParams, VideoID,
+};
+use crate::parse::{
+    AddPlaylistItem, AddPlaylistVideoResult, GetAlbum, GetArtist, GetArtistAlbumsAlbum,
+    GetPlaylistDetails, GetUser, HistoryPeriod, LibraryArtist, LibraryArtistSubscription,
+    LibraryPlaylist, ListenAgainItem, Lyrics, PlaylistItem, SearchResultAlbum, SearchResultArtist,
+    SearchResultEpisode, SearchResultFeaturedPlaylist, SearchResultPlaylist, SearchResultPodcast,
+    SearchResultProfile, SearchResultSong, SearchResultVideo, SearchResults, SearchSuggestions,
+    Song, UserPlaylist, UserVideo, WatchPlaylistTrack,
+};
+use crate::query::playlist::CreatePlaylistType;
+use crate::query::search::BasicSearch;
+use crate::query::search::filteredsearch::{
+    AlbumsFilter, ArtistsFilter, CommunityPlaylistsFilter, EpisodesFilter, FeaturedPlaylistsFilter,
+    FilteredSearch, PlaylistsFilter, PodcastsFilter, ProfilesFilter, SongsFilter, VideosFilter,
+};
+use crate::query::{
+    AddHistoryItemQuery, CreatePlaylistQuery, DeleteUploadEntityQuery, EditPlaylistQuery,
+    EditSongLibraryStatusQuery, EditUploadSongQuery, GetArtistQuery, GetChannelEpisodesQuery,
+    GetChannelQuery, GetEpisodeQuery, GetExploreQuery, GetLibraryChannelsQuery,
+    GetLibraryPodcastsQuery, GetLibrarySongsQuery, GetLibraryUploadAlbumQuery,
+    GetLibraryUploadAlbumsQuery, GetLibraryUploadArtistQuery, GetLibraryUploadArtistsQuery,
+    GetLibraryUploadSongsQuery, GetMoodCategoriesQuery, GetMoodPlaylistsQuery, GetNewEpisodesQuery,
+    GetNewMusicVideosQuery, GetNewReleasesQuery, GetPodcastQuery, GetSearchSuggestionsQuery,
+    GetTasteProfileQuery, Query, SearchQuery, SetTasteProfileQuery,
+};
+
+/// Generate blocking wrappers that run the async method of the same name on
+/// [`crate::YtMusic`] to completion on the internal runtime.
+/// Items are written as `fn name[generics](args) -> Output;`.
+macro_rules! blocking_queries {
+    ($(
+        $(#[$attr:meta])*
+        fn $name:ident[$($gen:tt)*]($($arg:ident: $arg_ty:ty),* $(,)?) -> $out:ty;
+    )*) => {$(
+        #[doc = concat!("Blocking version of [`crate::YtMusic::", stringify!($name), "`].")]
+        $(#[$attr])*
+        pub fn $name<$($gen)*>(&self, $($arg: $arg_ty),*) -> Result<$out> {
+            self.runtime.block_on(self.inner.$name($($arg),*))
+        }
+    )*};
+}
+
+/// Generate blocking wrappers for the async streaming method of the same name
+/// on [`crate::YtMusic`], returning a [`BlockingStream`] of its pages.
+/// Items are written as `fn name['a, generics](args) -> Page;`, where `'a` is
+/// the lifetime of the returned stream.
+macro_rules! blocking_streams {
+    ($(
+        $(#[$attr:meta])*
+        fn $name:ident[$lt:lifetime $($gen:tt)*]($($arg:ident: $arg_ty:ty),* $(,)?) -> $out:ty;
+    )*) => {$(
+        #[doc = concat!("Blocking version of [`crate::YtMusic::", stringify!($name), "`].")]
+        $(#[$attr])*
+        pub fn $name<$lt $($gen)*>(&$lt self, $($arg: $arg_ty),*) -> BlockingStream<$lt, $out> {
+            BlockingStream {
+                runtime: &self.runtime,
+                stream: Box::pin(self.inner.$name($($arg),*)),
+            }
+        }
+    )*};
+}
+
+impl<A: AuthToken> YtMusic<A> {
+    blocking_queries! {
+        #[deprecated = "To be removed in future release - see issue #353"]
+        #[allow(deprecated)]
+        fn search['a, Q: Into<SearchQuery<'a, BasicSearch>>](query: Q) -> SearchResults;
+        fn search_artists['a, Q: Into<SearchQuery<'a, FilteredSearch<ArtistsFilter>>>](
+            query: Q,
+        ) -> Vec<SearchResultArtist>;
+        fn search_artists_all['a, Q: Into<SearchQuery<'a, FilteredSearch<ArtistsFilter>>>](
+            query: Q,
+            max_items: Option<usize>,
+        ) -> Vec<SearchResultArtist>;
+        fn search_albums['a, Q: Into<SearchQuery<'a, FilteredSearch<AlbumsFilter>>>](
+            query: Q,
+        ) -> Vec<SearchResultAlbum>;
+        fn search_albums_all['a, Q: Into<SearchQuery<'a, FilteredSearch<AlbumsFilter>>>](
+            query: Q,
+            max_items: Option<usize>,
+        ) -> Vec<SearchResultAlbum>;
+        fn search_songs['a, Q: Into<SearchQuery<'a, FilteredSearch<SongsFilter>>>](
+            query: Q,
+        ) -> Vec<SearchResultSong>;
+        fn search_songs_all['a, Q: Into<SearchQuery<'a, FilteredSearch<SongsFilter>>>](
+            query: Q,
+            max_items: Option<usize>,
+        ) -> Vec<SearchResultSong>;
+        fn search_playlists['a, Q: Into<SearchQuery<'a, FilteredSearch<PlaylistsFilter>>>](
+            query: Q,
+        ) -> Vec<SearchResultPlaylist>;
+        fn search_playlists_all['a, Q: Into<SearchQuery<'a, FilteredSearch<PlaylistsFilter>>>](
+            query: Q,
+            max_items: Option<usize>,
+        ) -> Vec<SearchResultPlaylist>;
+        fn search_community_playlists[
+            'a,
+            Q: Into<SearchQuery<'a, FilteredSearch<CommunityPlaylistsFilter>>>,
+        ](query: Q) -> Vec<SearchResultPlaylist>;
+        fn search_community_playlists_all[
+            'a,
+            Q: Into<SearchQuery<'a, FilteredSearch<CommunityPlaylistsFilter>>>,
+        ](query: Q, max_items: Option<usize>) -> Vec<SearchResultPlaylist>;
+        fn search_featured_playlists[
+            'a,
+            Q: Into<SearchQuery<'a, FilteredSearch<FeaturedPlaylistsFilter>>>,
+        ](query: Q) -> Vec<SearchResultFeaturedPlaylist>;
+        fn search_featured_playlists_all[
+            'a,
+            Q: Into<SearchQuery<'a, FilteredSearch<FeaturedPlaylistsFilter>>>,
+        ](query: Q, max_items: Option<usize>) -> Vec<SearchResultFeaturedPlaylist>;
+        fn search_episodes['a, Q: Into<SearchQuery<'a, FilteredSearch<EpisodesFilter>>>](
+            query: Q,
+        ) -> Vec<SearchResultEpisode>;
+        fn search_episodes_all['a, Q: Into<SearchQuery<'a, FilteredSearch<EpisodesFilter>>>](
+            query: Q,
+            max_items: Option<usize>,
+        ) -> Vec<SearchResultEpisode>;
+        fn search_podcasts['a, Q: Into<SearchQuery<'a, FilteredSearch<PodcastsFilter>>>](
+            query: Q,
+        ) -> Vec<SearchResultPodcast>;
+        fn search_podcasts_all['a, Q: Into<SearchQuery<'a, FilteredSearch<PodcastsFilter>>>](
+            query: Q,
+            max_items: Option<usize>,
+        ) -> Vec<SearchResultPodcast>;
+        fn search_videos['a, Q: Into<SearchQuery<'a, FilteredSearch<VideosFilter>>>](
+            query: Q,
+        ) -> Vec<SearchResultVideo>;
+        fn search_videos_all['a, Q: Into<SearchQuery<'a, FilteredSearch<VideosFilter>>>](
+            query: Q,
+            max_items: Option<usize>,
+        ) -> Vec<SearchResultVideo>;
+        fn search_profiles['a, Q: Into<SearchQuery<'a, FilteredSearch<ProfilesFilter>>>](
+            query: Q,
+        ) -> Vec<SearchResultProfile>;
+        fn search_profiles_all['a, Q: Into<SearchQuery<'a, FilteredSearch<ProfilesFilter>>>](
+            query: Q,
+            max_items: Option<usize>,
+        ) -> Vec<SearchResultProfile>;
+        fn get_artist['a](query: impl Into<GetArtistQuery<'a>>) -> GetArtist;
+        fn get_artist_albums['a, T: Into<ArtistChannelID<'a>>, U: Into<BrowseParams<'a>>](
+            channel_id: T,
+            browse_params: U,
+        ) -> Vec<GetArtistAlbumsAlbum>;
+        fn get_album['a, T: Into<AlbumID<'a>>](album_id: T) -> GetAlbum;
+        fn get_watch_playlist_from_video_id['a, S: Into<VideoID<'a>>](
+            video_id: S,
+        ) -> Vec<WatchPlaylistTrack>;
+        fn get_watch_playlist_from_video_id_all['a, S: Into<VideoID<'a>>](
+            video_id: S,
+            max_items: usize,
+        ) -> Vec<WatchPlaylistTrack>;
+        fn get_lyrics_id['a, T: Into<VideoID<'a>>](video_id: T) -> LyricsID<'static>;
+        fn get_lyrics['a, T: Into<LyricsID<'a>>](lyrics_id: T) -> Lyrics;
+        fn get_playlist_tracks['a, T: Into<PlaylistID<'a>>](playlist_id: T) -> Vec<PlaylistItem>;
+        fn get_playlist_tracks_all['a, T: Into<PlaylistID<'a>>](
+            playlist_id: T,
+            max_items: Option<usize>,
+        ) -> Vec<PlaylistItem>;
+        fn get_playlist_details['a, T: Into<PlaylistID<'a>>](playlist_id: T) -> GetPlaylistDetails;
+        fn get_search_suggestions['a, S: Into<GetSearchSuggestionsQuery<'a>>](
+            query: S,
+        ) -> SearchSuggestions;
+        fn get_taste_profile[]() -> <GetTasteProfileQuery as Query<A>>::Output;
+        fn set_taste_profile['a](
+            taste_tokens: impl IntoIterator<Item = TasteToken<'a>>,
+        ) -> <SetTasteProfileQuery<'a> as Query<A>>::Output;
+        fn get_mood_categories[]() -> <GetMoodCategoriesQuery as Query<A>>::Output;
+        fn get_explore[]() -> <GetExploreQuery as Query<A>>::Output;
+        fn get_new_releases[]() -> <GetNewReleasesQuery as Query<A>>::Output;
+        fn get_new_releases_all[](
+            max_items: Option<usize>,
+        ) -> <GetNewReleasesQuery as Query<A>>::Output;
+        fn get_new_music_videos[]() -> <GetNewMusicVideosQuery as Query<A>>::Output;
+        fn get_new_music_videos_all[](
+            max_items: Option<usize>,
+        ) -> <GetNewMusicVideosQuery as Query<A>>::Output;
+        fn get_mood_playlists['a, T: Into<MoodCategoryParams<'a>>](
+            mood_params: T,
+        ) -> <GetMoodPlaylistsQuery<'_> as Query<A>>::Output;
+        fn get_song_tracking_url['a, T: Into<VideoID<'a>>](video_id: T) -> SongTrackingUrl<'static>;
+        fn get_song['a, T: Into<VideoID<'a>>](video_id: T) -> Song;
+        fn get_channel['a](
+            channel_id: impl Into<PodcastChannelID<'a>>,
+        ) -> <GetChannelQuery<'a> as Query<A>>::Output;
+        fn get_channel_episodes['a](
+            channel_id: impl Into<PodcastChannelID<'a>>,
+            podcast_channel_params: impl Into<PodcastChannelParams<'a>>,
+        ) -> <GetChannelEpisodesQuery<'_> as Query<A>>::Output;
+        fn get_podcast['a](
+            podcast_id: impl Into<PodcastID<'a>>,
+        ) -> <GetPodcastQuery<'a> as Query<A>>::Output;
+        fn get_episode['a](
+            episode_id: impl Into<EpisodeID<'a>>,
+        ) -> <GetEpisodeQuery<'a> as Query<A>>::Output;
+        fn get_new_episodes[]() -> <GetNewEpisodesQuery as Query<A>>::Output;
+        fn get_user['a](id: impl Into<UserChannelID<'a>>) -> GetUser;
+        fn get_user_videos['a, T: Into<UserChannelID<'a>>, U: Into<UserVideosParams<'a>>](
+            channel_id: T,
+            browse_params: U,
+        ) -> Vec<UserVideo>;
+        fn get_user_videos_all['a, T: Into<UserChannelID<'a>>, U: Into<UserVideosParams<'a>>](
+            channel_id: T,
+            browse_params: U,
+            max_items: Option<usize>,
+        ) -> Vec<UserVideo>;
+        fn get_user_playlists['a, T: Into<UserChannelID<'a>>, U: Into<UserPlaylistsParams<'a>>](
+            channel_id: T,
+            browse_params: U,
+        ) -> Vec<UserPlaylist>;
+        fn get_user_playlists_all['a, T: Into<UserChannelID<'a>>, U: Into<UserPlaylistsParams<'a>>](
+            channel_id: T,
+            browse_params: U,
+            max_items: Option<usize>,
+        ) -> Vec<UserPlaylist>;
+    }
+    blocking_streams! {
+        fn search_artists_stream['a, Q: Into<SearchQuery<'a, FilteredSearch<ArtistsFilter>>> + 'a](
+            query: Q,
+        ) -> Vec<SearchResultArtist>;
+        fn search_albums_stream['a, Q: Into<SearchQuery<'a, FilteredSearch<AlbumsFilter>>> + 'a](
+            query: Q,
+        ) -> Vec<SearchResultAlbum>;
+        fn search_songs_stream['a, Q: Into<SearchQuery<'a, FilteredSearch<SongsFilter>>> + 'a](
+            query: Q,
+        ) -> Vec<SearchResultSong>;
+        fn search_playlists_stream[
+            'a,
+            Q: Into<SearchQuery<'a, FilteredSearch<PlaylistsFilter>>> + 'a,
+        ](query: Q) -> Vec<SearchResultPlaylist>;
+        fn search_community_playlists_stream[
+            'a,
+            Q: Into<SearchQuery<'a, FilteredSearch<CommunityPlaylistsFilter>>> + 'a,
+        ](query: Q) -> Vec<SearchResultPlaylist>;
+        fn search_featured_playlists_stream[
+            'a,
+            Q: Into<SearchQuery<'a, FilteredSearch<FeaturedPlaylistsFilter>>> + 'a,
+        ](query: Q) -> Vec<SearchResultFeaturedPlaylist>;
+        fn search_episodes_stream[
+            'a,
+            Q: Into<SearchQuery<'a, FilteredSearch<EpisodesFilter>>> + 'a,
+        ](query: Q) -> Vec<SearchResultEpisode>;
+        fn search_podcasts_stream[
+            'a,
+            Q: Into<SearchQuery<'a, FilteredSearch<PodcastsFilter>>> + 'a,
+        ](query: Q) -> Vec<SearchResultPodcast>;
+        fn search_videos_stream['a, Q: Into<SearchQuery<'a, FilteredSearch<VideosFilter>>> + 'a](
+            query: Q,
+        ) -> Vec<SearchResultVideo>;
+        fn search_profiles_stream[
+            'a,
+            Q: Into<SearchQuery<'a, FilteredSearch<ProfilesFilter>>> + 'a,
+        ](query: Q) -> Vec<SearchResultProfile>;
+        fn get_watch_playlist_from_video_id_stream['a, S: Into<VideoID<'a>> + 'a](
+            video_id: S,
+        ) -> Vec<WatchPlaylistTrack>;
+        fn get_new_releases_stream['a]() -> <GetNewReleasesQuery as Query<A>>::Output;
+        fn get_new_music_videos_stream['a]() -> <GetNewMusicVideosQuery as Query<A>>::Output;
+        fn get_playlist_tracks_stream['a, T: Into<PlaylistID<'a>> + 'a](
+            playlist_id: T,
+        ) -> Vec<PlaylistItem>;
+        fn get_user_videos_stream[
+            'a,
+            T: Into<UserChannelID<'a>> + 'a,
+            U: Into<UserVideosParams<'a>> + 'a,
+        ](channel_id: T, browse_params: U) -> Vec<UserVideo>;
+        fn get_user_playlists_stream[
+            'a,
+            T: Into<UserChannelID<'a>> + 'a,
+            U: Into<UserPlaylistsParams<'a>> + 'a,
+        ](channel_id: T, browse_params: U) -> Vec<UserPlaylist>;
+    }
+}
+
+impl<A: LoggedIn> YtMusic<A> {
+    blocking_queries! {
+        fn remove_playlist_items['a, T: Into<PlaylistID<'a>>](
+            playlist_id: T,
+            video_items: impl IntoIterator<Item = SetVideoID<'a>>,
+        ) -> ();
+        fn edit_playlist[](query: EditPlaylistQuery<'_>) -> ApiOutcome;
+        fn get_library_upload_songs[]() -> <GetLibraryUploadSongsQuery as Query<A>>::Output;
+        fn get_library_upload_songs_all[](
+            max_items: Option<usize>,
+        ) -> <GetLibraryUploadSongsQuery as Query<A>>::Output;
+        fn get_library_upload_artists[]() -> <GetLibraryUploadArtistsQuery as Query<A>>::Output;
+        fn get_library_upload_artists_all[](
+            max_items: Option<usize>,
+        ) -> <GetLibraryUploadArtistsQuery as Query<A>>::Output;
+        fn get_library_upload_albums[]() -> <GetLibraryUploadAlbumsQuery as Query<A>>::Output;
+        fn get_library_upload_albums_all[](
+            max_items: Option<usize>,
+        ) -> <GetLibraryUploadAlbumsQuery as Query<A>>::Output;
+        fn get_library_upload_album['a, T: Into<UploadAlbumID<'a>>](
+            upload_album_id: T,
+        ) -> <GetLibraryUploadAlbumQuery<'_> as Query<A>>::Output;
+        fn get_library_upload_artist['a, T: Into<UploadArtistID<'a>>](
+            upload_artist_id: T,
+        ) -> <GetLibraryUploadArtistQuery<'_> as Query<A>>::Output;
+        fn get_library_upload_artist_all['a, T: Into<UploadArtistID<'a>>](
+            upload_artist_id: T,
+            max_items: Option<usize>,
+        ) -> <GetLibraryUploadArtistQuery<'_> as Query<A>>::Output;
+        fn delete_upload_entity['a, T: Into<UploadEntityID<'a>>](
+            upload_entity_id: T,
+        ) -> <DeleteUploadEntityQuery<'_> as Query<A>>::Output;
+        fn edit_upload_song[](query: EditUploadSongQuery<'_>) -> ApiOutcome;
+        fn remove_history_items['a](
+            feedback_tokens: impl IntoIterator<Item = FeedbackTokenRemoveFromHistory<'a>>,
+        ) -> Vec<ApiOutcome>;
+        fn remove_search_suggestions['a](
+            feedback_tokens: impl IntoIterator<Item = FeedbackTokenRemoveSearchSuggestion<'a>>,
+        ) -> Vec<ApiOutcome>;
+        fn edit_song_library_status[](query: EditSongLibraryStatusQuery<'_>) -> Vec<ApiOutcome>;
+        fn rate_song['a, T: Into<VideoID<'a>>](video_id: T, rating: LikeStatus) -> ();
+        fn rate_playlist['a, T: Into<PlaylistID<'a>>](playlist_id: T, rating: LikeStatus) -> ();
+        fn delete_playlist['a, T: Into<PlaylistID<'a>>](playlist_id: T) -> ();
+        fn create_playlist[T: CreatePlaylistType](
+            query: CreatePlaylistQuery<'_, T>,
+        ) -> PlaylistID<'static>;
+        fn add_video_items_to_playlist['a, T: Into<PlaylistID<'a>>](
+            playlist_id: T,
+            video_ids: impl IntoIterator<Item = VideoID<'a>>,
+        ) -> Vec<AddPlaylistVideoResult>;
+        fn add_playlist_to_playlist['a, T: Into<PlaylistID<'a>>, U: Into<PlaylistID<'a>>](
+            destination_playlist: T,
+            source_playlist: U,
+        ) -> Vec<AddPlaylistItem>;
+        fn get_library_playlists[]() -> Vec<LibraryPlaylist>;
+        fn get_library_playlists_all[](max_items: Option<usize>) -> Vec<LibraryPlaylist>;
+        fn get_library_artists[]() -> Vec<LibraryArtist>;
+        fn get_library_artists_all[](max_items: Option<usize>) -> Vec<LibraryArtist>;
+        fn get_library_songs[]() -> <GetLibrarySongsQuery as Query<A>>::Output;
+        fn get_library_songs_all[](
+            max_items: Option<usize>,
+        ) -> <GetLibrarySongsQuery as Query<A>>::Output;
+        fn get_library_albums[]() -> Vec<SearchResultAlbum>;
+        fn get_library_albums_all[](max_items: Option<usize>) -> Vec<SearchResultAlbum>;
+        fn get_library_artist_subscriptions[]() -> Vec<LibraryArtistSubscription>;
+        fn get_library_artist_subscriptions_all[](
+            max_items: Option<usize>,
+        ) -> Vec<LibraryArtistSubscription>;
+        fn get_library_podcasts[]() -> <GetLibraryPodcastsQuery as Query<A>>::Output;
+        fn get_library_podcasts_all[](
+            max_items: Option<usize>,
+        ) -> <GetLibraryPodcastsQuery as Query<A>>::Output;
+        fn get_library_channels[]() -> <GetLibraryChannelsQuery as Query<A>>::Output;
+        fn get_library_channels_all[](
+            max_items: Option<usize>,
+        ) -> <GetLibraryChannelsQuery as Query<A>>::Output;
+        fn get_history[]() -> Vec<HistoryPeriod>;
+        fn add_history_item['a, T: Into<SongTrackingUrl<'a>>](
+            song_url: T,
+        ) -> <AddHistoryItemQuery<'a> as Query<A>>::Output;
+        fn subscribe_artist['a](channel_id: impl Into<ArtistChannelID<'a>>) -> ();
+        fn unsubscribe_artists['a](
+            channels: impl IntoIterator<Item = impl Into<ArtistChannelID<'a>>>,
+        ) -> ();
+        fn subscribe_podcast['a](podcast_id: impl Into<PodcastID<'a>>) -> ();
+        fn unsubscribe_podcast['a](podcast_id: impl Into<PodcastID<'a>>) -> ();
+        fn mark_episode_played['a](
+            feedback_token: impl Into<FeedbackTokenMarkPlayed<'a>>,
+        ) -> ApiOutcome;
+        fn mark_episode_unplayed['a](
+            feedback_token: impl Into<FeedbackTokenMarkUnplayed<'a>>,
+        ) -> ApiOutcome;
+        fn get_listen_again[]() -> Vec<ListenAgainItem>;
+        fn pin_to_listen_again['a](
+            feedback_token: impl Into<FeedbackTokenPinToListenAgain<'a>>,
+        ) -> ApiOutcome;
+        fn unpin_from_listen_again['a](
+            feedback_token: impl Into<FeedbackTokenUnpinFromListenAgain<'a>>,
+        ) -> ApiOutcome;
+    }
+    blocking_streams! {
+        fn get_library_upload_songs_stream['a](
+        ) -> <GetLibraryUploadSongsQuery as Query<A>>::Output;
+        fn get_library_upload_artists_stream['a](
+        ) -> <GetLibraryUploadArtistsQuery as Query<A>>::Output;
+        fn get_library_upload_albums_stream['a](
+        ) -> <GetLibraryUploadAlbumsQuery as Query<A>>::Output;
+        fn get_library_upload_artist_stream['a, T: Into<UploadArtistID<'a>> + 'a](
+            upload_artist_id: T,
+        ) -> <GetLibraryUploadArtistQuery<'a> as Query<A>>::Output;
+        fn get_library_playlists_stream['a]() -> Vec<LibraryPlaylist>;
+        fn get_library_artists_stream['a]() -> Vec<LibraryArtist>;
+        fn get_library_songs_stream['a]() -> <GetLibrarySongsQuery as Query<A>>::Output;
+        fn get_library_albums_stream['a]() -> Vec<SearchResultAlbum>;
+        fn get_library_artist_subscriptions_stream['a]() -> Vec<LibraryArtistSubscription>;
+        fn get_library_podcasts_stream['a]() -> <GetLibraryPodcastsQuery as Query<A>>::Output;
+        fn get_library_channels_stream['a]() -> <GetLibraryChannelsQuery as Query<A>>::Output;
+    }
+}
@@ -24,11 +24,14 @@ pub async fn main() -> Result<(), ytmapi_rs::Error> {
 "##
 )]
 use crate::{
-    Result, YtMusic,
+    Error, Result, YtMusic,
     auth::{BrowserToken, OAuthToken},
-    client::Client,
+    client::{Client, RequestOptions, parse_base_url},
+    client_profile::ClientProfile,
 };
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use std::path::Path;
+use std::time::Duration;
 
 #[derive(Default)]
 pub enum ClientOptions {
@@ -38,7 +41,6 @@ pub enum ClientOptions {
     Rustls,
     #[cfg(feature = "native-tls")]
     NativeTls,
-    Existing(Client),
 }
 
 /// Helper struct for YtMusicBuilder.
@@ -48,52 +50,168 @@ pub struct FromCookie(String);
 /// Helper struct for YtMusicBuilder.
 pub struct FromCookieFile<T>(T);
 
-/// Builder to build more complex YtMusic.
-pub struct YtMusicBuilder<T> {
+/// Helper struct for YtMusicBuilder - a new client will be built, using the
+/// selected TLS backend, proxy and connect timeout.
+#[derive(Default)]
+pub struct NewClient {
     client_options: ClientOptions,
+    proxy: Option<String>,
+    connect_timeout: Option<Duration>,
+}
+/// Helper struct for YtMusicBuilder - an existing client will be used.
+pub struct ExistingClient(Client);
+
+mod private {
+    pub trait Sealed {}
+}
+/// Source of the client used by YtMusicBuilder, either [`NewClient`] or
+/// [`ExistingClient`].
+pub trait ClientSource: private::Sealed {
+    #[doc(hidden)]
+    fn into_client(self) -> Result<Client>;
+}
+impl private::Sealed for NewClient {}
+impl ClientSource for NewClient {
+    fn into_client(self) -> Result<Client> {
+        let NewClient {
+            client_options,
+            proxy,
+            connect_timeout,
+        } = self;
+        let mut builder = match client_options {
+            ClientOptions::Default => reqwest::Client::builder(),
+            #[cfg(feature = "rustls")]
+            ClientOptions::Rustls => reqwest::Client::builder().use_rustls_tls(),
+            #[cfg(feature = "native-tls")]
+            ClientOptions::NativeTls => reqwest::Client::builder().use_native_tls(),
+        };
+        if let Some(proxy) = proxy {
+            let proxy = reqwest::Proxy::all(&proxy).map_err(|_| {
+                Error::invalid_client_options(format!("invalid proxy url: {proxy}"))
+            })?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(connect_timeout) = connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        Client::from_reqwest_builder(builder)
+    }
+}
+impl private::Sealed for ExistingClient {}
+impl ClientSource for ExistingClient {
+    fn into_client(self) -> Result<Client> {
+        Ok(self.0)
+    }
+}
+
+/// Options applied to every request sent by the client when building YtMusic.
+#[derive(Default)]
+struct ClientConfig {
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    headers: Vec<(String, String)>,
+    client_profile: Option<ClientProfile>,
+    base_url: Option<String>,
+}
+
+/// Builder to build more complex YtMusic.
+/// # Client configuration
+/// The request timeout, user agent, extra headers, client profile and base url
+/// are applied to every request, so can be used with a client passed in using
+/// `with_client`.
+/// The TLS backend, proxy and connect timeout are applied when the underlying
+/// `reqwest::Client` is built, so are only available when the builder builds
+/// its own client - i.e not after `with_client` or `new_with_client`.
+pub struct YtMusicBuilder<T, C = NewClient> {
+    client: C,
+    client_config: ClientConfig,
     token: T,
 }
 
-impl<T> YtMusicBuilder<T> {
-    pub fn with_default_tls(mut self) -> Self {
-        self.client_options = ClientOptions::Default;
+impl<T, C> YtMusicBuilder<T, C> {
+    /// Use an existing client, instead of building a new one.
+    /// # Note
+    /// A proxy and connect timeout can't be applied to an existing client, as
+    /// `reqwest` only allows setting them when the `reqwest::Client` is built.
+    /// To use them with your own client, set them using
+    /// `reqwest::ClientBuilder` before passing it to
+    /// `Client::new_from_reqwest_client` (requires feature `reqwest`).
+    pub fn with_client(self, client: Client) -> YtMusicBuilder<T, ExistingClient> {
+        let YtMusicBuilder {
+            client: _,
+            client_config,
+            token,
+        } = self;
+        YtMusicBuilder {
+            client: ExistingClient(client),
+            client_config,
+            token,
+        }
+    }
+    /// Set a timeout for each request, from when the request starts connecting
+    /// until the response body has finished.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client_config.timeout = Some(timeout);
         self
     }
-    pub fn with_client(mut self, client: Client) -> Self {
-        self.client_options = ClientOptions::Existing(client);
+    /// Use a custom User-Agent for all requests, instead of the default
+    /// browser User-Agent. This takes precedence over the User-Agent of any
+    /// [`ClientProfile`].
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.client_config.user_agent = Some(user_agent.into());
         self
     }
-    #[cfg(feature = "rustls")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
-    pub fn with_rustls_tls(mut self) -> Self {
-        self.client_options = ClientOptions::Rustls;
+    /// Add an extra header to all requests. If the header is also set by the
+    /// auth token, this value will replace it.
+    pub fn with_header(mut self, header: impl Into<String>, value: impl Into<String>) -> Self {
+        self.client_config
+            .headers
+            .push((header.into(), value.into()));
         self
     }
-    #[cfg(feature = "native-tls")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "native-tls")))]
-    pub fn with_native_tls(mut self) -> Self {
-        self.client_options = ClientOptions::NativeTls;
+    /// Set the default Innertube client profile, used for queries that don't
+    /// request a specific profile.
+    /// # NOTE
+    /// Response parsers are written against the default `WEB_REMIX` client,
+    /// so most queries will fail to parse with a different default profile -
+    /// see [`crate::client_profile`].
+    pub fn with_client_profile(mut self, client_profile: ClientProfile) -> Self {
+        self.client_config.client_profile = Some(client_profile);
         self
     }
-    pub fn with_auth_token<A: AuthToken>(self, token: A) -> YtMusicBuilder<A> {
+    /// Send all requests to `base_url` instead of the YouTube servers, keeping
+    /// the path and query of each request. Intended for testing against a
+    /// local server, such as `ytmapi-mock-server`. Building will return an
+    /// error if the url is not a valid http(s) url.
+    // Test hook, so not part of the stable API.
+    #[doc(hidden)]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.client_config.base_url = Some(base_url.into());
+        self
+    }
+    pub fn with_auth_token<A: AuthToken>(self, token: A) -> YtMusicBuilder<A, C> {
         let YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token: _,
         } = self;
         YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token,
         }
     }
     // TODO: Improve how this handles building client.
-    pub fn with_browser_token_cookie(self, cookie: String) -> YtMusicBuilder<FromCookie> {
+    pub fn with_browser_token_cookie(self, cookie: String) -> YtMusicBuilder<FromCookie, C> {
         let YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token: _,
         } = self;
         let token = FromCookie(cookie);
         YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token,
         }
     }
@@ -101,58 +219,105 @@ impl<T> YtMusicBuilder<T> {
     pub fn with_browser_token_cookie_file<P: AsRef<Path>>(
         self,
         cookie_file: P,
-    ) -> YtMusicBuilder<FromCookieFile<P>> {
+    ) -> YtMusicBuilder<FromCookieFile<P>, C> {
         let YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token: _,
         } = self;
         let token = FromCookieFile(cookie_file);
         YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token,
         }
     }
     #[deprecated = "Use generic `with_auth_token` instead"]
-    pub fn with_browser_token(self, token: BrowserToken) -> YtMusicBuilder<BrowserToken> {
+    pub fn with_browser_token(self, token: BrowserToken) -> YtMusicBuilder<BrowserToken, C> {
         let YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token: _,
         } = self;
         YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token,
         }
     }
     #[deprecated = "Use generic `with_auth_token` instead"]
-    pub fn with_oauth_token(self, token: OAuthToken) -> YtMusicBuilder<OAuthToken> {
+    pub fn with_oauth_token(self, token: OAuthToken) -> YtMusicBuilder<OAuthToken, C> {
         let YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token: _,
         } = self;
         YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token,
         }
     }
 }
-impl YtMusicBuilder<FromCookie> {
+impl<T> YtMusicBuilder<T, NewClient> {
+    pub fn with_default_tls(mut self) -> Self {
+        self.client.client_options = ClientOptions::Default;
+        self
+    }
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    pub fn with_rustls_tls(mut self) -> Self {
+        self.client.client_options = ClientOptions::Rustls;
+        self
+    }
+    #[cfg(feature = "native-tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "native-tls")))]
+    pub fn with_native_tls(mut self) -> Self {
+        self.client.client_options = ClientOptions::NativeTls;
+        self
+    }
+    /// Send all requests via a proxy.
+    /// Supports http, https and socks5 proxy urls, e.g `socks5://127.0.0.1:1080`
+    /// (socks5 requires feature `socks`). Building will return an error if the
+    /// url is not a valid proxy url.
+    /// # Note
+    /// Only available when the builder builds its own client. `reqwest` has no
+    /// way to rebuild an existing `reqwest::Client` with a proxy, so a client
+    /// passed in using `with_client` must have its proxy set on the
+    /// `reqwest::ClientBuilder` it was built from.
+    pub fn with_proxy(mut self, proxy_url: impl Into<String>) -> Self {
+        self.client.proxy = Some(proxy_url.into());
+        self
+    }
+    /// Set a timeout for only the connect phase of the client.
+    /// # Note
+    /// Only available when the builder builds its own client - see
+    /// [`YtMusicBuilder::with_proxy`].
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.client.connect_timeout = Some(timeout);
+        self
+    }
+}
+impl<C: ClientSource> YtMusicBuilder<FromCookie, C> {
     pub async fn build(self) -> Result<YtMusic<BrowserToken>> {
         let YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token: FromCookie(cookie),
         } = self;
-        let client = build_client(client_options)?;
+        let client = build_client(client, client_config)?;
         let token = BrowserToken::from_str(cookie.as_ref(), &client).await?;
         Ok(YtMusic { client, token })
     }
 }
-impl<P: AsRef<Path>> YtMusicBuilder<FromCookieFile<P>> {
+impl<P: AsRef<Path>, C: ClientSource> YtMusicBuilder<FromCookieFile<P>, C> {
     pub async fn build(self) -> Result<YtMusic<BrowserToken>> {
         let YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token: FromCookieFile(cookie_file),
         } = self;
-        let client = build_client(client_options)?;
+        let client = build_client(client, client_config)?;
         let token = BrowserToken::from_cookie_file(cookie_file, &client).await?;
         Ok(YtMusic { client, token })
     }
@@ -164,58 +329,218 @@ impl YtMusicBuilder<NoToken> {
     #[allow(clippy::new_without_default)]
     pub fn new() -> YtMusicBuilder<NoToken> {
         YtMusicBuilder {
-            client_options: ClientOptions::Default,
+            client: NewClient::default(),
+            client_config: ClientConfig::default(),
             token: NoToken,
         }
     }
-    pub fn new_with_client(client: Client) -> YtMusicBuilder<NoToken> {
+    /// Create a builder using an existing client, instead of building a new
+    /// one. See [`YtMusicBuilder::with_client`] for the options that can't be
+    /// applied to an existing client.
+    pub fn new_with_client(client: Client) -> YtMusicBuilder<NoToken, ExistingClient> {
         YtMusicBuilder {
-            client_options: ClientOptions::Existing(client),
+            client: ExistingClient(client),
+            client_config: ClientConfig::default(),
             token: NoToken,
         }
     }
     #[cfg(feature = "rustls")]
     #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
     pub fn new_rustls_tls() -> YtMusicBuilder<NoToken> {
-        YtMusicBuilder {
-            client_options: ClientOptions::Rustls,
-            token: NoToken,
-        }
+        YtMusicBuilder::new().with_rustls_tls()
     }
     #[cfg(feature = "native-tls")]
     #[cfg_attr(docsrs, doc(cfg(feature = "native-tls")))]
     pub fn new_native_tls() -> Self {
-        YtMusicBuilder {
-            client_options: ClientOptions::NativeTls,
-            token: NoToken,
-        }
+        YtMusicBuilder::new().with_native_tls()
     }
+}
+impl<C: ClientSource> YtMusicBuilder<NoToken, C> {
     pub async fn build(self) -> Result<YtMusic<NoAuthToken>> {
-        let YtMusicBuilder { client_options, .. } = self;
-        let client = build_client(client_options)?;
+        let YtMusicBuilder {
+            client,
+            client_config,
+            ..
+        } = self;
+        let client = build_client(client, client_config)?;
         let token = NoAuthToken::new(&client).await?;
         Ok(YtMusic { client, token })
     }
 }
 
-impl<A: AuthToken> YtMusicBuilder<A> {
+impl<A: AuthToken, C: ClientSource> YtMusicBuilder<A, C> {
     pub fn build(self) -> Result<YtMusic<A>> {
         let YtMusicBuilder {
-            client_options,
+            client,
+            client_config,
             token,
         } = self;
-        let client = build_client(client_options)?;
+        let client = build_client(client, client_config)?;
         Ok(YtMusic { client, token })
     }
 }
 
-fn build_client(client_options: ClientOptions) -> Result<Client> {
-    match client_options {
-        ClientOptions::Default => Client::new(),
-        #[cfg(feature = "rustls")]
-        ClientOptions::Rustls => Client::new_rustls_tls(),
-        #[cfg(feature = "native-tls")]
-        ClientOptions::NativeTls => Client::new_native_tls(),
-        ClientOptions::Existing(client) => Ok(client),
+fn build_client(client: impl ClientSource, client_config: ClientConfig) -> Result<Client> {
+    let ClientConfig {
+        timeout,
+        user_agent,
+        headers,
+        client_profile,
+        base_url,
+    } = client_config;
+    let client = client.into_client()?;
+    let user_agent = user_agent
+        .map(|user_agent| {
+            HeaderValue::from_str(&user_agent).map_err(|_| Error::invalid_user_agent(user_agent))
+        })
+        .transpose()?;
+    let headers = headers
+        .into_iter()
+        .map(|(header, value)| {
+            let header_name = HeaderName::from_bytes(header.as_bytes())
+                .map_err(|_| Error::invalid_client_options(format!("invalid header: {header}")))?;
+            // The value isn't included in the error, as it may be sensitive (e.g a
+            // cookie).
+            let value = HeaderValue::from_str(&value).map_err(|_| {
+                Error::invalid_client_options(format!("invalid value for header: {header}"))
+            })?;
+            Ok((header_name, value))
+        })
+        .collect::<Result<HeaderMap>>()?;
+    let base_url = base_url
+        .map(|base_url| parse_base_url(&base_url).map(Box::new))
+        .transpose()?;
+    let client = client.with_request_options(RequestOptions {
+        timeout,
+        user_agent,
+        headers,
+        base_url,
+    });
+    Ok(match client_profile {
+        Some(client_profile) => client.with_client_profile(client_profile),
+        None => client,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClientSource, FromCookie, NoToken, YtMusicBuilder};
+    use crate::Client;
+    use crate::client_profile::ClientProfile;
+    use crate::error::ErrorKind;
+    use crate::query::GetLibrarySongsQuery;
+    use std::time::Duration;
+    use ytmapi_mock_server::{MockServer, Route, Routes};
+
+    const MOCK_COOKIE: &str = "SAPISID=ytmapi-mock-server; __Secure-3PAPISID=ytmapi-mock-server;";
+    const LIBRARY_SONGS: &str = "test_json/get_library_songs_20240701.json";
+
+    async fn library_songs_server(route: Route) -> MockServer {
+        let routes = Routes::new().with_route(
+            route
+                .with_body_field("/browseId", "FEmusic_liked_videos")
+                .respond_with_file(LIBRARY_SONGS),
+        );
+        MockServer::start(routes).await.unwrap()
+    }
+    /// Build the client and run a query that only succeeds if the mock server
+    /// route matched the request.
+    async fn query_library_songs<C: ClientSource>(
+        builder: YtMusicBuilder<FromCookie, C>,
+    ) -> crate::Result<()> {
+        let yt = builder.build().await?;
+        yt.query(GetLibrarySongsQuery::default()).await.map(|_| ())
+    }
+    fn with_request_options<C>(
+        builder: YtMusicBuilder<NoToken, C>,
+        server: &MockServer,
+    ) -> YtMusicBuilder<FromCookie, C> {
+        builder
+            .with_base_url(server.base_url())
+            .with_user_agent("ytmapi-rs-test")
+            .with_header("X-Ytmapi-Test", "value")
+            .with_timeout(Duration::from_secs(10))
+            .with_browser_token_cookie(MOCK_COOKIE.to_string())
+    }
+    fn expect_invalid_client_options(result: crate::Result<()>) -> String {
+        let Err(e) = result else {
+            panic!("Expected InvalidClientOptions error, but building succeeded");
+        };
+        let display = e.to_string();
+        match e.into_kind() {
+            ErrorKind::InvalidClientOptions { message } => message,
+            _ => panic!("Expected InvalidClientOptions error, got {display}"),
+        }
+    }
+    #[tokio::test]
+    async fn test_request_options_applied() {
+        let server = library_songs_server(
+            Route::post("browse")
+                .with_header("User-Agent", "ytmapi-rs-test")
+                .with_header("X-Ytmapi-Test", "value"),
+        )
+        .await;
+        query_library_songs(with_request_options(YtMusicBuilder::new(), &server))
+            .await
+            .unwrap();
+        // Options that are applied per request can also be used with an existing
+        // client.
+        query_library_songs(with_request_options(
+            YtMusicBuilder::new_with_client(Client::new().unwrap()),
+            &server,
+        ))
+        .await
+        .unwrap();
+    }
+    #[tokio::test]
+    async fn test_client_profile_applied() {
+        let server = library_songs_server(
+            Route::post("browse").with_body_field("/context/client/clientName", "TEST_CLIENT"),
+        )
+        .await;
+        let builder = YtMusicBuilder::new()
+            .with_base_url(server.base_url())
+            .with_client_profile(ClientProfile {
+                name: "TEST_CLIENT".into(),
+                ..ClientProfile::web_remix()
+            })
+            .with_browser_token_cookie(MOCK_COOKIE.to_string());
+        query_library_songs(builder).await.unwrap();
+    }
+    #[tokio::test]
+    async fn test_proxy_applied() {
+        let server = library_songs_server(Route::post("browse")).await;
+        // The base url can't be resolved, so requests only reach the mock server
+        // via the proxy.
+        let builder = YtMusicBuilder::new()
+            .with_base_url("http://ytmapi-rs-proxy-test.invalid")
+            .with_proxy(server.base_url())
+            .with_connect_timeout(Duration::from_secs(10))
+            .with_browser_token_cookie(MOCK_COOKIE.to_string());
+        query_library_songs(builder).await.unwrap();
+    }
+    #[tokio::test]
+    async fn test_invalid_options_error() {
+        let build = |builder: YtMusicBuilder<_>| {
+            query_library_songs(builder.with_browser_token_cookie(MOCK_COOKIE.to_string()))
+        };
+        let message = expect_invalid_client_options(
+            build(YtMusicBuilder::new().with_proxy("not a url")).await,
+        );
+        assert!(message.contains("not a url"), "{message}");
+        let message = expect_invalid_client_options(
+            build(YtMusicBuilder::new().with_header("Bad Header", "value")).await,
+        );
+        assert!(message.contains("Bad Header"), "{message}");
+        // The value may be sensitive, so isn't included in the error.
+        let message = expect_invalid_client_options(
+            build(YtMusicBuilder::new().with_header("X-Secret", "secret\nvalue")).await,
+        );
+        assert!(message.contains("X-Secret"), "{message}");
+        assert!(!message.contains("secret\nvalue"), "{message}");
+        let message = expect_invalid_client_options(
+            build(YtMusicBuilder::new().with_base_url("ftp://localhost")).await,
+        );
+        assert!(message.contains("ftp://localhost"), "{message}");
     }
 }
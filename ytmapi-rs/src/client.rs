@@ -1,7 +1,11 @@
 //! This module contains the basic HTTP client used in this library.
+use crate::client_profile::ClientProfile;
 use crate::{Error, Result};
+use reqwest::header::{HeaderMap, HeaderValue};
 use serde::Serialize;
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Basic HTTP client using TLS wrapping a `reqwest::Client`,
 /// with the minimum required features to call YouTube Music queries.
@@ -9,6 +13,81 @@ use std::borrow::Cow;
 #[derive(Debug, Clone)]
 pub struct Client {
     inner: reqwest::Client,
+    request_options: RequestOptions,
+    // Arc to keep Client cheap to clone.
+    client_profile: Arc<ClientProfile>,
+    #[cfg(feature = "tower")]
+    transport: Option<TowerTransport>,
+}
+/// Options applied to every request sent by the client, regardless of the
+/// underlying `reqwest::Client` or transport.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RequestOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) user_agent: Option<HeaderValue>,
+    pub(crate) headers: HeaderMap,
+    // Boxed, as Url is large relative to the other options.
+    pub(crate) base_url: Option<Box<reqwest::Url>>,
+}
+impl RequestOptions {
+    /// Merge other into self - options set in other take priority.
+    fn merge(&mut self, other: RequestOptions) {
+        let RequestOptions {
+            timeout,
+            user_agent,
+            headers,
+            base_url,
+        } = other;
+        self.timeout = timeout.or(self.timeout);
+        self.user_agent = user_agent.or(self.user_agent.take());
+        self.headers.extend(headers);
+        self.base_url = base_url.or(self.base_url.take());
+    }
+    fn apply(&self, request: &mut reqwest::Request) {
+        if let Some(timeout) = self.timeout {
+            *request.timeout_mut() = Some(timeout);
+        }
+        for (header, value) in &self.headers {
+            request.headers_mut().insert(header, value.clone());
+        }
+        // Replaces the User-Agent set by the AuthToken.
+        if let Some(user_agent) = &self.user_agent {
+            request
+                .headers_mut()
+                .insert(reqwest::header::USER_AGENT, user_agent.clone());
+        }
+        // Only the origin is replaced - the path and query are kept.
+        if let Some(base_url) = &self.base_url {
+            let url = request.url_mut();
+            // These can only fail if base_url is not a valid http(s) url, which
+            // is checked when it's set.
+            let _ = url.set_scheme(base_url.scheme());
+            let _ = url.set_host(base_url.host_str());
+            let _ = url.set_port(base_url.port());
+        }
+    }
+}
+/// Parse a url that requests can be redirected to. Only http(s) urls with a
+/// host are supported.
+pub(crate) fn parse_base_url(base_url: &str) -> Result<reqwest::Url> {
+    let invalid = || Error::invalid_client_options(format!("invalid base url: {base_url}"));
+    let url = reqwest::Url::parse(base_url).map_err(|_| invalid())?;
+    if !matches!(url.scheme(), "http" | "https") || !url.has_host() {
+        return Err(invalid());
+    }
+    Ok(url)
+}
+/// A `tower` service used to send requests instead of `reqwest::Client`.
+#[cfg(feature = "tower")]
+#[derive(Clone)]
+struct TowerTransport(
+    tower::util::BoxCloneSyncService<reqwest::Request, reqwest::Response, tower::BoxError>,
+);
+#[cfg(feature = "tower")]
+impl std::fmt::Debug for TowerTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TowerTransport").finish_non_exhaustive()
+    }
 }
 /// Body that can be sent as a POST query using our client.
 pub enum Body {
@@ -57,27 +136,118 @@ impl Client {
     /// Utilises reqwest's default tls choice for the enabled set of options.
     pub fn new() -> Result<Self> {
         let inner = reqwest::Client::builder().build()?;
-        Ok(Self { inner })
+        Ok(Self::from_reqwest_client(inner))
     }
     #[cfg(feature = "rustls")]
     #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
     /// Force the use of rustls
     pub fn new_rustls_tls() -> Result<Self> {
         let inner = reqwest::Client::builder().use_rustls_tls().build()?;
-        Ok(Self { inner })
+        Ok(Self::from_reqwest_client(inner))
     }
     #[cfg(feature = "native-tls")]
     #[cfg_attr(docsrs, doc(cfg(feature = "native-tls")))]
     /// Force the use of native-tls
     pub fn new_native_tls() -> Result<Self> {
         let inner = reqwest::Client::builder().use_native_tls().build()?;
-        Ok(Self { inner })
+        Ok(Self::from_reqwest_client(inner))
     }
     #[cfg(feature = "reqwest")]
     #[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
     /// Re-use a pre-existing reqwest::Client.
     pub fn new_from_reqwest_client(client: reqwest::Client) -> Self {
-        Self { inner: client }
+        Self::from_reqwest_client(client)
+    }
+    #[cfg(feature = "tower")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tower")))]
+    /// Send requests using a `tower` service, allowing middleware such as
+    /// timeouts, retries and concurrency limits to be layered over the
+    /// transport. Typically, the innermost service will be a
+    /// `reqwest::Client`.
+    /// # Usage
+    /// ```
+    /// use std::time::Duration;
+    /// use tower::ServiceBuilder;
+    /// let service = ServiceBuilder::new()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .concurrency_limit(4)
+    ///     .service(reqwest::Client::new());
+    /// let client = ytmapi_rs::Client::new_from_tower_service(service).unwrap();
+    /// ```
+    pub fn new_from_tower_service<S>(service: S) -> Result<Self>
+    where
+        S: tower::Service<reqwest::Request, Response = reqwest::Response>
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        S::Error: Into<tower::BoxError>,
+        S::Future: Send + 'static,
+    {
+        use tower::ServiceExt;
+        // The reqwest::Client is only used to build requests.
+        let mut client = Self::new()?;
+        client.transport = Some(TowerTransport(tower::util::BoxCloneSyncService::new(
+            service.map_err(Into::into),
+        )));
+        Ok(client)
+    }
+    pub(crate) fn from_reqwest_builder(builder: reqwest::ClientBuilder) -> Result<Self> {
+        Ok(Self::from_reqwest_client(builder.build()?))
+    }
+    fn from_reqwest_client(inner: reqwest::Client) -> Self {
+        Self {
+            inner,
+            request_options: RequestOptions::default(),
+            client_profile: Arc::new(ClientProfile::default()),
+            #[cfg(feature = "tower")]
+            transport: None,
+        }
+    }
+    /// Apply options to every request sent by this client, in addition to any
+    /// previously applied options.
+    pub(crate) fn with_request_options(mut self, request_options: RequestOptions) -> Self {
+        self.request_options.merge(request_options);
+        self
+    }
+    /// Set the default Innertube client profile for queries sent by this
+    /// client.
+    pub(crate) fn with_client_profile(mut self, client_profile: ClientProfile) -> Self {
+        self.client_profile = Arc::new(client_profile);
+        self
+    }
+    /// The default Innertube client profile for queries sent by this client.
+    pub fn client_profile(&self) -> &ClientProfile {
+        &self.client_profile
+    }
+    /// Send all requests to `base_url` instead of the YouTube servers, keeping
+    /// the path and query of each request. Intended for testing against a
+    /// local server, such as `ytmapi-mock-server`.
+    // Test hook, so not part of the stable API.
+    #[doc(hidden)]
+    pub fn with_base_url(self, base_url: impl AsRef<str>) -> Result<Self> {
+        let base_url = parse_base_url(base_url.as_ref())?;
+        Ok(self.with_request_options(RequestOptions {
+            base_url: Some(Box::new(base_url)),
+            ..Default::default()
+        }))
+    }
+    async fn send(&self, request_builder: reqwest::RequestBuilder) -> Result<QueryResponse> {
+        let mut request = request_builder.build()?;
+        self.request_options.apply(&mut request);
+        #[cfg(feature = "tower")]
+        if let Some(TowerTransport(transport)) = &self.transport {
+            use tower::ServiceExt;
+            let response = transport.clone().oneshot(request).await.map_err(|e| {
+                match e.downcast::<reqwest::Error>() {
+                    Ok(e) => Error::from(*e),
+                    Err(e) => Error::web(e.to_string()),
+                }
+            })?;
+            return QueryResponse::try_from_reqwest_response(response).await;
+        }
+        let response = self.inner.execute(request).await?;
+        QueryResponse::try_from_reqwest_response(response).await
     }
     /// Run a POST query, with url, body, key/kalue params and headers.
     pub async fn post_query<'a, I>(
@@ -94,8 +264,7 @@ impl Client {
         for (header, value) in headers {
             request_builder = request_builder.header(header, value.as_ref());
         }
-        let response = request_builder.send().await?;
-        QueryResponse::try_from_reqwest_response(response).await
+        self.send(request_builder).await
     }
     /// Run a POST query, with url, body serialisable to json, key/kalue params
     /// and headers.
@@ -113,8 +282,7 @@ impl Client {
         for (header, value) in headers {
             request_builder = request_builder.header(header, value.as_ref());
         }
-        let response = request_builder.send().await?;
-        QueryResponse::try_from_reqwest_response(response).await
+        self.send(request_builder).await
     }
     /// Run a GET query, with url, key/value params and headers.
     pub async fn get_query<'a, I>(
@@ -130,7 +298,6 @@ impl Client {
         for (header, value) in headers {
             request_builder = request_builder.header(header, value.as_ref());
         }
-        let response = request_builder.send().await?;
-        QueryResponse::try_from_reqwest_response(response).await
+        self.send(request_builder).await
     }
 }
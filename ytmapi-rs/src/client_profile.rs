@@ -0,0 +1,182 @@
+//! Innertube client profiles.
+//! By default queries are sent as the `WEB_REMIX` (YouTube Music web) client,
+//! but some data is only exposed to other clients, such as the YouTube Music
+//! Android or iOS apps. Only the `WEB_REMIX` profile is provided, as the
+//! context and User-Agent sent by the other clients change frequently - other
+//! profiles can be constructed using the public fields of [`ClientProfile`].
+//! A [`ClientProfile`] can be requested by a query (see
+//! [`PostQuery::client_profile`](crate::query::PostQuery::client_profile)), or
+//! set as the default using
+//! [`YtMusicBuilder::with_client_profile`](crate::builder::YtMusicBuilder::with_client_profile).
+//! # NOTE
+//! Response parsers are written against `WEB_REMIX` responses, and other
+//! clients return differently structured responses for most endpoints - so
+//! setting a different default profile will cause most queries to fail to
+//! parse. Prefer requesting a profile only for the queries that need it, e.g
+//! [`GetSongQuery::with_client_profile`](crate::query::song::GetSongQuery::with_client_profile).
+//! # User-Agent precedence
+//! The User-Agent sent with a query is, in order of precedence:
+//! 1. The User-Agent set by
+//!    [`YtMusicBuilder::with_user_agent`](crate::builder::YtMusicBuilder::with_user_agent).
+//! 2. The User-Agent of the query's [`ClientProfile`].
+//! 3. The User-Agent provided by the AuthToken.
+use serde_json::{Map, Value, json};
+use std::borrow::Cow;
+
+/// The Innertube client that a query is sent as.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientProfile {
+    /// Innertube `clientName`.
+    pub name: Cow<'static, str>,
+    /// Innertube `clientVersion`. If None, the client version provided by the
+    /// AuthToken is used.
+    pub version: Option<Cow<'static, str>>,
+    /// User-Agent to send with the query. If None, the User-Agent provided by
+    /// the AuthToken is used. Note that a User-Agent set on the builder takes
+    /// precedence over this.
+    pub user_agent: Option<Cow<'static, str>>,
+    /// Extra fields to add to the client context, e.g `osName`.
+    pub extra_context: Map<String, Value>,
+}
+
+impl Default for ClientProfile {
+    fn default() -> Self {
+        Self::web_remix()
+    }
+}
+
+impl ClientProfile {
+    /// YouTube Music web client. This is the default profile.
+    pub fn web_remix() -> Self {
+        Self {
+            name: "WEB_REMIX".into(),
+            version: None,
+            user_agent: None,
+            extra_context: Map::new(),
+        }
+    }
+    /// Generate the client context sent in the body of a query.
+    /// `default_version` is used if this profile doesn't specify a version.
+    pub(crate) fn context(&self, default_version: Cow<'_, str>) -> Value {
+        let mut context = Map::new();
+        context.insert("clientName".into(), self.name.as_ref().into());
+        context.insert(
+            "clientVersion".into(),
+            self.version.as_deref().unwrap_or(&default_version).into(),
+        );
+        context.insert("user".into(), json!({}));
+        context.extend(self.extra_context.clone());
+        Value::Object(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClientProfile;
+    use crate::YtMusic;
+    use crate::auth::BrowserToken;
+    use crate::builder::YtMusicBuilder;
+    use crate::common::{VideoID, YoutubeID};
+    use crate::query::GetLibrarySongsQuery;
+    use crate::query::song::GetSongQuery;
+    use ytmapi_mock_server::{CLIENT_VERSION, MockServer, Route, Routes};
+
+    const MOCK_COOKIE: &str = "SAPISID=ytmapi-mock-server; __Secure-3PAPISID=ytmapi-mock-server;";
+    const TEST_USER_AGENT: &str = "ytmapi-rs-test-client/1.0";
+
+    /// A user-defined profile, for testing that each part of the profile is
+    /// sent.
+    fn test_profile(name: &'static str) -> ClientProfile {
+        ClientProfile {
+            name: name.into(),
+            version: Some("1.0".into()),
+            user_agent: Some(TEST_USER_AGENT.into()),
+            extra_context: serde_json::Map::from_iter([("osName".into(), name.into())]),
+        }
+    }
+
+    /// Mock server that only responds to a library songs query if it's sent
+    /// with the expected client context and headers.
+    async fn library_songs_server(route: Route) -> MockServer {
+        let routes = Routes::new().with_route(
+            route
+                .with_body_field("/browseId", "FEmusic_liked_videos")
+                .respond_with_file("test_json/get_library_songs_20240701.json"),
+        );
+        MockServer::start(routes).await.unwrap()
+    }
+    async fn build(builder: YtMusicBuilder<crate::builder::NoToken>) -> YtMusic<BrowserToken> {
+        builder
+            .with_browser_token_cookie(MOCK_COOKIE.to_string())
+            .build()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_web_remix_is_default() {
+        let server = library_songs_server(
+            Route::post("browse")
+                .with_body_field("/context/client/clientName", "WEB_REMIX")
+                .with_body_field("/context/client/clientVersion", CLIENT_VERSION),
+        )
+        .await;
+        let yt = build(YtMusicBuilder::new().with_base_url(server.base_url())).await;
+        yt.query(GetLibrarySongsQuery::default()).await.unwrap();
+    }
+    #[tokio::test]
+    async fn test_default_profile_sent() {
+        let server = library_songs_server(
+            Route::post("browse")
+                .with_body_field("/context/client/clientName", "TEST_DEFAULT")
+                .with_body_field("/context/client/clientVersion", "1.0")
+                .with_body_field("/context/client/osName", "TEST_DEFAULT")
+                .with_header("User-Agent", TEST_USER_AGENT),
+        )
+        .await;
+        let yt = build(
+            YtMusicBuilder::new()
+                .with_base_url(server.base_url())
+                .with_client_profile(test_profile("TEST_DEFAULT")),
+        )
+        .await;
+        yt.query(GetLibrarySongsQuery::default()).await.unwrap();
+    }
+    #[tokio::test]
+    async fn test_query_profile_overrides_default() {
+        let routes = Routes::new().with_route(
+            Route::post("player")
+                .with_body_field("/context/client/clientName", "TEST_QUERY")
+                .with_body_field("/context/client/osName", "TEST_QUERY")
+                .respond_with_file("test_json/get_song_tracking_url_20240728.json"),
+        );
+        let server = MockServer::start(routes).await.unwrap();
+        let yt = build(
+            YtMusicBuilder::new()
+                .with_base_url(server.base_url())
+                .with_client_profile(test_profile("TEST_DEFAULT")),
+        )
+        .await;
+        let query = GetSongQuery::new(VideoID::from_raw("9mWr4c_ig54"))
+            .unwrap()
+            .with_client_profile(test_profile("TEST_QUERY"));
+        yt.query(query).await.unwrap();
+    }
+    #[tokio::test]
+    async fn test_builder_user_agent_overrides_profile() {
+        let server = library_songs_server(
+            Route::post("browse")
+                .with_body_field("/context/client/clientName", "TEST_DEFAULT")
+                .with_header("User-Agent", "ytmapi-rs-test"),
+        )
+        .await;
+        let yt = build(
+            YtMusicBuilder::new()
+                .with_base_url(server.base_url())
+                .with_client_profile(test_profile("TEST_DEFAULT"))
+                .with_user_agent("ytmapi-rs-test"),
+        )
+        .await;
+        yt.query(GetLibrarySongsQuery::default()).await.unwrap();
+    }
+}
@@ -3,24 +3,32 @@
 // libraries. As opposed to simply part of the interface.
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::time::Duration;
 
 /// A search suggestion containing a list of TextRuns.
 /// May be a history suggestion.
-#[derive(PartialEq, Debug, Clone, Deserialize)]
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct SearchSuggestion {
     pub runs: Vec<TextRun>,
     pub suggestion_type: SuggestionType,
+    /// Token to remove the suggestion from your search history. Only present
+    /// for history suggestions.
+    #[serde(default)]
+    pub feedback_token_remove: Option<FeedbackTokenRemoveSearchSuggestion<'static>>,
 }
 
-#[derive(PartialEq, Debug, Clone, Deserialize, Copy)]
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum SuggestionType {
     History,
     Prediction,
 }
 
 /// A block of text that may be boldened.
-#[derive(PartialEq, Debug, Clone, Deserialize)]
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum TextRun {
     Bold(String),
     Normal(String),
@@ -55,11 +63,23 @@ impl SearchSuggestion {
         Self {
             runs,
             suggestion_type,
+            feedback_token_remove: None,
+        }
+    }
+    pub(crate) fn new_history(
+        runs: Vec<TextRun>,
+        feedback_token_remove: Option<FeedbackTokenRemoveSearchSuggestion<'static>>,
+    ) -> Self {
+        Self {
+            runs,
+            suggestion_type: SuggestionType::History,
+            feedback_token_remove,
         }
     }
 }
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[must_use]
 /// Indicates a result from an API action such as a 'delete playlist'
 pub enum ApiOutcome {
@@ -70,6 +90,7 @@ pub enum ApiOutcome {
 }
 
 #[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 // Intentionally not marked non_exhaustive - not expecting this to change.
 pub struct Thumbnail {
     pub height: u64,
@@ -78,6 +99,7 @@ pub struct Thumbnail {
 }
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 /// Set of both taste tokens.
 // Intentionally not marked non_exhaustive - not expecting this to change.
 // TODO: constructor
@@ -89,13 +111,26 @@ pub struct TasteToken<'a> {
 /// Collection of required fields to identify and change library status.
 // Intentionally not marked non_exhaustive - not expecting this to change.
 #[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct LibraryManager {
     pub status: LibraryStatus,
     pub add_to_library_token: FeedbackTokenAddToLibrary<'static>,
     pub remove_from_library_token: FeedbackTokenRemoveFromLibrary<'static>,
 }
 
+/// Collection of required fields to identify and change whether an item is
+/// pinned to "Listen again".
+// Intentionally not marked non_exhaustive - not expecting this to change.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ListenAgainPinManager {
+    pub status: PinStatus,
+    pub pin_token: FeedbackTokenPinToListenAgain<'static>,
+    pub unpin_token: FeedbackTokenUnpinFromListenAgain<'static>,
+}
+
 #[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 /// LIBRARY_SAVED and LIBRARY_ADD icons are being phased out so this is an
 /// intermediate step to check if a/b test is still running.
 ///
@@ -131,13 +166,26 @@ impl From<LibraryStatusIcon> for LibraryStatus {
 }
 
 #[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(from = "LibraryStatusIcon")]
 pub enum LibraryStatus {
     InLibrary,
     NotInLibrary,
 }
 
+/// Parsed from the icon of the pin toggle, which represents the action it will
+/// perform.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PinStatus {
+    #[serde(rename = "KEEP_OFF")]
+    Pinned,
+    #[serde(rename = "KEEP")]
+    NotPinned,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum LikeStatus {
     #[serde(rename = "LIKE")]
     Liked,
@@ -149,12 +197,14 @@ pub enum LikeStatus {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Explicit {
     IsExplicit,
     NotExplicit,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum AlbumType {
     Single,
     Album,
@@ -164,85 +214,413 @@ pub enum AlbumType {
 /// Type safe version of API ID used as part of YTM's interface.
 pub trait YoutubeID<'a> {
     fn get_raw(&self) -> &str;
-    // TODO: Create fallible version for when parsing is required. This could
-    // possiby be a seperate trait YoutubeIDFallible
+    /// Create the ID from a raw string, without validating it's format.
     fn from_raw<S: Into<Cow<'a, str>>>(raw_str: S) -> Self;
+    /// Check if a raw string is in the expected format for this type of ID.
+    /// Unless the type of ID has a known format, any non-empty string is
+    /// valid.
+    fn is_valid_raw(raw_str: &str) -> bool {
+        !raw_str.is_empty()
+    }
+    /// Create the ID from a raw string, returning an error if it's not in the
+    /// expected format for this type of ID.
+    /// ```
+    /// use ytmapi_rs::common::{AlbumID, VideoID, YoutubeID};
+    /// assert!(VideoID::try_from_raw("FZ8BxMU3BYc").is_ok());
+    /// assert!(VideoID::try_from_raw("not a video").is_err());
+    /// assert!(AlbumID::try_from_raw("FZ8BxMU3BYc").is_err());
+    /// ```
+    fn try_from_raw<S: Into<Cow<'a, str>>>(raw_str: S) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let raw_str = raw_str.into();
+        if !Self::is_valid_raw(&raw_str) {
+            return Err(crate::Error::invalid_id(
+                std::any::type_name::<Self>(),
+                raw_str,
+            ));
+        }
+        Ok(Self::from_raw(raw_str))
+    }
 }
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeedbackTokenRemoveFromHistory<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FeedbackTokenRemoveSearchSuggestion<'a>(Cow<'a, str>);
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeedbackTokenAddToLibrary<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeedbackTokenRemoveFromLibrary<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FeedbackTokenMarkPlayed<'a>(Cow<'a, str>);
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FeedbackTokenMarkUnplayed<'a>(Cow<'a, str>);
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FeedbackTokenPinToListenAgain<'a>(Cow<'a, str>);
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FeedbackTokenUnpinFromListenAgain<'a>(Cow<'a, str>);
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BrowseParams<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UserVideosParams<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UserPlaylistsParams<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PodcastChannelParams<'a>(Cow<'a, str>);
-// TODO: Add parsing - PlaylistID begining with VL should fail.
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PlaylistID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AlbumID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ArtistChannelID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PodcastChannelID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ContinuationParams<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UserChannelID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PodcastID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EpisodeID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct VideoID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UploadEntityID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct LyricsID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SetVideoID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UploadAlbumID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UploadArtistID<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TasteTokenSelection<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TasteTokenImpression<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MoodCategoryParams<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SongTrackingUrl<'a>(Cow<'a, str>);
 
 impl_youtube_id!(UploadEntityID<'a>);
 impl_youtube_id!(SetVideoID<'a>);
-impl_youtube_id!(AlbumID<'a>);
-impl_youtube_id!(UploadAlbumID<'a>);
-impl_youtube_id!(UploadArtistID<'a>);
-impl_youtube_id!(UserChannelID<'a>);
-impl_youtube_id!(PodcastID<'a>);
-impl_youtube_id!(EpisodeID<'a>);
-impl_youtube_id!(VideoID<'a>);
-impl_youtube_id!(PlaylistID<'a>);
-impl_youtube_id!(ArtistChannelID<'a>);
-impl_youtube_id!(PodcastChannelID<'a>);
-impl_youtube_id!(LyricsID<'a>);
+impl_youtube_id!(AlbumID<'a>, |raw| {
+    raw.starts_with("MPREb_") || raw.starts_with("FEmusic_library_privately_owned_release_detail")
+});
+impl_youtube_id!(UploadAlbumID<'a>, |raw| {
+    raw.starts_with("FEmusic_library_privately_owned_release_detail")
+});
+impl_youtube_id!(UploadArtistID<'a>, |raw| {
+    raw.starts_with("FEmusic_library_privately_owned_artist_detail")
+});
+impl_youtube_id!(UserChannelID<'a>, is_channel_id);
+impl_youtube_id!(PodcastID<'a>, |raw| {
+    raw.strip_prefix("MPSP").is_some_and(is_playlist_id)
+});
+impl_youtube_id!(EpisodeID<'a>, |raw| {
+    is_video_id(raw.strip_prefix("MPED").unwrap_or(raw))
+});
+impl_youtube_id!(VideoID<'a>, is_video_id);
+impl_youtube_id!(PlaylistID<'a>, is_playlist_id);
+impl_youtube_id!(ArtistChannelID<'a>, |raw| {
+    is_channel_id(
+        raw.strip_prefix("MPLA")
+            .or_else(|| raw.strip_prefix("MPAD"))
+            .unwrap_or(raw),
+    )
+});
+impl_youtube_id!(PodcastChannelID<'a>, is_channel_id);
+impl_youtube_id!(LyricsID<'a>, |raw| raw.starts_with("MPLY"));
 impl_youtube_id!(BrowseParams<'a>);
 impl_youtube_id!(PodcastChannelParams<'a>);
 impl_youtube_id!(ContinuationParams<'a>);
 impl_youtube_id!(FeedbackTokenRemoveFromHistory<'a>);
+impl_youtube_id!(FeedbackTokenRemoveSearchSuggestion<'a>);
 impl_youtube_id!(FeedbackTokenRemoveFromLibrary<'a>);
 impl_youtube_id!(FeedbackTokenAddToLibrary<'a>);
+impl_youtube_id!(FeedbackTokenMarkPlayed<'a>);
+impl_youtube_id!(FeedbackTokenMarkUnplayed<'a>);
+impl_youtube_id!(FeedbackTokenPinToListenAgain<'a>);
+impl_youtube_id!(FeedbackTokenUnpinFromListenAgain<'a>);
 impl_youtube_id!(TasteTokenImpression<'a>);
 impl_youtube_id!(TasteTokenSelection<'a>);
 impl_youtube_id!(MoodCategoryParams<'a>);
 impl_youtube_id!(SongTrackingUrl<'a>);
 impl_youtube_id!(UserVideosParams<'a>);
 impl_youtube_id!(UserPlaylistsParams<'a>);
+
+fn is_id_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+fn is_video_id(raw: &str) -> bool {
+    raw.len() == 11 && raw.chars().all(is_id_char)
+}
+// A "VL" prefix denotes a playlist browse ID rather than a playlist ID.
+fn is_playlist_id(raw: &str) -> bool {
+    !raw.is_empty() && !raw.starts_with("VL") && raw.chars().all(is_id_char)
+}
+fn is_channel_id(raw: &str) -> bool {
+    raw.len() == 24 && raw.starts_with("UC") && raw.chars().all(is_id_char)
+}
+
+/// A YouTube or YouTube Music url, parsed into typed IDs.
+#[derive(PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub enum ParsedYoutubeUrl {
+    Video {
+        video_id: VideoID<'static>,
+        /// Playlist the video is being played from, if any.
+        playlist_id: Option<PlaylistID<'static>>,
+        /// Position to start playing from, if any.
+        timestamp: Option<Duration>,
+    },
+    Playlist(PlaylistID<'static>),
+    Album(AlbumID<'static>),
+    /// NOTE: Channel urls don't identify the type of channel - this could also
+    /// be a user or podcast channel.
+    Channel(ArtistChannelID<'static>),
+    Podcast(PodcastID<'static>),
+    Episode(EpisodeID<'static>),
+}
+
+/// Parse a YouTube, YouTube Music or youtu.be url (such as a share link) into
+/// typed IDs.
+/// ```
+/// use ytmapi_rs::common::{ParsedYoutubeUrl, VideoID, YoutubeID, parse_url};
+/// let parsed = parse_url("https://youtu.be/FZ8BxMU3BYc?si=abc&t=42").unwrap();
+/// assert_eq!(
+///     parsed,
+///     ParsedYoutubeUrl::Video {
+///         video_id: VideoID::from_raw("FZ8BxMU3BYc"),
+///         playlist_id: None,
+///         timestamp: Some(std::time::Duration::from_secs(42)),
+///     }
+/// );
+/// ```
+pub fn parse_url(url: &str) -> crate::Result<ParsedYoutubeUrl> {
+    let unrecognised = || crate::Error::unrecognised_url(url);
+    let trimmed = url.trim();
+    let parsed = match url::Url::parse(trimmed) {
+        Ok(parsed) => parsed,
+        // Allow urls without a scheme, e.g "music.youtube.com/watch?v=xxx".
+        Err(url::ParseError::RelativeUrlWithoutBase) => {
+            url::Url::parse(&format!("https://{trimmed}")).map_err(|_| unrecognised())?
+        }
+        Err(_) => return Err(unrecognised()),
+    };
+    let host = parsed.host_str().ok_or_else(unrecognised)?;
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    let query_param = |key: &str| {
+        parsed
+            .query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    };
+    let timestamp = query_param("t")
+        .or_else(|| query_param("start"))
+        .and_then(|t| parse_timestamp(&t));
+    let mut segments = parsed.path_segments().ok_or_else(unrecognised)?;
+    let first = segments.next().unwrap_or_default();
+    let second = segments.next().unwrap_or_default();
+    let video = |video_id: String| {
+        Ok(ParsedYoutubeUrl::Video {
+            video_id: VideoID::try_from_raw(video_id)?,
+            playlist_id: query_param("list")
+                .map(PlaylistID::try_from_raw)
+                .transpose()?,
+            timestamp,
+        })
+    };
+    match host {
+        "youtu.be" => video(first.to_string()),
+        "youtube.com" | "m.youtube.com" | "music.youtube.com" => match first {
+            "watch" => video(query_param("v").ok_or_else(unrecognised)?),
+            "shorts" | "embed" | "live" | "v" => video(second.to_string()),
+            "playlist" => Ok(ParsedYoutubeUrl::Playlist(PlaylistID::try_from_raw(
+                query_param("list").ok_or_else(unrecognised)?,
+            )?)),
+            "channel" => Ok(ParsedYoutubeUrl::Channel(ArtistChannelID::try_from_raw(
+                second.to_string(),
+            )?)),
+            "browse" => parse_browse_id(second).ok_or_else(unrecognised),
+            _ => Err(unrecognised()),
+        },
+        _ => Err(unrecognised()),
+    }
+}
+
+/// Parse a browse ID from a music.youtube.com/browse/ url.
+fn parse_browse_id(browse_id: &str) -> Option<ParsedYoutubeUrl> {
+    let browse_id = browse_id.to_string();
+    if let Some(playlist_id) = browse_id.strip_prefix("VL") {
+        return PlaylistID::try_from_raw(playlist_id.to_string())
+            .ok()
+            .map(ParsedYoutubeUrl::Playlist);
+    }
+    if browse_id.starts_with("MPREb_") {
+        return AlbumID::try_from_raw(browse_id)
+            .ok()
+            .map(ParsedYoutubeUrl::Album);
+    }
+    if browse_id.starts_with("MPSP") {
+        return PodcastID::try_from_raw(browse_id)
+            .ok()
+            .map(ParsedYoutubeUrl::Podcast);
+    }
+    if browse_id.starts_with("MPED") {
+        return EpisodeID::try_from_raw(browse_id)
+            .ok()
+            .map(ParsedYoutubeUrl::Episode);
+    }
+    ArtistChannelID::try_from_raw(browse_id)
+        .ok()
+        .map(ParsedYoutubeUrl::Channel)
+}
+
+/// Parse a timestamp in the forms "90", "90s" or "1h2m3s".
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+    if let Ok(secs) = timestamp.parse() {
+        return Some(Duration::from_secs(secs));
+    }
+    let mut secs: u64 = 0;
+    let mut digits = String::new();
+    for c in timestamp.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let multiplier = match c {
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        secs = secs.checked_add(digits.parse::<u64>().ok()?.checked_mul(multiplier)?)?;
+        digits.clear();
+    }
+    digits.is_empty().then_some(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AlbumID, ArtistChannelID, EpisodeID, ParsedYoutubeUrl, PlaylistID, PodcastID, VideoID,
+        YoutubeID, parse_url,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_try_from_raw() {
+        assert!(VideoID::try_from_raw("FZ8BxMU3BYc").is_ok());
+        assert!(VideoID::try_from_raw("FZ8BxMU3BY").is_err());
+        assert!(AlbumID::try_from_raw("MPREb_zwdwliUwMK8").is_ok());
+        assert!(AlbumID::try_from_raw("FZ8BxMU3BYc").is_err());
+        assert!(ArtistChannelID::try_from_raw("UCjwf9oA4C9Yzxcg4vjI5GDg").is_ok());
+        assert!(ArtistChannelID::try_from_raw("MPLAUCjwf9oA4C9Yzxcg4vjI5GDg").is_ok());
+        assert!(ArtistChannelID::try_from_raw("UCjwf9oA4C9").is_err());
+        assert!(PodcastID::try_from_raw("MPSPPLBTqyWsmTvbGVRJTp9vqLo2nC29lL8C1x").is_ok());
+        assert!(PodcastID::try_from_raw("PLBTqyWsmTvbGVRJTp9vqLo2nC29lL8C1x").is_err());
+        assert!(EpisodeID::try_from_raw("MPEDFZ8BxMU3BYc").is_ok());
+        assert!(PlaylistID::try_from_raw("PL with spaces").is_err());
+        assert!(PlaylistID::try_from_raw("VLPLti4V4FomqcSMmZP9b8wsNGJ6Y8h6m9RR").is_err());
+    }
+    #[test]
+    fn test_parse_url_video() {
+        let expected = ParsedYoutubeUrl::Video {
+            video_id: VideoID::from_raw("FZ8BxMU3BYc"),
+            playlist_id: Some(PlaylistID::from_raw("RDAMVMFZ8BxMU3BYc")),
+            timestamp: Some(Duration::from_secs(90)),
+        };
+        assert_eq!(
+            parse_url(
+                "https://music.youtube.com/watch?v=FZ8BxMU3BYc&list=RDAMVMFZ8BxMU3BYc&t=1m30s"
+            )
+            .unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse_url("www.youtube.com/watch?v=FZ8BxMU3BYc&list=RDAMVMFZ8BxMU3BYc&t=90").unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse_url("https://www.youtube.com/shorts/FZ8BxMU3BYc").unwrap(),
+            ParsedYoutubeUrl::Video {
+                video_id: VideoID::from_raw("FZ8BxMU3BYc"),
+                playlist_id: None,
+                timestamp: None,
+            }
+        );
+    }
+    #[test]
+    fn test_parse_url_browse() {
+        assert_eq!(
+            parse_url("https://music.youtube.com/playlist?list=PLti4V4FomqcSMmZP9b8wsNGJ6Y8h6m9RR")
+                .unwrap(),
+            ParsedYoutubeUrl::Playlist(PlaylistID::from_raw("PLti4V4FomqcSMmZP9b8wsNGJ6Y8h6m9RR"))
+        );
+        assert_eq!(
+            parse_url("https://music.youtube.com/browse/MPREb_zwdwliUwMK8").unwrap(),
+            ParsedYoutubeUrl::Album(AlbumID::from_raw("MPREb_zwdwliUwMK8"))
+        );
+        assert_eq!(
+            parse_url("https://music.youtube.com/channel/UCjwf9oA4C9Yzxcg4vjI5GDg").unwrap(),
+            ParsedYoutubeUrl::Channel(ArtistChannelID::from_raw("UCjwf9oA4C9Yzxcg4vjI5GDg"))
+        );
+        assert_eq!(
+            parse_url("https://music.youtube.com/browse/MPSPPLBTqyWsmTvbGVRJTp9vqLo2nC29lL8C1x")
+                .unwrap(),
+            ParsedYoutubeUrl::Podcast(PodcastID::from_raw(
+                "MPSPPLBTqyWsmTvbGVRJTp9vqLo2nC29lL8C1x"
+            ))
+        );
+    }
+    #[test]
+    fn test_parse_url_timestamp_overflow() {
+        let ParsedYoutubeUrl::Video { timestamp, .. } =
+            parse_url("https://youtu.be/FZ8BxMU3BYc?t=99999999999999999h").unwrap()
+        else {
+            panic!("Expected a video url");
+        };
+        assert_eq!(timestamp, None);
+    }
+    #[test]
+    fn test_parse_url_unrecognised() {
+        assert!(parse_url("https://example.com/watch?v=FZ8BxMU3BYc").is_err());
+        assert!(parse_url("https://music.youtube.com/explore").is_err());
+        assert!(parse_url("https://youtu.be/").is_err());
+    }
+}
@@ -31,10 +31,14 @@
 //! `From<ProcessedResult> for JsonCrawlerOwned` implementation.
 use crate::auth::AuthToken;
 use crate::common::ContinuationParams;
+use crate::instrument::in_query_span;
 use crate::parse::ParseFrom;
 use crate::query::{GetContinuationsQuery, PostMethod, PostQuery, Query, QueryMethod};
 use crate::{ProcessedResult, Result};
 use futures::Stream;
+#[cfg(feature = "simplified-queries")]
+use futures::StreamExt;
+use std::borrow::Borrow;
 use std::fmt::Debug;
 
 /// This trait represents a result that can be streamed to get more results.
@@ -68,8 +72,11 @@ where
 ///   Q: PostQuery - this simplifies code within this function.
 /// - a query can only be streamed if the output is Continuable - therefore we
 ///   specify Q::Output: ParseFromContinuable<Q>.
+///
+/// The query can be owned or borrowed - the stream holds onto it, and
+/// constructs each continuation query from it as required.
 pub(crate) fn stream<'a, Q, A>(
-    query: &'a Q,
+    query: impl Borrow<Q> + 'a,
     client: &'a crate::client::Client,
     tok: &'a A,
 ) -> impl Stream<Item = Result<Q::Output>> + 'a
@@ -81,34 +88,53 @@ where
 {
     futures::stream::unfold(
         // Initial state for unfold
-        // The first component is that the first query hasn't been run.
-        // The second component of state represents if there are continuations
+        // The first component is the number of the next page to run - the first query
+        // hasn't been run.
+        // The third component of state represents if there are continuations
         // (this is ignored on first run)
-        (false, None::<GetContinuationsQuery<'a, Q>>),
-        move |(first_query_run, maybe_next_query)| async move {
-            if !first_query_run {
-                let first_res = Q::Method::call(query, client, tok)
-                    .await
-                    .and_then(|res| res.process())
-                    .and_then(|res| GetContinuationsQuery::from_first_result(res));
+        (0, query, None::<ContinuationParams<'static>>),
+        move |(page, query, maybe_next_params)| async move {
+            if page == 0 {
+                let first_res = in_query_span::<Q, A, _>(Some(page), async {
+                    Q::Method::call(query.borrow(), client, tok)
+                        .await
+                        .and_then(|res| res.process())
+                        .and_then(|res| GetContinuationsQuery::from_first_result(res))
+                        .map(|(first, next)| {
+                            (
+                                first,
+                                next.map(GetContinuationsQuery::into_continuation_params),
+                            )
+                        })
+                })
+                .await;
                 match first_res {
                     Ok((first, next)) => {
-                        return Some((Ok(first), (true, next)));
+                        return Some((Ok(first), (page + 1, query, next)));
                     }
-                    Err(e) => return Some((Err(e), (true, None))),
+                    Err(e) => return Some((Err(e), (page + 1, query, None))),
                 }
             }
-            if let Some(ref next_query) = maybe_next_query {
-                let next_res = PostMethod::call(next_query, client, tok)
-                    .await
-                    .and_then(|res| res.process());
-                let next_res =
-                    next_res.and_then(|res| GetContinuationsQuery::from_continuation(res));
+            if let Some(next_params) = maybe_next_params {
+                let next_query = GetContinuationsQuery::new(query.borrow(), next_params);
+                let next_res = in_query_span::<Q, A, _>(Some(page), async {
+                    PostMethod::call(&next_query, client, tok)
+                        .await
+                        .and_then(|res| res.process())
+                        .and_then(|res| GetContinuationsQuery::from_continuation(res))
+                        .map(|(this, next)| {
+                            (
+                                this,
+                                next.map(GetContinuationsQuery::into_continuation_params),
+                            )
+                        })
+                })
+                .await;
                 match next_res {
                     Ok((this, next)) => {
-                        return Some((Ok(this), (true, next)));
+                        return Some((Ok(this), (page + 1, query, next)));
                     }
-                    Err(e) => return Some((Err(e), (true, None))),
+                    Err(e) => return Some((Err(e), (page + 1, query, None))),
                 }
             }
             None
@@ -128,7 +154,7 @@ where
 /// - a query can only be streamed if the output is Continuable - therefore we
 ///   specify Q::Output: ParseFromContinuable<Q>.
 pub(crate) fn raw_json_stream<'a, Q, A>(
-    query: &'a Q,
+    query: impl Borrow<Q> + 'a,
     client: &'a crate::client::Client,
     tok: &'a A,
 ) -> impl Stream<Item = Result<String>> + 'a
@@ -140,44 +166,74 @@ where
 {
     futures::stream::unfold(
         // Initial state for unfold
-        // The first component is that the first query hasn't been run.
-        // The second component of state represents if there are continuations
+        // The first component is the number of the next page to run - the first query
+        // hasn't been run.
+        // The third component of state represents if there are continuations
         // (this is ignored on first run)
-        (false, None::<GetContinuationsQuery<'a, Q>>),
-        move |(first_query_run, maybe_next_query)| async move {
-            if !first_query_run {
-                let first_raw_res = Q::Method::call(query, client, tok).await;
+        (0, query, None::<ContinuationParams<'static>>),
+        move |(page, query, maybe_next_params)| async move {
+            if page == 0 {
+                let first_raw_res = in_query_span::<Q, A, _>(
+                    Some(page),
+                    Q::Method::call(query.borrow(), client, tok),
+                )
+                .await;
                 match first_raw_res {
                     Ok(first_raw_res) => {
                         let first_source = first_raw_res.json.clone();
-                        let next_query = first_raw_res
+                        let next_params = first_raw_res
                             .process()
                             .and_then(GetContinuationsQuery::from_first_result::<Q::Output>)
                             .ok()
-                            .and_then(|(_, q)| q);
-                        return Some((Ok(first_source), (true, next_query)));
+                            .and_then(|(_, q)| q)
+                            .map(GetContinuationsQuery::into_continuation_params);
+                        return Some((Ok(first_source), (page + 1, query, next_params)));
                     }
-                    Err(e) => return Some((Err(e), (true, None))),
+                    Err(e) => return Some((Err(e), (page + 1, query, None))),
                 }
             }
-            if let Some(ref next_query) = maybe_next_query {
-                let next_raw_res =
-                    <GetContinuationsQuery<Q> as Query<A>>::Method::call(next_query, client, tok)
-                        .await;
+            if let Some(next_params) = maybe_next_params {
+                let next_query = GetContinuationsQuery::new(query.borrow(), next_params);
+                let next_raw_res = in_query_span::<Q, A, _>(
+                    Some(page),
+                    <GetContinuationsQuery<Q> as Query<A>>::Method::call(&next_query, client, tok),
+                )
+                .await;
                 match next_raw_res {
                     Ok(next_raw_res) => {
                         let next_source = next_raw_res.json.clone();
-                        let next_query = next_raw_res
+                        let next_params = next_raw_res
                             .process()
                             .and_then(GetContinuationsQuery::from_continuation::<Q::Output>)
                             .ok()
-                            .and_then(|(_, q)| q);
-                        return Some((Ok(next_source), (true, next_query)));
+                            .and_then(|(_, q)| q)
+                            .map(GetContinuationsQuery::into_continuation_params);
+                        return Some((Ok(next_source), (page + 1, query, next_params)));
                     }
-                    Err(e) => return Some((Err(e), (true, None))),
+                    Err(e) => return Some((Err(e), (page + 1, query, None))),
                 }
             }
             None
         },
     )
 }
+
+/// Collect all the items from a stream of pages, stopping early once
+/// `max_items` items have been received. The stream stops at the first error.
+#[cfg(feature = "simplified-queries")]
+pub(crate) async fn collect_all<T>(
+    stream: impl Stream<Item = Result<Vec<T>>>,
+    max_items: Option<usize>,
+) -> Result<Vec<T>> {
+    let mut stream = std::pin::pin!(stream);
+    let mut items = Vec::new();
+    while max_items.is_none_or(|max_items| items.len() < max_items)
+        && let Some(page) = stream.next().await
+    {
+        items.extend(page?);
+    }
+    if let Some(max_items) = max_items {
+        items.truncate(max_items);
+    }
+    Ok(items)
+}
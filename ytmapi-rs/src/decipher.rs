@@ -0,0 +1,87 @@
+//! Deciphering of stream urls returned by
+//! [`GetSongQuery`](crate::query::GetSongQuery).
+//!
+//! YouTube obfuscates stream urls in two ways:
+//! - The signature (`s`) of a `signatureCipher` must be transformed by a
+//!   function in the player JS before it's appended to the url.
+//! - The `n` parameter of the url must be transformed by another function in
+//!   the player JS, otherwise downloads are throttled.
+//!
+//! Both functions change regularly and generally require a javascript runtime
+//! to evaluate, so deciphering is pluggable via the [`Decipher`] trait. This
+//! crate does not download or evaluate the player JS itself - implementing
+//! [`Decipher`] for the current player JS is left to the caller.
+//! # Usage
+//! ```no_run
+//! use ytmapi_rs::common::{VideoID, YoutubeID};
+//! use ytmapi_rs::decipher::Decipher;
+//! // Illustrative only - the steps making up the signature function, as
+//! // extracted from the current player JS (base.js) by the caller.
+//! enum Step {
+//!     Reverse,
+//!     Swap(usize),
+//!     Splice(usize),
+//! }
+//! struct StepDecipher(Vec<Step>);
+//! impl Decipher for StepDecipher {
+//!     fn decipher_signature(&self, signature: &str) -> ytmapi_rs::Result<String> {
+//!         let mut chars: Vec<char> = signature.chars().collect();
+//!         for step in &self.0 {
+//!             match *step {
+//!                 Step::Reverse => chars.reverse(),
+//!                 Step::Swap(i) => {
+//!                     let len = chars.len();
+//!                     chars.swap(0, i % len)
+//!                 }
+//!                 Step::Splice(i) => {
+//!                     chars.drain(..i);
+//!                 }
+//!             }
+//!         }
+//!         Ok(chars.into_iter().collect())
+//!     }
+//!     // Leaving `n` unchanged still produces a playable, but throttled, url.
+//!     fn decipher_n(&self, n: &str) -> ytmapi_rs::Result<String> {
+//!         Ok(n.to_string())
+//!     }
+//! }
+//! # async {
+//! let decipher = StepDecipher(vec![Step::Reverse, Step::Splice(2), Step::Swap(39)]);
+//! let yt = ytmapi_rs::YtMusic::new_unauthenticated().await?;
+//! let song = yt.get_song(VideoID::from_raw("FZ8BxMU3BYc")).await?;
+//! let url = song.best_audio_format().unwrap().url.resolve(&decipher)?;
+//! # Ok::<(), ytmapi_rs::Error>(())
+//! # };
+//! ```
+use crate::{Error, Result};
+
+/// Transformations required to turn a stream url into a playable url.
+/// # NOTE
+/// Signatures are only guaranteed to decipher correctly for a
+/// [`GetSongQuery`](crate::query::GetSongQuery) made with the
+/// signatureTimestamp of the player JS used to decipher them - see
+/// [`GetSongQuery::with_signature_timestamp`](crate::query::GetSongQuery::with_signature_timestamp).
+pub trait Decipher {
+    /// Transform the `s` parameter of a `signatureCipher` into the signature
+    /// expected by the stream url.
+    fn decipher_signature(&self, signature: &str) -> Result<String>;
+    /// Transform the `n` parameter of a stream url.
+    fn decipher_n(&self, n: &str) -> Result<String>;
+}
+
+/// A [`Decipher`] that performs no deciphering. Only able to resolve stream
+/// urls that aren't behind a `signatureCipher`, and leaves the `n` parameter
+/// unchanged - so the resolved url may be throttled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoDecipher;
+
+impl Decipher for NoDecipher {
+    fn decipher_signature(&self, _: &str) -> Result<String> {
+        Err(Error::decipher(
+            "NoDecipher is unable to decipher signatures",
+        ))
+    }
+    fn decipher_n(&self, n: &str) -> Result<String> {
+        Ok(n.to_string())
+    }
+}
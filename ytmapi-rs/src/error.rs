@@ -1,4 +1,5 @@
 //! Module to contain code related to errors that could be produced by the API.
+use crate::common::{VideoID, YoutubeID};
 use core::fmt::{Debug, Display};
 pub use json_crawler::CrawlerError as JsonError;
 use std::hash::{Hash, Hasher};
@@ -72,6 +73,43 @@ pub enum ErrorKind {
         message: String,
     },
     MissingUploadUrl,
+    /// A playlist item needed to be moved or removed, but had no SetVideoID.
+    PlaylistItemNotEditable {
+        video_id: String,
+    },
+    /// Tried to add videos to a playlist using
+    /// `DuplicateHandlingMode::ReturnError`, and at least one of them was
+    /// already in the playlist. No videos were added.
+    /// # NOTE
+    /// YouTube Music doesn't report which videos were duplicates, so
+    /// `video_ids` lists every video that was requested.
+    DuplicatePlaylistItems {
+        video_ids: Vec<VideoID<'static>>,
+    },
+    /// InnerTube reported adding a different number of playlist items to the
+    /// number requested during a playlist sync.
+    PlaylistSyncAddMismatch {
+        expected: usize,
+        added: usize,
+    },
+    /// Tried to create an ID from a string that's not in the expected format.
+    InvalidID {
+        id_type: &'static str,
+        raw: String,
+    },
+    /// Tried to parse a url that's not a recognised YouTube or YouTube Music
+    /// url.
+    UnrecognisedUrl {
+        url: String,
+    },
+    /// Unable to decipher a stream url.
+    Decipher {
+        message: String,
+    },
+    /// The options passed to `YtMusicBuilder` can't be used to build a client.
+    InvalidClientOptions {
+        message: String,
+    },
 }
 /// The type we were attempting to pass from the Json.
 #[derive(Debug, Clone)]
@@ -84,6 +122,10 @@ impl Error {
     pub fn into_kind(self) -> ErrorKind {
         *self.inner
     }
+    /// Return a reference to the inner kind of the error.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.inner
+    }
     pub(crate) fn invalid_user_agent<S: Into<String>>(user_agent: S) -> Self {
         Self {
             inner: Box::new(ErrorKind::InvalidUserAgent(user_agent.into())),
@@ -149,6 +191,50 @@ impl Error {
             inner: Box::new(ErrorKind::MissingUploadUrl),
         }
     }
+    pub(crate) fn playlist_item_not_editable(video_id: impl Into<String>) -> Self {
+        Self {
+            inner: Box::new(ErrorKind::PlaylistItemNotEditable {
+                video_id: video_id.into(),
+            }),
+        }
+    }
+    pub(crate) fn duplicate_playlist_items(video_ids: Vec<VideoID<'static>>) -> Self {
+        Self {
+            inner: Box::new(ErrorKind::DuplicatePlaylistItems { video_ids }),
+        }
+    }
+    pub(crate) fn playlist_sync_add_mismatch(expected: usize, added: usize) -> Self {
+        Self {
+            inner: Box::new(ErrorKind::PlaylistSyncAddMismatch { expected, added }),
+        }
+    }
+    pub(crate) fn invalid_id(id_type: &'static str, raw: impl Into<String>) -> Self {
+        Self {
+            inner: Box::new(ErrorKind::InvalidID {
+                id_type,
+                raw: raw.into(),
+            }),
+        }
+    }
+    pub(crate) fn unrecognised_url(url: impl Into<String>) -> Self {
+        Self {
+            inner: Box::new(ErrorKind::UnrecognisedUrl { url: url.into() }),
+        }
+    }
+    pub(crate) fn decipher(message: impl Into<String>) -> Self {
+        Self {
+            inner: Box::new(ErrorKind::Decipher {
+                message: message.into(),
+            }),
+        }
+    }
+    pub(crate) fn invalid_client_options(message: impl Into<String>) -> Self {
+        Self {
+            inner: Box::new(ErrorKind::InvalidClientOptions {
+                message: message.into(),
+            }),
+        }
+    }
     pub(crate) fn web(message: impl Into<String>) -> Self {
         Self {
             inner: Box::new(ErrorKind::Web {
@@ -204,6 +290,37 @@ impl Display for ErrorKind {
             ErrorKind::MissingUploadUrl => {
                 write!(f, "expected an x-goog-upload-url but didn't get one")
             }
+            ErrorKind::PlaylistItemNotEditable { video_id } => write!(
+                f,
+                "Playlist item {video_id} has no SetVideoID, so it cannot be moved or removed"
+            ),
+            ErrorKind::DuplicatePlaylistItems { video_ids } => {
+                let video_ids = video_ids
+                    .iter()
+                    .map(YoutubeID::get_raw)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "Playlist already contains at least one of videos {video_ids}, so none were added"
+                )
+            }
+            ErrorKind::PlaylistSyncAddMismatch { expected, added } => write!(
+                f,
+                "Expected to add {expected} playlist items during sync, but {added} were added"
+            ),
+            ErrorKind::InvalidID { id_type, raw } => {
+                write!(f, "<{raw}> is not a valid {id_type}")
+            }
+            ErrorKind::UnrecognisedUrl { url } => {
+                write!(f, "<{url}> is not a recognised YouTube Music url")
+            }
+            ErrorKind::Decipher { message } => {
+                write!(f, "Unable to decipher stream url. <{message}>")
+            }
+            ErrorKind::InvalidClientOptions { message } => {
+                write!(f, "Invalid client options. <{message}>")
+            }
         }
     }
 }
@@ -0,0 +1,254 @@
+//! Optional instrumentation of queries using `tracing`.
+//! A span is created per query (or per page, for a streamed query), recording
+//! the query type, auth token type, endpoint, latency and response size.
+//! Headers, cookies and tokens are never recorded.
+//! When the `tracing` feature is disabled, these functions are no-ops.
+use crate::Result;
+#[cfg(feature = "tracing")]
+use crate::error::ErrorKind;
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+/// Run a query future inside a span for query `Q` using auth token `A`, and
+/// record any error it returns.
+/// `page` is the page number (starting at 0), if the query is being streamed.
+// Q and A are only used to name the span.
+#[cfg_attr(not(feature = "tracing"), allow(clippy::extra_unused_type_parameters))]
+pub(crate) async fn in_query_span<Q, A, T>(
+    page: Option<usize>,
+    fut: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    let fut = async {
+        let out = fut.await;
+        if let Err(e) = &out {
+            record_error(e);
+        }
+        out
+    };
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::info_span!(
+            "ytmapi_query",
+            query = std::any::type_name::<Q>(),
+            auth = std::any::type_name::<A>(),
+            page,
+            endpoint = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            response_bytes = tracing::field::Empty,
+        );
+        tracing::Instrument::instrument(fut, span).await
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = page;
+        fut.await
+    }
+}
+
+/// Times a single web request made within a query span.
+/// The endpoint recorded is the Innertube endpoint or url path - query strings
+/// are not recorded.
+pub(crate) struct RequestTimer {
+    #[cfg(feature = "tracing")]
+    start: Instant,
+}
+
+impl RequestTimer {
+    /// Start timing a request to `endpoint`.
+    pub(crate) fn start(endpoint: &str) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            tracing::Span::current().record("endpoint", endpoint);
+            Self {
+                start: Instant::now(),
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = endpoint;
+            Self {}
+        }
+    }
+    /// Record the latency of the request and the size of its response.
+    pub(crate) fn finish(self, response_bytes: usize) {
+        #[cfg(feature = "tracing")]
+        {
+            let latency_ms = self.start.elapsed().as_millis() as u64;
+            let span = tracing::Span::current();
+            span.record("latency_ms", latency_ms);
+            span.record("response_bytes", response_bytes);
+            tracing::debug!(latency_ms, response_bytes, "Received response");
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = response_bytes;
+    }
+}
+
+/// Record an error returned by a query.
+/// For parsing errors, only the key path is recorded - not the source json.
+fn record_error(e: &crate::Error) {
+    #[cfg(feature = "tracing")]
+    match e.kind() {
+        ErrorKind::JsonParsing(e) => {
+            tracing::warn!(key_path = e.key(), "Error parsing query response")
+        }
+        _ => tracing::warn!(error = %e, "Query returned an error"),
+    }
+    #[cfg(not(feature = "tracing"))]
+    let _ = e;
+}
+
+#[cfg(test)]
+#[cfg(feature = "tracing")]
+mod tests {
+    use crate::YtMusicBuilder;
+    use crate::common::{SongTrackingUrl, YoutubeID};
+    use crate::query::{AddHistoryItemQuery, GetLibrarySongsQuery};
+    use futures::TryStreamExt;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use ytmapi_mock_server::{MockServer, Route, Routes};
+
+    const MOCK_COOKIE: &str = "SAPISID=ytmapi-mock-server; __Secure-3PAPISID=ytmapi-mock-server;";
+
+    /// Fields recorded on each ytmapi_query span, in order of creation.
+    type SpanLog = Arc<Mutex<Vec<HashMap<String, String>>>>;
+
+    /// Layer that records the fields of each span, and every field value seen
+    /// in spans or events.
+    #[derive(Default, Clone)]
+    struct RecordingLayer {
+        spans: SpanLog,
+        span_ids: Arc<Mutex<HashMap<Id, usize>>>,
+        all_values: Arc<Mutex<Vec<String>>>,
+    }
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+    }
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for RecordingLayer {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _: Context<'_, S>) {
+            let mut fields = HashMap::new();
+            attrs.record(&mut FieldVisitor(&mut fields));
+            self.all_values
+                .lock()
+                .unwrap()
+                .extend(fields.values().cloned());
+            let mut spans = self.spans.lock().unwrap();
+            self.span_ids
+                .lock()
+                .unwrap()
+                .insert(id.clone(), spans.len());
+            spans.push(fields);
+        }
+        fn on_record(&self, id: &Id, values: &Record<'_>, _: Context<'_, S>) {
+            let mut fields = HashMap::new();
+            values.record(&mut FieldVisitor(&mut fields));
+            self.all_values
+                .lock()
+                .unwrap()
+                .extend(fields.values().cloned());
+            let idx = self.span_ids.lock().unwrap()[id];
+            self.spans.lock().unwrap()[idx].extend(fields);
+        }
+        fn on_event(&self, event: &tracing::Event<'_>, _: Context<'_, S>) {
+            let mut fields = HashMap::new();
+            event.record(&mut FieldVisitor(&mut fields));
+            self.all_values.lock().unwrap().extend(fields.into_values());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_spans() {
+        let layer = RecordingLayer::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(layer.clone()));
+        let routes = Routes::new().with_route(Route::post("browse").respond_with_pages([
+            "test_json/get_library_songs_20240701.json",
+            "test_json/get_library_songs_continuation_20240910.json",
+        ]));
+        let server = MockServer::start(routes).await.unwrap();
+        let yt = YtMusicBuilder::new()
+            .with_base_url(server.base_url())
+            .with_browser_token_cookie(MOCK_COOKIE.to_string())
+            .build()
+            .await
+            .unwrap();
+        let _: Vec<_> = yt
+            .stream(&GetLibrarySongsQuery::default())
+            .try_collect()
+            .await
+            .unwrap();
+        // The query string of a GET query must not be recorded.
+        let tracking_url = format!("{}/?docid=SECRET_DOCID", server.base_url());
+        // The mock server responds to the tracking url with its homepage, so only
+        // the raw response is requested.
+        yt.raw_json_query::<AddHistoryItemQuery>(AddHistoryItemQuery::new(
+            SongTrackingUrl::from_raw(tracking_url),
+        ))
+        .await
+        .unwrap();
+
+        let spans = layer.spans.lock().unwrap();
+        let spans: Vec<_> = spans
+            .iter()
+            .map(|span| {
+                let field = |name: &str| span.get(name).map(String::as_str);
+                (
+                    field("query").map(|q| q.rsplit("::").next().unwrap()),
+                    field("auth").map(|a| a.rsplit("::").next().unwrap()),
+                    field("page"),
+                    field("endpoint"),
+                    field("latency_ms").is_some(),
+                    field("response_bytes").is_some(),
+                )
+            })
+            .collect();
+        pretty_assertions::assert_eq!(
+            spans,
+            [
+                (
+                    Some("GetLibrarySongsQuery"),
+                    Some("BrowserToken"),
+                    Some("0"),
+                    Some("browse"),
+                    true,
+                    true
+                ),
+                (
+                    Some("GetLibrarySongsQuery"),
+                    Some("BrowserToken"),
+                    Some("1"),
+                    Some("browse"),
+                    true,
+                    true
+                ),
+                (
+                    Some("AddHistoryItemQuery<'_>"),
+                    Some("BrowserToken"),
+                    None,
+                    Some("/"),
+                    true,
+                    true
+                ),
+            ]
+        );
+        // Cookies, the SAPISID derived authorization header and query strings are
+        // never recorded.
+        let all_values = layer.all_values.lock().unwrap();
+        for value in all_values.iter() {
+            assert!(!value.contains("ytmapi-mock-server"), "{value}");
+            assert!(!value.contains("SAPISIDHASH"), "{value}");
+            assert!(!value.contains("SECRET_DOCID"), "{value}");
+        }
+    }
+}
@@ -69,6 +69,19 @@
 //! - **simplified_queries**: Adds convenience methods to [`YtMusic`].
 //! - **serde_json**: Enables some interoperability functions with `serde_json`.
 //! - **reqwest**: Enables some interoperability functions with `reqwest`.
+//! - **socks**: Enables the use of socks5 proxies with
+//!   [`builder::YtMusicBuilder::with_proxy`].
+//! - **blocking**: Adds a blocking (synchronous) API handle -
+//!   [`blocking::YtMusic`].
+//! - **tower**: Implements `tower::Service` for [`YtMusic`], and allows a
+//!   `tower` service to be used as the HTTP transport - see
+//!   [`Client::new_from_tower_service`].
+//! - **tracing**: Instruments each query and continuation page with a
+//!   `tracing` span, recording the query and auth token types, endpoint,
+//!   latency, response size and the key path of any parse errors. Cookies and
+//!   tokens are never recorded.
+//! - **schemars**: Implements `schemars::JsonSchema` for query outputs and IDs,
+//!   and exports versioned JSON Schemas for them - see [`schema`].
 // For feature specific documentation.
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #[cfg(not(any(feature = "rustls", feature = "native-tls", feature = "default-tls")))]
@@ -76,12 +89,12 @@ compile_error!("One of the TLS features must be enabled for this crate");
 use auth::browser::BrowserToken;
 use auth::noauth::NoAuthToken;
 use auth::oauth::OAuthDeviceCode;
-use auth::{AuthToken, OAuthToken, OAuthTokenGenerator, RawResult};
+use auth::{AuthToken, LoggedIn, OAuthToken, OAuthTokenGenerator, RawResult};
 #[doc(inline)]
 pub use builder::YtMusicBuilder;
 #[doc(inline)]
 pub use client::Client;
-use common::ApiOutcome;
+use common::{ApiOutcome, PlaylistID, VideoID};
 use continuations::ParseFromContinuable;
 #[doc(inline)]
 pub use error::{Error, Result};
@@ -90,6 +103,7 @@ use json::Json;
 use parse::ParseFrom;
 #[doc(inline)]
 pub use parse::ProcessedResult;
+use playlist_sync::{PlaylistSyncPlan, PlaylistSyncReport};
 use query::{PostQuery, Query, QueryMethod};
 use std::borrow::Borrow;
 use std::hash::{DefaultHasher, Hash, Hasher};
@@ -97,19 +111,31 @@ use std::path::Path;
 
 #[macro_use]
 mod utils;
+mod instrument;
 mod nav_consts;
+#[cfg(feature = "tower")]
+mod service;
 mod upload_song;
 mod youtube_enums;
 
 pub mod auth;
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
 pub mod builder;
 pub mod client;
+pub mod client_profile;
 pub mod common;
 pub mod continuations;
+pub mod decipher;
 pub mod error;
 pub mod json;
 pub mod parse;
+pub mod playlist_sync;
 pub mod query;
+#[cfg(feature = "schemars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "schemars")))]
+pub mod schema;
 
 #[cfg(feature = "simplified-queries")]
 #[cfg_attr(docsrs, doc(cfg(feature = "simplified-queries")))]
@@ -145,6 +171,19 @@ impl YtMusic<NoAuthToken> {
         let token = NoAuthToken::new(&client).await?;
         Ok(YtMusic { client, token })
     }
+    /// Refresh the internal token if it's older than `max_age`, and return a
+    /// clone of it (for user to store locally, e.g).
+    pub async fn validate_or_refresh_token(
+        &mut self,
+        max_age: chrono::TimeDelta,
+    ) -> Result<NoAuthToken> {
+        self.token = self
+            .token
+            .clone()
+            .validate_or_refresh(&self.client, max_age)
+            .await?;
+        Ok(self.token.clone())
+    }
 }
 impl YtMusic<BrowserToken> {
     /// Create a new API handle using a BrowserToken.
@@ -186,6 +225,19 @@ impl YtMusic<BrowserToken> {
     pub async fn upload_song(&self, file_path: impl AsRef<Path>) -> Result<ApiOutcome> {
         upload_song::upload_song(file_path, &self.token, &self.client).await
     }
+    /// Refresh the internal browser token if it's older than `max_age`, and
+    /// return a clone of it (for user to store locally, e.g).
+    pub async fn validate_or_refresh_token(
+        &mut self,
+        max_age: chrono::TimeDelta,
+    ) -> Result<BrowserToken> {
+        self.token = self
+            .token
+            .clone()
+            .validate_or_refresh(&self.client, max_age)
+            .await?;
+        Ok(self.token.clone())
+    }
 }
 impl YtMusic<OAuthToken> {
     /// Create a new API handle using an OAuthToken.
@@ -237,9 +289,12 @@ impl<A: AuthToken> YtMusic<A> {
     /// # };
     /// ```
     pub async fn raw_json_query<Q: Query<A>>(&self, query: impl Borrow<Q>) -> Result<String> {
-        Q::Method::call(query.borrow(), &self.client, &self.token)
-            .await
-            .map(|raw| raw.json)
+        instrument::in_query_span::<Q, A, _>(None, async {
+            Q::Method::call(query.borrow(), &self.client, &self.token)
+                .await
+                .map(|raw| raw.json)
+        })
+        .await
     }
     /// Return a result from YouTube music that has had errors removed and been
     /// deserialized into parsable JSON.
@@ -259,10 +314,13 @@ impl<A: AuthToken> YtMusic<A> {
     /// # };
     /// ```
     pub async fn json_query<Q: Query<A>>(&self, query: impl Borrow<Q>) -> Result<Json> {
-        Q::Method::call(query.borrow(), &self.client, &self.token)
-            .await?
-            .process()
-            .map(|processed| processed.json)
+        instrument::in_query_span::<Q, A, _>(None, async {
+            Q::Method::call(query.borrow(), &self.client, &self.token)
+                .await?
+                .process()
+                .map(|processed| processed.json)
+        })
+        .await
     }
     /// Run a Query on the API returning its output.
     /// # Usage
@@ -277,11 +335,14 @@ impl<A: AuthToken> YtMusic<A> {
     /// # };
     /// ```
     pub async fn query<Q: Query<A>>(&self, query: impl Borrow<Q>) -> Result<Q::Output> {
-        Q::Output::parse_from(
-            Q::Method::call(query.borrow(), &self.client, &self.token)
-                .await?
-                .process()?,
-        )
+        instrument::in_query_span::<Q, A, _>(None, async {
+            Q::Output::parse_from(
+                Q::Method::call(query.borrow(), &self.client, &self.token)
+                    .await?
+                    .process()?,
+            )
+        })
+        .await
     }
     /// Stream a query that has 'continuations', i.e can continue to stream
     /// results.
@@ -306,7 +367,7 @@ impl<A: AuthToken> YtMusic<A> {
         Q: PostQuery,
         Q::Output: ParseFromContinuable<Q>,
     {
-        continuations::stream(query, &self.client, &self.token)
+        continuations::stream::<Q, A>(query, &self.client, &self.token)
     }
     /// Return the source JSON from streaming a query that has 'continuations',
     /// i.e can continue to stream results.
@@ -336,7 +397,51 @@ impl<A: AuthToken> YtMusic<A> {
         Q: PostQuery,
         Q::Output: ParseFromContinuable<Q>,
     {
-        continuations::raw_json_stream(query, &self.client, &self.token)
+        continuations::raw_json_stream::<Q, A>(query, &self.client, &self.token)
+    }
+}
+impl<A: LoggedIn> YtMusic<A> {
+    /// Compute the steps required to sync a playlist to the desired ordered
+    /// list of videos, without changing the playlist (dry run).
+    /// See [`playlist_sync`] for a usage example.
+    pub async fn plan_playlist_sync<'a>(
+        &self,
+        playlist_id: PlaylistID<'a>,
+        desired: impl IntoIterator<Item = VideoID<'a>>,
+    ) -> Result<PlaylistSyncPlan<'a>> {
+        playlist_sync::plan_playlist_sync(self, playlist_id, desired).await
+    }
+    /// Execute a previously computed plan to sync a playlist, returning the
+    /// outcome of each step.
+    /// See [`playlist_sync`] for a usage example.
+    pub async fn execute_playlist_sync<'a>(
+        &self,
+        plan: &PlaylistSyncPlan<'a>,
+    ) -> PlaylistSyncReport<'a> {
+        playlist_sync::execute_playlist_sync(self, plan).await
+    }
+    /// Sync a playlist to the desired ordered list of videos, using the minimum
+    /// number of add, remove and move actions.
+    /// # Usage
+    /// ```no_run
+    /// use ytmapi_rs::common::{PlaylistID, VideoID, YoutubeID};
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE").await?;
+    /// let desired = [VideoID::from_raw("VIDEO_1"), VideoID::from_raw("VIDEO_2")];
+    /// let report = yt
+    ///     .sync_playlist(PlaylistID::from_raw("PLAYLIST_ID"), desired)
+    ///     .await?;
+    /// assert!(report.is_success());
+    /// # Ok::<(), ytmapi_rs::Error>(())
+    /// # };
+    /// ```
+    pub async fn sync_playlist<'a>(
+        &self,
+        playlist_id: PlaylistID<'a>,
+        desired: impl IntoIterator<Item = VideoID<'a>>,
+    ) -> Result<PlaylistSyncReport<'a>> {
+        let plan = self.plan_playlist_sync(playlist_id, desired).await?;
+        Ok(self.execute_playlist_sync(&plan).await)
     }
 }
 /// Generates a tuple containing fresh OAuthDeviceCode and corresponding url for
@@ -15,7 +15,7 @@ pub const MUSIC_PLAYLIST_SHELF: &str = "/musicPlaylistShelfRenderer";
 pub const GRID: &str = "/gridRenderer";
 pub const MENU: &str = "/menu/menuRenderer";
 pub const MENU_SERVICE: &str = "/menuServiceItemRenderer/serviceEndpoint";
-pub const _TOGGLE_MENU: &str = "/toggleMenuServiceItemRenderer";
+pub const TOGGLE_MENU: &str = "/toggleMenuServiceItemRenderer";
 pub const PLAY_BUTTON: &str =
     "/overlay/musicItemThumbnailOverlayRenderer/content/musicPlayButtonRenderer";
 pub const NAVIGATION_BROWSE: &str = "/navigationEndpoint/browseEndpoint";
@@ -86,6 +86,7 @@ pub const TOGGLED_ENDPOINT: &str = concatcp!("/toggledServiceEndpoint", FEEDBACK
 pub const TITLE_TEXT: &str = concatcp!("/title", RUN_TEXT);
 pub const NAVIGATION_VIDEO_ID: &str = concatcp!("/navigationEndpoint", WATCH_VIDEO_ID);
 pub const PLAYLIST_ITEM_VIDEO_ID: &str = "/playlistItemData/videoId";
+pub const PLAYLIST_ITEM_SET_VIDEO_ID: &str = "/playlistItemData/playlistSetVideoId";
 pub const SINGLE_COLUMN_TAB: &str = concatcp!(SINGLE_COLUMN, TAB_CONTENT);
 pub const SECTION_LIST_ITEM: &str = concatcp!("/sectionListRenderer", CONTENT);
 pub const SECONDARY_SECTION_LIST_RENDERER: &str =
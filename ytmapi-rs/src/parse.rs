@@ -21,7 +21,7 @@
 //! `json-crawler` as a dependency and use the provided
 //! `From<ProcessedResult> for JsonCrawlerOwned` implementation.
 use crate::auth::AuthToken;
-use crate::common::{AlbumID, ArtistChannelID, Thumbnail};
+use crate::common::{AlbumID, ApiOutcome, ArtistChannelID, Thumbnail};
 use crate::json::Json;
 use crate::nav_consts::*;
 use crate::{RawResult, Result, error};
@@ -38,6 +38,10 @@ mod history;
 pub use history::*;
 mod library;
 pub use library::*;
+mod numeric;
+pub use numeric::{
+    ENGLISH_COUNT_SUFFIXES, parse_count, parse_count_with_suffixes, parse_duration, parse_year,
+};
 mod playlist;
 pub use playlist::*;
 mod podcasts;
@@ -67,24 +71,38 @@ pub trait ParseFrom<Q>: Debug + Sized {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum EpisodeDate {
     Live,
     Recorded { date: String },
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum EpisodeDuration {
     Live,
     Recorded { duration: String },
 }
 
+impl EpisodeDuration {
+    /// Parse the duration of a recorded episode, if possible.
+    pub fn parsed_duration(&self) -> Option<std::time::Duration> {
+        match self {
+            EpisodeDuration::Live => None,
+            EpisodeDuration::Recorded { duration } => parse_duration(duration),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 // Intentionally not marked non_exhaustive - not expecting this to change.
 pub struct ParsedSongArtist {
     pub name: String,
     pub id: Option<ArtistChannelID<'static>>,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 // Intentionally not marked non_exhaustive - not expecting this to change.
 pub struct ParsedSongAlbum {
     pub name: String,
@@ -153,6 +171,31 @@ impl<Q> From<ProcessedResult<'_, Q>> for JsonCrawlerOwned {
     }
 }
 
+/// Parse the response to a single feedback token sent to the `feedback`
+/// endpoint.
+fn parse_feedback_outcome(json_crawler: JsonCrawlerOwned) -> Result<ApiOutcome> {
+    let processed = json_crawler
+        .navigate_pointer("/feedbackResponses/0/isProcessed")?
+        .take_value()?;
+    match processed {
+        true => Ok(ApiOutcome::Success),
+        false => Ok(ApiOutcome::Failure),
+    }
+}
+/// Captured feedback response, trimmed to only its processed feedback entry,
+/// since the capture sent several tokens at once.
+#[cfg(test)]
+pub(crate) async fn processed_feedback_response() -> String {
+    let source = tokio::fs::read_to_string("./test_json/remove_history_items_20240704.json")
+        .await
+        .expect("Expect file read to pass during tests");
+    let mut json: serde_json::Value = serde_json::from_str(&source).unwrap();
+    let responses = json["feedbackResponses"].as_array_mut().unwrap();
+    responses.retain(|r| r["isProcessed"] == true);
+    assert_eq!(responses.len(), 1);
+    json.to_string()
+}
+
 fn fixed_column_item_pointer(col_idx: usize) -> String {
     format!("/fixedColumns/{col_idx}/musicResponsiveListItemFixedColumnRenderer")
 }
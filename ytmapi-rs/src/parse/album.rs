@@ -4,10 +4,12 @@ use super::{
 };
 use crate::Result;
 use crate::common::{
-    AlbumType, Explicit, LibraryManager, LibraryStatus, LikeStatus, PlaylistID, Thumbnail, VideoID,
+    AlbumID, AlbumType, Explicit, LibraryManager, LibraryStatus, LikeStatus, PlaylistID, Thumbnail,
+    VideoID,
 };
 use crate::nav_consts::*;
 use crate::query::*;
+use crate::youtube_enums::YoutubeMusicEntityPageType;
 use const_format::concatcp;
 use json_crawler::{
     CrawlerResult, JsonCrawler, JsonCrawlerBorrowed, JsonCrawlerIterator, JsonCrawlerOwned,
@@ -16,12 +18,14 @@ use serde::{Deserialize, Serialize};
 
 /// In some contexts, dislike will also be classified as indifferent.
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum InLikedSongs {
     Liked,
     Indifferent,
 }
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct AlbumSong {
     pub video_id: VideoID<'static>,
@@ -36,10 +40,15 @@ pub struct AlbumSong {
     pub like_status: LikeStatus,
     pub explicit: Explicit,
 }
+impl_parsed_fields!(AlbumSong {
+    parsed_duration: duration as duration,
+    parsed_plays: plays as count,
+});
 
 // Is this similar to another struct?
 // XXX: Consider correct privacy
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetAlbum {
     pub title: String,
@@ -55,7 +64,36 @@ pub struct GetAlbum {
     // TODO: better interface
     pub tracks: Vec<AlbumSong>,
     pub library_status: LibraryStatus,
+    /// Other versions of this album, e.g deluxe, explicit or clean editions.
+    #[serde(default)]
+    pub other_versions: Vec<RelatedAlbum>,
+    /// Other releases by the album artist.
+    #[serde(default)]
+    pub more_from_artist: Vec<RelatedAlbum>,
 }
+impl_parsed_fields!(GetAlbum {
+    parsed_year: year as year,
+    parsed_duration: duration as duration,
+});
+
+/// An album listed on an album page, in the other versions or more from artist
+/// shelves.
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RelatedAlbum {
+    pub title: String,
+    pub album_type: AlbumType,
+    /// Other versions list the album artists instead of the year.
+    pub year: Option<String>,
+    pub artists: Vec<ParsedSongArtist>,
+    pub album_id: AlbumID<'static>,
+    pub thumbnails: Vec<Thumbnail>,
+    pub explicit: Explicit,
+}
+impl_parsed_fields!(RelatedAlbum {
+    parsed_year: year as year,
+});
 
 impl<'a> ParseFrom<GetAlbumQuery<'a>> for GetAlbum {
     fn parse_from(p: ProcessedResult<GetAlbumQuery<'a>>) -> crate::Result<Self> {
@@ -161,6 +199,32 @@ fn parse_album_query(p: ProcessedResult<GetAlbumQuery>) -> Result<GetAlbum> {
         .try_into_iter()?
         .filter_map(|mut track| parse_album_track(&mut track).transpose())
         .collect::<Result<Vec<AlbumSong>>>()?;
+    let mut other_versions = Vec::new();
+    let mut more_from_artist = Vec::new();
+    for section in columns
+        .navigate_pointer("/secondaryContents/sectionListRenderer/contents")?
+        .try_into_iter()?
+    {
+        let Ok(mut carousel) = section.navigate_pointer(CAROUSEL) else {
+            continue;
+        };
+        let Some(shelf) = related_album_shelf(&mut carousel) else {
+            continue;
+        };
+        let albums = match shelf {
+            RelatedAlbumShelf::OtherVersions => &mut other_versions,
+            RelatedAlbumShelf::MoreFromArtist => &mut more_from_artist,
+        };
+        // Only the first shelf of each kind is used.
+        if !albums.is_empty() {
+            continue;
+        }
+        *albums = carousel
+            .navigate_pointer("/contents")?
+            .try_into_iter()?
+            .map(|item| parse_related_album(item.navigate_pointer(MTRIR)?, shelf))
+            .collect::<Result<_>>()?;
+    }
     Ok(GetAlbum {
         library_status,
         title,
@@ -174,6 +238,93 @@ fn parse_album_query(p: ProcessedResult<GetAlbumQuery>) -> Result<GetAlbum> {
         tracks,
         artists,
         thumbnails,
+        other_versions,
+        more_from_artist,
+    })
+}
+
+/// The shelves of related albums that can be shown below the tracks on an
+/// album page.
+#[derive(Clone, Copy)]
+enum RelatedAlbumShelf {
+    /// Subtitle is in the format: "Album • Artist 1 & Artist 2".
+    OtherVersions,
+    /// Subtitle is in the format: "Album • 2024".
+    MoreFromArtist,
+}
+
+// Carousel titles are localised, so the shelves are identified by their
+// structure instead. The more from artist title links to the artist's page,
+// whereas the other versions title has no link and the shelf only contains
+// albums. Returns None for any other carousel.
+fn related_album_shelf(carousel: &mut JsonCrawlerOwned) -> Option<RelatedAlbumShelf> {
+    if carousel.path_exists(concatcp!(CAROUSEL_TITLE, NAVIGATION_BROWSE)) {
+        return carousel
+            .borrow_value_pointer::<YoutubeMusicEntityPageType>(concatcp!(
+                CAROUSEL_TITLE,
+                NAVIGATION_BROWSE,
+                PAGE_TYPE
+            ))
+            .is_ok_and(|page_type| page_type == YoutubeMusicEntityPageType::Artist)
+            .then_some(RelatedAlbumShelf::MoreFromArtist);
+    }
+    let only_albums = carousel
+        .borrow_pointer("/contents")
+        .and_then(|mut contents| {
+            Ok(contents.try_iter_mut()?.all(|item| {
+                item.borrow_value_pointer::<YoutubeMusicEntityPageType>(concatcp!(
+                    MTRIR,
+                    TITLE,
+                    NAVIGATION_BROWSE,
+                    PAGE_TYPE
+                ))
+                .is_ok_and(|page_type| page_type == YoutubeMusicEntityPageType::Album)
+            }))
+        })
+        .unwrap_or_default();
+    only_albums.then_some(RelatedAlbumShelf::OtherVersions)
+}
+
+fn parse_related_album(
+    mut item: JsonCrawlerOwned,
+    shelf: RelatedAlbumShelf,
+) -> Result<RelatedAlbum> {
+    let title = item.take_value_pointer(TITLE_TEXT)?;
+    let album_type = item.take_value_pointer(SUBTITLE)?;
+    let album_id = item.take_value_pointer(concatcp!(TITLE, NAVIGATION_BROWSE_ID))?;
+    let thumbnails = item.take_value_pointer(THUMBNAIL_RENDERER)?;
+    let explicit = if item.path_exists(SUBTITLE_BADGE_LABEL) {
+        Explicit::IsExplicit
+    } else {
+        Explicit::NotExplicit
+    };
+    // Skip the album type and separators.
+    let mut runs = item
+        .borrow_pointer(SUBTITLE_RUNS)?
+        .try_into_iter()?
+        .skip(2)
+        .step_by(2);
+    let (year, artists) = match shelf {
+        RelatedAlbumShelf::OtherVersions => (
+            None,
+            runs.map(|mut run| parse_song_artist(&mut run))
+                .collect::<Result<_>>()?,
+        ),
+        RelatedAlbumShelf::MoreFromArtist => (
+            runs.next()
+                .map(|mut run| run.take_value_pointer("/text"))
+                .transpose()?,
+            Vec::new(),
+        ),
+    };
+    Ok(RelatedAlbum {
+        title,
+        album_type,
+        year,
+        artists,
+        album_id,
+        thumbnails,
+        explicit,
     })
 }
 
@@ -194,6 +345,57 @@ mod tests {
         );
     }
     #[tokio::test]
+    async fn test_get_album_query_other_versions() {
+        parse_test!(
+            "./test_json/get_album_other_versions_mock.json",
+            "./test_json/get_album_other_versions_mock_output.txt",
+            GetAlbumQuery::new(AlbumID::from_raw("")),
+            BrowserToken
+        );
+    }
+    #[test]
+    fn test_get_album_query_related_album_shelves() {
+        let source =
+            std::fs::read_to_string("./test_json/get_album_other_versions_mock.json").unwrap();
+        let expected = crate::process_json::<_, BrowserToken>(
+            source.clone(),
+            GetAlbumQuery::new(AlbumID::from_raw("")),
+        )
+        .unwrap();
+        assert!(expected.other_versions[0].year.is_none());
+        assert!(!expected.other_versions[0].artists.is_empty());
+        assert_eq!(expected.more_from_artist[0].year.as_deref(), Some("2024"));
+        // Swap the order of the shelves, and surround them with carousels of
+        // playlists that have no title link. Each shelf should still be identified.
+        let mut json: serde_json::Value = serde_json::from_str(&source).unwrap();
+        let sections = json
+            .pointer_mut(
+                "/contents/twoColumnBrowseResultsRenderer/secondaryContents/sectionListRenderer/contents",
+            )
+            .and_then(serde_json::Value::as_array_mut)
+            .unwrap();
+        let mut playlists = sections[1].clone();
+        for item in playlists
+            .pointer_mut("/musicCarouselShelfRenderer/contents")
+            .and_then(serde_json::Value::as_array_mut)
+            .unwrap()
+        {
+            *item
+                .pointer_mut("/musicTwoRowItemRenderer/title/runs/0/navigationEndpoint/browseEndpoint/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType")
+                .unwrap() = "MUSIC_PAGE_TYPE_PLAYLIST".into();
+        }
+        sections.swap(1, 2);
+        sections.insert(1, playlists.clone());
+        sections.push(playlists);
+        let output = crate::process_json::<_, BrowserToken>(
+            json.to_string(),
+            GetAlbumQuery::new(AlbumID::from_raw("")),
+        )
+        .unwrap();
+        assert_eq!(output.other_versions, expected.other_versions);
+        assert_eq!(output.more_from_artist, expected.more_from_artist);
+    }
+    #[tokio::test]
     async fn test_get_album_query_no_artist_thumbnail() {
         parse_test!(
             "./test_json/get_album_various_artists_no_thumbnail_20240818.json",
@@ -15,6 +15,7 @@ use json_crawler::{JsonCrawler, JsonCrawlerIterator, JsonCrawlerOwned};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetArtist {
     pub description: Option<String>,
@@ -28,8 +29,13 @@ pub struct GetArtist {
     pub thumbnails: Vec<Thumbnail>,
     pub top_releases: GetArtistTopReleases,
 }
+impl_parsed_fields!(GetArtist {
+    parsed_views: views as count,
+    parsed_subscribers: subscribers as count,
+});
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetArtistAlbumsAlbum {
     pub title: String,
@@ -41,6 +47,9 @@ pub struct GetArtistAlbumsAlbum {
     pub thumbnails: Vec<Thumbnail>,
     pub year: Option<String>,
 }
+impl_parsed_fields!(GetArtistAlbumsAlbum {
+    parsed_year: year as year,
+});
 
 impl<'a> ParseFrom<GetArtistQuery<'a>> for GetArtist {
     fn parse_from(p: ProcessedResult<GetArtistQuery<'a>>) -> crate::Result<Self> {
@@ -121,6 +130,7 @@ impl ParseFrom<UnsubscribeArtistsQuery<'_>> for () {
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetArtistTopReleases {
     pub songs: Option<GetArtistSongs>,
@@ -130,17 +140,20 @@ pub struct GetArtistTopReleases {
     pub related: Option<GetArtistRelated>,
 }
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetArtistRelated {
     pub results: Vec<RelatedResult>,
 }
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetArtistSongs {
     pub results: Vec<ArtistSong>,
     pub browse_id: PlaylistID<'static>,
 }
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct ArtistSong {
     pub video_id: VideoID<'static>,
@@ -155,7 +168,11 @@ pub struct ArtistSong {
     pub like_status: LikeStatus,
     pub explicit: Explicit,
 }
+impl_parsed_fields!(ArtistSong {
+    parsed_plays: plays as count,
+});
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetArtistVideos {
     pub results: Vec<SearchResultVideo>,
@@ -166,6 +183,7 @@ pub struct GetArtistVideos {
 /// albums. If they aren't set, and results is not empty, you can assume that
 /// all albums are displayed here already.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetArtistAlbums {
     pub results: Vec<AlbumResult>,
@@ -174,13 +192,18 @@ pub struct GetArtistAlbums {
     pub params: Option<BrowseParams<'static>>,
 }
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct RelatedResult {
     pub browse_id: ArtistChannelID<'static>,
     pub title: String,
     pub subscribers: String,
 }
+impl_parsed_fields!(RelatedResult {
+    parsed_subscribers: subscribers as count,
+});
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct AlbumResult {
     pub title: String,
@@ -192,8 +215,12 @@ pub struct AlbumResult {
     pub thumbnails: Vec<Thumbnail>,
     pub explicit: Explicit,
 }
+impl_parsed_fields!(AlbumResult {
+    parsed_year: year as year,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 // Could this alternatively be Result<Song>?
 // May need to be enum to track 'Not Available' case.
@@ -215,9 +242,13 @@ pub struct TableListSong {
     /// Id of the playlist that will get created when pressing 'Start Radio'.
     pub playlist_id: PlaylistID<'static>,
 }
+impl_parsed_fields!(TableListSong {
+    parsed_duration: duration as duration,
+});
 
 // Should be at higher level in mod structure.
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 enum ArtistTopReleaseCategory {
     #[serde(alias = "albums")]
     Albums,
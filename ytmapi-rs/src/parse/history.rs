@@ -6,28 +6,53 @@ use super::{
 };
 use crate::Result;
 use crate::common::{
-    ApiOutcome, ArtistChannelID, EpisodeID, Explicit, FeedbackTokenRemoveFromHistory,
-    LibraryManager, LikeStatus, PlaylistID, Thumbnail, UploadEntityID, VideoID,
+    ApiOutcome, ArtistChannelID, EpisodeID, Explicit, FeedbackTokenAddToLibrary,
+    FeedbackTokenRemoveFromHistory, LibraryManager, LikeStatus, PlaylistID, Thumbnail,
+    UploadEntityID, VideoID,
 };
 use crate::nav_consts::{
-    FEEDBACK_TOKEN, LIVE_BADGE_LABEL, MENU_SERVICE, NAVIGATION_BROWSE_ID, NAVIGATION_PLAYLIST_ID,
-    NAVIGATION_VIDEO_TYPE, PLAY_BUTTON, SECTION_LIST, SINGLE_COLUMN_TAB, TEXT_RUN, WATCH_VIDEO_ID,
+    FEEDBACK_TOKEN, LIVE_BADGE_LABEL, MENU, MENU_SERVICE, NAVIGATION_BROWSE_ID,
+    NAVIGATION_PLAYLIST_ID, NAVIGATION_VIDEO_TYPE, PLAY_BUTTON, SECTION_LIST, SINGLE_COLUMN_TAB,
+    TEXT_RUN, WATCH_VIDEO_ID,
 };
 use crate::parse::parse_flex_column_item;
 use crate::query::{AddHistoryItemQuery, GetHistoryQuery, RemoveHistoryItemsQuery};
 use crate::youtube_enums::YoutubeMusicVideoType;
+use chrono::{Datelike, Days, Months, NaiveDate};
 use const_format::concatcp;
 use json_crawler::{JsonCrawler, JsonCrawlerBorrowed, JsonCrawlerIterator, JsonCrawlerOwned};
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct HistoryPeriod {
     pub period_name: String,
     pub items: Vec<HistoryItem>,
 }
+/// Inclusive range of dates covered by a [`HistoryPeriod`]. Only able to be
+/// resolved from English period names, see
+/// [`HistoryPeriod::parsed_date_range`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HistoryDateRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+impl HistoryPeriod {
+    /// Resolve the period name into the range of dates it covers, relative to
+    /// `today`. Returns None if the period name is not recognised.
+    /// # NOTE
+    /// Period names are language dependent - only English period names are
+    /// able to be resolved, e.g "Today", "Last week" or "June 2024". Weeks are
+    /// assumed to start on a Monday.
+    pub fn parsed_date_range(&self, today: NaiveDate) -> Option<HistoryDateRange> {
+        parse_history_period_name(&self.period_name, today)
+    }
+}
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum HistoryItem {
     Song(HistoryItemSong),
     Video(HistoryItemVideo),
@@ -36,6 +61,7 @@ pub enum HistoryItem {
 }
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 // Could this alternatively be Result<Song>?
 // May need to be enum to track 'Not Available' case.
@@ -48,8 +74,14 @@ pub struct HistoryItemSong {
     pub library_management: Option<LibraryManager>,
     pub title: String,
     pub artists: Vec<super::ParsedSongArtist>,
-    // TODO: Song like feedback tokens.
     pub like_status: LikeStatus,
+    /// Liking an item also adds it to your library - this is the library token
+    /// sent with the like, for use with `EditSongLibraryStatusQuery`.
+    /// None if the item cannot be liked, or no token was returned.
+    /// # NOTE
+    /// YouTube Music doesn't return like or dislike tokens - to like or
+    /// dislike an item, use `RateSongQuery` with its `video_id`.
+    pub like_library_token: Option<FeedbackTokenAddToLibrary<'static>>,
     pub thumbnails: Vec<super::Thumbnail>,
     pub explicit: Explicit,
     pub is_available: bool,
@@ -57,8 +89,12 @@ pub struct HistoryItemSong {
     pub playlist_id: PlaylistID<'static>,
     pub feedback_token_remove: FeedbackTokenRemoveFromHistory<'static>,
 }
+impl_parsed_fields!(HistoryItemSong {
+    parsed_duration: duration as duration,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct HistoryItemVideo {
     pub video_id: VideoID<'static>,
@@ -67,16 +103,26 @@ pub struct HistoryItemVideo {
     // Could be 'ParsedVideoChannel'
     pub channel_name: String,
     pub channel_id: ArtistChannelID<'static>,
-    // TODO: Song like feedback tokens.
     pub like_status: LikeStatus,
+    /// Liking an item also adds it to your library - this is the library token
+    /// sent with the like, for use with `EditSongLibraryStatusQuery`.
+    /// None if the item cannot be liked, or no token was returned.
+    /// # NOTE
+    /// YouTube Music doesn't return like or dislike tokens - to like or
+    /// dislike an item, use `RateSongQuery` with its `video_id`.
+    pub like_library_token: Option<FeedbackTokenAddToLibrary<'static>>,
     pub thumbnails: Vec<super::Thumbnail>,
     pub is_available: bool,
     /// Id of the playlist that will get created when pressing 'Start Radio'.
     pub playlist_id: PlaylistID<'static>,
     pub feedback_token_remove: FeedbackTokenRemoveFromHistory<'static>,
 }
+impl_parsed_fields!(HistoryItemVideo {
+    parsed_duration: duration as duration,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct HistoryItemEpisode {
     pub episode_id: EpisodeID<'static>,
@@ -94,6 +140,7 @@ pub struct HistoryItemEpisode {
 }
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 // May need to be enum to track 'Not Available' case.
 // TODO: Move to common
@@ -108,6 +155,9 @@ pub struct HistoryItemUploadSong {
     pub thumbnails: Vec<Thumbnail>,
     pub feedback_token_remove: FeedbackTokenRemoveFromHistory<'static>,
 }
+impl_parsed_fields!(HistoryItemUploadSong {
+    parsed_duration: duration as duration,
+});
 
 impl ParseFrom<GetHistoryQuery> for Vec<HistoryPeriod> {
     fn parse_from(p: super::ProcessedResult<GetHistoryQuery>) -> Result<Self> {
@@ -148,6 +198,69 @@ impl ParseFrom<AddHistoryItemQuery<'_>> for () {
     }
 }
 
+/// Resolve an English history period name into a date range. See
+/// [`HistoryPeriod::parsed_date_range`].
+fn parse_history_period_name(period_name: &str, today: NaiveDate) -> Option<HistoryDateRange> {
+    let days_since_monday = u64::from(today.weekday().num_days_from_monday());
+    let this_week_start = today.checked_sub_days(Days::new(days_since_monday))?;
+    let range = |start, end| Some(HistoryDateRange { start, end });
+    match period_name.trim().to_lowercase().as_str() {
+        "today" => range(today, today),
+        "yesterday" => {
+            let yesterday = today.pred_opt()?;
+            range(yesterday, yesterday)
+        }
+        "this week" => range(this_week_start, today),
+        "last week" => range(
+            this_week_start.checked_sub_days(Days::new(7))?,
+            this_week_start.pred_opt()?,
+        ),
+        "this month" => range(today.with_day(1)?, today),
+        "last month" => {
+            let this_month_start = today.with_day(1)?;
+            range(
+                this_month_start.checked_sub_months(Months::new(1))?,
+                this_month_start.pred_opt()?,
+            )
+        }
+        other => {
+            if let Ok(start) = NaiveDate::parse_from_str(&format!("1 {other}"), "%d %B %Y") {
+                let end = start.checked_add_months(Months::new(1))?.pred_opt()?;
+                return range(start, end);
+            }
+            let year = other.parse().ok()?;
+            range(
+                NaiveDate::from_ymd_opt(year, 1, 1)?,
+                NaiveDate::from_ymd_opt(year, 12, 31)?,
+            )
+        }
+    }
+}
+/// Items without a like button (e.g unavailable songs) return None.
+fn parse_like_library_token(
+    data: &mut impl JsonCrawler,
+) -> Option<FeedbackTokenAddToLibrary<'static>> {
+    data.borrow_pointer(concatcp!(
+        MENU,
+        "/topLevelButtons/0/likeButtonRenderer/serviceEndpoints"
+    ))
+    .ok()?
+    .try_into_iter()
+    .ok()?
+    .find(|endpoint| {
+        endpoint
+            .borrow_value_pointer::<LikeStatus>("/likeEndpoint/status")
+            .is_ok_and(|status| status == LikeStatus::Liked)
+    })?
+    .navigate_pointer("/likeEndpoint/actions")
+    .ok()?
+    .try_into_iter()
+    .ok()?
+    .find_path("/musicLibraryStatusUpdateCommand/addToLibraryFeedbackToken")
+    .ok()?
+    .take_value()
+    .ok()
+}
 fn parse_history_period(json: JsonCrawlerOwned) -> Result<HistoryPeriod> {
     let mut data = json.navigate_pointer(MUSIC_SHELF)?;
     let period_name = data.take_value_pointer(TITLE_TEXT)?;
@@ -255,6 +368,7 @@ fn parse_history_item_video(
         WATCH_VIDEO_ID
     ))?;
     let like_status = data.take_value_pointer(MENU_LIKE_STATUS)?;
+    let like_library_token = parse_like_library_token(&mut data);
     let channel_name = parse_flex_column_item(&mut data, 1, 0)?;
     let channel_id = data
         .borrow_pointer(flex_column_item_pointer(1))?
@@ -281,6 +395,7 @@ fn parse_history_item_video(
         duration,
         title,
         like_status,
+        like_library_token,
         thumbnails,
         playlist_id,
         is_available,
@@ -337,6 +452,7 @@ fn parse_history_item_song(
     let library_management =
         parse_library_management_items_from_menu(data.borrow_pointer(MENU_ITEMS)?)?;
     let like_status = data.take_value_pointer(MENU_LIKE_STATUS)?;
+    let like_library_token = parse_like_library_token(&mut data);
     let artists = super::parse_song_artists(&mut data, 1)?;
     let album = super::parse_song_album(&mut data, 2)?;
     let duration = data
@@ -368,6 +484,7 @@ fn parse_history_item_song(
         title,
         artists,
         like_status,
+        like_library_token,
         thumbnails,
         explicit,
         album,
@@ -379,9 +496,11 @@ fn parse_history_item_song(
 
 #[cfg(test)]
 mod tests {
+    use super::parse_history_period_name;
     use crate::auth::BrowserToken;
     use crate::common::{SongTrackingUrl, YoutubeID};
     use crate::query::AddHistoryItemQuery;
+    use chrono::NaiveDate;
 
     #[tokio::test]
     async fn test_add_history_item_query() {
@@ -410,6 +529,20 @@ mod tests {
             BrowserToken
         );
     }
+    #[test]
+    fn test_history_period_date_range() {
+        let today = NaiveDate::from_ymd_opt(2024, 7, 13).unwrap();
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let range = |name: &str| parse_history_period_name(name, today).map(|r| (r.start, r.end));
+        assert_eq!(range("Today"), Some((date(7, 13), date(7, 13))));
+        assert_eq!(range("Yesterday"), Some((date(7, 12), date(7, 12))));
+        assert_eq!(range("This week"), Some((date(7, 8), date(7, 13))));
+        assert_eq!(range("Last week"), Some((date(7, 1), date(7, 7))));
+        assert_eq!(range("Last month"), Some((date(6, 1), date(6, 30))));
+        assert_eq!(range("June 2024"), Some((date(6, 1), date(6, 30))));
+        assert_eq!(range("2024"), Some((date(1, 1), date(12, 31))));
+        assert_eq!(range("Heute"), None);
+    }
     #[tokio::test]
     async fn test_remove_history_items() {
         parse_test!(
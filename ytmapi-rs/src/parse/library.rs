@@ -28,6 +28,7 @@ use json_crawler::{CrawlerResult, JsonCrawler, JsonCrawlerBorrowed, JsonCrawlerO
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 // Very similar to LibraryArtist struct
 pub struct GetLibraryArtistSubscription {
@@ -36,8 +37,12 @@ pub struct GetLibraryArtistSubscription {
     pub channel_id: ArtistChannelID<'static>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(GetLibraryArtistSubscription {
+    parsed_subscribers: subscribers as count,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 // Very similar to LibraryArtist struct
 pub struct LibraryArtistSubscription {
@@ -46,8 +51,12 @@ pub struct LibraryArtistSubscription {
     pub channel_id: ArtistChannelID<'static>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(LibraryArtistSubscription {
+    parsed_subscribers: subscribers as count,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct LibraryPlaylist {
     pub playlist_id: PlaylistID<'static>,
@@ -59,6 +68,7 @@ pub struct LibraryPlaylist {
     pub author_id: Option<ArtistChannelID<'static>>,
 }
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct LibraryArtist {
     pub channel_id: ArtistChannelID<'static>,
@@ -66,6 +76,7 @@ pub struct LibraryArtist {
     pub byline: String, // e.g 16 songs or 17.8k subscribers
 }
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct LibraryPodcast {
     pub title: String,
@@ -75,6 +86,7 @@ pub struct LibraryPodcast {
     pub podcast_source: PodcastSource,
 }
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct LibraryChannel {
     pub title: String,
@@ -82,8 +94,12 @@ pub struct LibraryChannel {
     pub channel_id: PodcastChannelID<'static>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(LibraryChannel {
+    parsed_subscribers: subscribers as count,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum PodcastSource {
     Rss,
     YouTube,
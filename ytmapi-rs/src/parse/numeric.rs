@@ -0,0 +1,185 @@
+//! Parsing of numeric fields that Innertube only returns as display strings.
+//! Parse outputs retain the raw strings for display, and expose typed
+//! accessors built on these functions, e.g
+//! [`SearchResultSong::parsed_plays`](crate::parse::SearchResultSong::parsed_plays).
+use std::time::Duration;
+
+/// Count suffixes used by YouTube Music when the language is English.
+pub const ENGLISH_COUNT_SUFFIXES: &[(&str, u64)] =
+    &[("K", 1_000), ("M", 1_000_000), ("B", 1_000_000_000)];
+
+/// Parse a duration such as "3:45", "1:02:03", "1 hour, 17 minutes" or "1 hr
+/// 10 mins".
+/// ```
+/// # use std::time::Duration;
+/// use ytmapi_rs::parse::parse_duration;
+/// assert_eq!(parse_duration("3:45"), Some(Duration::from_secs(225)));
+/// assert_eq!(parse_duration("1 hr 2 mins"), Some(Duration::from_secs(3720)));
+/// ```
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let s = s.trim();
+    if s.contains(':') {
+        let mut secs = 0u64;
+        for part in s.split(':') {
+            secs = secs
+                .checked_mul(60)?
+                .checked_add(part.trim().parse().ok()?)?;
+        }
+        return Some(Duration::from_secs(secs));
+    }
+    let mut tokens = s
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|t| !t.is_empty());
+    let mut secs = None;
+    while let Some(token) = tokens.next() {
+        let n = token.parse::<u64>().ok()?;
+        let unit = tokens.next()?.to_lowercase();
+        let multiplier = if unit.starts_with('h') {
+            60 * 60
+        } else if unit.starts_with('m') {
+            60
+        } else if unit.starts_with('s') {
+            1
+        } else {
+            return None;
+        };
+        secs = Some(
+            secs.unwrap_or(0u64)
+                .checked_add(n.checked_mul(multiplier)?)?,
+        );
+    }
+    secs.map(Duration::from_secs)
+}
+
+/// Parse a count such as "1.2M plays", "144K subscribers" or "2,345 views",
+/// using English suffixes. See [`parse_count_with_suffixes`] for other
+/// languages.
+/// ```
+/// use ytmapi_rs::parse::parse_count;
+/// assert_eq!(parse_count("1.2M plays"), Some(1_200_000));
+/// assert_eq!(parse_count("2,345 views"), Some(2_345));
+/// ```
+pub fn parse_count(s: &str) -> Option<u64> {
+    parse_count_with_suffixes(s, ENGLISH_COUNT_SUFFIXES)
+}
+
+/// Parse a count such as "1,2 Mio. Aufrufe", using a table of suffixes and
+/// their multipliers.
+/// When a suffix is present, the last `.` or `,` is treated as a decimal
+/// separator. Otherwise, `.` and `,` are treated as grouping separators.
+/// ```
+/// use ytmapi_rs::parse::parse_count_with_suffixes;
+/// let suffixes = [("Mio.", 1_000_000), ("Mrd.", 1_000_000_000)];
+/// assert_eq!(
+///     parse_count_with_suffixes("1,2 Mio. Aufrufe", &suffixes),
+///     Some(1_200_000)
+/// );
+/// ```
+pub fn parse_count_with_suffixes(s: &str, suffixes: &[(&str, u64)]) -> Option<u64> {
+    let start = s.find(|c: char| c.is_ascii_digit())?;
+    let s = &s[start..];
+    let number_end = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+        .unwrap_or(s.len());
+    let (number, rest) = s.split_at(number_end);
+    let rest = rest.trim_start();
+    let multiplier = suffixes.iter().find_map(|(suffix, multiplier)| {
+        rest.strip_prefix(suffix)
+            .filter(|after| !after.starts_with(char::is_alphabetic))
+            .map(|_| *multiplier)
+    });
+    let Some(multiplier) = multiplier else {
+        return number
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>()
+            .parse()
+            .ok();
+    };
+    let (whole, fraction) = match number.rfind(['.', ',']) {
+        Some(idx) => (&number[..idx], &number[idx + 1..]),
+        None => (number, ""),
+    };
+    let whole = whole
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>()
+        .parse::<u64>()
+        .ok()?;
+    let mut count = whole.checked_mul(multiplier)?;
+    let mut place = multiplier;
+    for digit in fraction.chars() {
+        place /= 10;
+        count = count.checked_add(u64::from(digit.to_digit(10)?) * place)?;
+    }
+    Some(count)
+}
+
+/// Parse the first 4 digit year in a string, such as "2019".
+/// ```
+/// use ytmapi_rs::parse::parse_year;
+/// assert_eq!(parse_year("2019"), Some(2019));
+/// ```
+pub fn parse_year(s: &str) -> Option<u16> {
+    s.split(|c: char| !c.is_ascii_digit())
+        .find(|digits| digits.len() == 4)
+        .and_then(|digits| digits.parse().ok())
+}
+
+/// A raw field that typed accessors can be generated for.
+pub(crate) trait RawField {
+    fn parse_raw<T>(&self, f: fn(&str) -> Option<T>) -> Option<T>;
+}
+impl RawField for String {
+    fn parse_raw<T>(&self, f: fn(&str) -> Option<T>) -> Option<T> {
+        f(self)
+    }
+}
+impl RawField for Option<String> {
+    fn parse_raw<T>(&self, f: fn(&str) -> Option<T>) -> Option<T> {
+        self.as_deref().and_then(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_count, parse_duration, parse_year};
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("0:38"), Some(Duration::from_secs(38)));
+        assert_eq!(parse_duration("1:00:06"), Some(Duration::from_secs(3606)));
+        assert_eq!(
+            parse_duration("1 hour, 17 minutes"),
+            Some(Duration::from_secs(4620))
+        );
+        assert_eq!(
+            parse_duration("1 hr 10 mins"),
+            Some(Duration::from_secs(4200))
+        );
+        assert_eq!(parse_duration("45 sec"), Some(Duration::from_secs(45)));
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("Live"), None);
+        assert_eq!(parse_duration("18446744073709551615 hours"), None);
+        assert_eq!(parse_duration("18446744073709551615:00"), None);
+        assert_eq!(parse_duration("18446744073709551615 secs 1 sec"), None);
+    }
+    #[test]
+    fn test_parse_count() {
+        assert_eq!(parse_count("3.3B plays"), Some(3_300_000_000));
+        assert_eq!(parse_count("2.17M subscribers"), Some(2_170_000));
+        assert_eq!(parse_count("51M\u{a0}plays"), Some(51_000_000));
+        assert_eq!(parse_count("144K subscribers"), Some(144_000));
+        assert_eq!(parse_count("1 view"), Some(1));
+        assert_eq!(parse_count("24 tracks"), Some(24));
+        assert_eq!(parse_count("1,234,567 views"), Some(1_234_567));
+        assert_eq!(parse_count("No views"), None);
+    }
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(parse_year("2023"), Some(2023));
+        assert_eq!(parse_year("Album • 1969"), Some(1969));
+        assert_eq!(parse_year("12"), None);
+    }
+}
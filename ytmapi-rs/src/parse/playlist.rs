@@ -6,21 +6,23 @@ use super::{
 };
 use crate::common::{
     ApiOutcome, ArtistChannelID, ContinuationParams, EpisodeID, Explicit, LibraryManager,
-    LikeStatus, PlaylistID, SetVideoID, Thumbnail, UploadEntityID, VideoID,
+    LikeStatus, PlaylistID, SetVideoID, Thumbnail, UploadEntityID, VideoID, YoutubeID,
 };
 use crate::continuations::ParseFromContinuable;
+use crate::error::ErrorKind;
 use crate::nav_consts::{
     APPEND_CONTINUATION_ITEMS, BADGE_LABEL, CONTENT, CONTINUATION_RENDERER_COMMAND,
     DELETION_ENTITY_ID, DISPLAY_POLICY, FACEPILE_AVATAR_URL, FACEPILE_TEXT, LIVE_BADGE_LABEL,
     MENU_ITEMS, MENU_LIKE_STATUS, MRLIR, MUSIC_PLAYLIST_SHELF, NAVIGATION_BROWSE_ID,
     NAVIGATION_PLAYLIST_ID, NAVIGATION_VIDEO_ID, NAVIGATION_VIDEO_TYPE, PLAY_BUTTON,
-    PLAYLIST_PANEL_CONTINUATION, PPR, RADIO_CONTINUATION_PARAMS, RESPONSIVE_HEADER, RUN_TEXT,
-    SECOND_SUBTITLE_RUNS, SECONDARY_SECTION_LIST_RENDERER, SECTION_LIST_ITEM, TAB_CONTENT,
-    TEXT_RUN, TEXT_RUN_TEXT, THUMBNAIL, THUMBNAILS, WATCH_NEXT_CONTENT, WATCH_VIDEO_ID,
+    PLAYLIST_ITEM_SET_VIDEO_ID, PLAYLIST_PANEL_CONTINUATION, PPR, RADIO_CONTINUATION_PARAMS,
+    RESPONSIVE_HEADER, RUN_TEXT, SECOND_SUBTITLE_RUNS, SECONDARY_SECTION_LIST_RENDERER,
+    SECTION_LIST_ITEM, TAB_CONTENT, TEXT_RUN, TEXT_RUN_TEXT, THUMBNAIL, THUMBNAILS,
+    WATCH_NEXT_CONTENT, WATCH_VIDEO_ID,
 };
 use crate::query::playlist::{
-    CreatePlaylistType, GetPlaylistDetailsQuery, GetWatchPlaylistQueryID, PrivacyStatus,
-    SpecialisedQuery,
+    AddPlaylistToPlaylist, AddVideosToPlaylist, CreatePlaylistType, DuplicateHandlingMode,
+    GetPlaylistDetailsQuery, GetWatchPlaylistQueryID, PrivacyStatus,
 };
 use crate::query::{
     AddPlaylistItemsQuery, CreatePlaylistQuery, DeletePlaylistQuery, EditPlaylistQuery,
@@ -33,6 +35,7 @@ use json_crawler::{JsonCrawler, JsonCrawlerIterator, JsonCrawlerOwned};
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetPlaylistDetails {
     pub id: PlaylistID<'static>,
@@ -50,7 +53,13 @@ pub struct GetPlaylistDetails {
     pub views: Option<String>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(GetPlaylistDetails {
+    parsed_year: year as year,
+    parsed_duration: duration as duration,
+    parsed_views: views as count,
+});
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 /// Provides a SetVideoID and VideoID for each video added to the playlist.
 // Intentionally not marked non_exhaustive - not expecting this to change.
 pub struct AddPlaylistItem {
@@ -58,6 +67,36 @@ pub struct AddPlaylistItem {
     pub set_video_id: SetVideoID<'static>,
 }
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// Outcome of adding a single video to a playlist, reported in the order the
+/// videos were requested.
+pub enum AddPlaylistVideoResult {
+    /// The video was added, and can now be referenced by its SetVideoID.
+    Added(AddPlaylistItem),
+    /// The video was requested, but YouTube Music didn't report it as added.
+    /// This is never due to the video already being in the playlist - using
+    /// `DuplicateHandlingMode::ReturnError` a duplicate causes
+    /// `ErrorKind::DuplicatePlaylistItems` instead, and using
+    /// `DuplicateHandlingMode::Unhandled` duplicates are added again.
+    NotAdded(VideoID<'static>),
+}
+impl AddPlaylistVideoResult {
+    pub fn video_id(&self) -> &VideoID<'static> {
+        match self {
+            AddPlaylistVideoResult::Added(item) => &item.video_id,
+            AddPlaylistVideoResult::NotAdded(video_id) => video_id,
+        }
+    }
+    /// Returns the SetVideoID if the video was added.
+    pub fn set_video_id(&self) -> Option<&SetVideoID<'static>> {
+        match self {
+            AddPlaylistVideoResult::Added(item) => Some(&item.set_video_id),
+            AddPlaylistVideoResult::NotAdded(_) => None,
+        }
+    }
+}
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct WatchPlaylistTrack {
     pub title: String,
@@ -66,13 +105,20 @@ pub struct WatchPlaylistTrack {
     pub thumbnails: Vec<Thumbnail>,
     pub video_id: VideoID<'static>,
 }
+impl_parsed_fields!(WatchPlaylistTrack {
+    parsed_duration: duration as duration,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 // Could this alternatively be Result<Song>?
 // May need to be enum to track 'Not Available' case.
 pub struct PlaylistSong {
     pub video_id: VideoID<'static>,
+    /// Identifies this entry within the playlist, required to move or remove
+    /// it. May not be present for all playlist items.
+    pub set_video_id: Option<SetVideoID<'static>>,
     pub track_no: usize,
     pub album: ParsedSongAlbum,
     pub duration: String,
@@ -89,8 +135,12 @@ pub struct PlaylistSong {
     /// Id of the playlist that will get created when pressing 'Start Radio'.
     pub playlist_id: PlaylistID<'static>,
 }
+impl_parsed_fields!(PlaylistSong {
+    parsed_duration: duration as duration,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum PlaylistItem {
     Song(PlaylistSong),
     Video(PlaylistVideo),
@@ -99,9 +149,13 @@ pub enum PlaylistItem {
 }
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct PlaylistVideo {
     pub video_id: VideoID<'static>,
+    /// Identifies this entry within the playlist, required to move or remove
+    /// it. May not be present for all playlist items.
+    pub set_video_id: Option<SetVideoID<'static>>,
     pub track_no: usize,
     pub duration: String,
     pub title: String,
@@ -115,11 +169,18 @@ pub struct PlaylistVideo {
     /// Id of the playlist that will get created when pressing 'Start Radio'.
     pub playlist_id: PlaylistID<'static>,
 }
+impl_parsed_fields!(PlaylistVideo {
+    parsed_duration: duration as duration,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct PlaylistEpisode {
     pub episode_id: EpisodeID<'static>,
+    /// Identifies this entry within the playlist, required to move or remove
+    /// it. May not be present for all playlist items.
+    pub set_video_id: Option<SetVideoID<'static>>,
     pub track_no: usize,
     pub date: EpisodeDate,
     pub duration: EpisodeDuration,
@@ -133,10 +194,14 @@ pub struct PlaylistEpisode {
 }
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct PlaylistUploadSong {
     pub entity_id: UploadEntityID<'static>,
     pub video_id: VideoID<'static>,
+    /// Identifies this entry within the playlist, required to move or remove
+    /// it. May not be present for all playlist items.
+    pub set_video_id: Option<SetVideoID<'static>>,
     pub track_no: usize,
     pub duration: String,
     // An UploadSong may not have an album
@@ -148,6 +213,31 @@ pub struct PlaylistUploadSong {
     pub like_status: LikeStatus,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(PlaylistUploadSong {
+    parsed_duration: duration as duration,
+});
+
+impl PlaylistItem {
+    /// Get the VideoID of the item. For an episode, this is the VideoID
+    /// equivalent of its EpisodeID.
+    pub fn video_id(&self) -> VideoID<'_> {
+        match self {
+            PlaylistItem::Song(s) => (&s.video_id).into(),
+            PlaylistItem::Video(v) => (&v.video_id).into(),
+            PlaylistItem::Episode(e) => VideoID::from_raw(e.episode_id.get_raw()),
+            PlaylistItem::UploadSong(u) => (&u.video_id).into(),
+        }
+    }
+    /// Get the SetVideoID of the item, if it has one.
+    pub fn set_video_id(&self) -> Option<&SetVideoID<'static>> {
+        match self {
+            PlaylistItem::Song(s) => s.set_video_id.as_ref(),
+            PlaylistItem::Video(v) => v.set_video_id.as_ref(),
+            PlaylistItem::Episode(e) => e.set_video_id.as_ref(),
+            PlaylistItem::UploadSong(u) => u.set_video_id.as_ref(),
+        }
+    }
+}
 
 impl<'a> ParseFrom<RemovePlaylistItemsQuery<'a>> for () {
     fn parse_from(_: ProcessedResult<RemovePlaylistItemsQuery<'a>>) -> crate::Result<Self> {
@@ -162,26 +252,75 @@ impl<'a, C: CreatePlaylistType> ParseFrom<CreatePlaylistQuery<'a, C>> for Playli
             .map_err(Into::into)
     }
 }
-impl<'a, T: SpecialisedQuery> ParseFrom<AddPlaylistItemsQuery<'a, T>> for Vec<AddPlaylistItem> {
-    fn parse_from(p: ProcessedResult<AddPlaylistItemsQuery<'a, T>>) -> crate::Result<Self> {
-        let mut json_crawler: JsonCrawlerOwned = p.into();
-        let status: ApiOutcome = json_crawler.borrow_pointer("/status")?.take_value()?;
-        if let ApiOutcome::Failure = status {
-            return Err(Error::status_failed());
-        }
-        json_crawler
-            .navigate_pointer("/playlistEditResults")?
-            .try_iter_mut()?
-            .map(|r| {
-                let mut r = r.navigate_pointer("/playlistEditVideoAddedResultData")?;
-                Ok(AddPlaylistItem {
-                    video_id: r.take_value_pointer("/videoId")?,
-                    set_video_id: r.take_value_pointer("/setVideoId")?,
-                })
+impl<'a> ParseFrom<AddPlaylistItemsQuery<'a, AddPlaylistToPlaylist<'a>>> for Vec<AddPlaylistItem> {
+    fn parse_from(
+        p: ProcessedResult<AddPlaylistItemsQuery<'a, AddPlaylistToPlaylist<'a>>>,
+    ) -> crate::Result<Self> {
+        parse_add_playlist_items(p.into())
+    }
+}
+impl<'a> ParseFrom<AddPlaylistItemsQuery<'a, AddVideosToPlaylist<'a>>>
+    for Vec<AddPlaylistVideoResult>
+{
+    fn parse_from(
+        p: ProcessedResult<AddPlaylistItemsQuery<'a, AddVideosToPlaylist<'a>>>,
+    ) -> crate::Result<Self> {
+        let query = p.query;
+        let mut added = match parse_add_playlist_items(p.into()) {
+            // Using ReturnError, YouTube Music fails the whole request if any video is
+            // a duplicate - without identifying which.
+            Err(e)
+                if matches!(e.kind(), ErrorKind::ApiStatusFailed)
+                    && query.duplicate_handling_mode() == &DuplicateHandlingMode::ReturnError =>
+            {
+                return Err(Error::duplicate_playlist_items(
+                    query
+                        .video_ids()
+                        .iter()
+                        .map(|video_id| VideoID::from_raw(video_id.get_raw().to_owned()))
+                        .collect(),
+                ));
+            }
+            other => other?,
+        };
+        // Pair each requested video with the first unclaimed result for it.
+        let mut results = query
+            .video_ids()
+            .iter()
+            .map(|video_id| {
+                match added
+                    .iter()
+                    .position(|item| item.video_id.get_raw() == video_id.get_raw())
+                {
+                    Some(idx) => AddPlaylistVideoResult::Added(added.remove(idx)),
+                    None => AddPlaylistVideoResult::NotAdded(VideoID::from_raw(
+                        video_id.get_raw().to_owned(),
+                    )),
+                }
             })
-            .collect()
+            .collect::<Vec<_>>();
+        // Not expected, but don't discard anything YouTube reports as added.
+        results.extend(added.into_iter().map(AddPlaylistVideoResult::Added));
+        Ok(results)
     }
 }
+fn parse_add_playlist_items(mut json_crawler: JsonCrawlerOwned) -> Result<Vec<AddPlaylistItem>> {
+    let status: ApiOutcome = json_crawler.borrow_pointer("/status")?.take_value()?;
+    if let ApiOutcome::Failure = status {
+        return Err(Error::status_failed());
+    }
+    json_crawler
+        .navigate_pointer("/playlistEditResults")?
+        .try_iter_mut()?
+        .map(|r| {
+            let mut r = r.navigate_pointer("/playlistEditVideoAddedResultData")?;
+            Ok(AddPlaylistItem {
+                video_id: r.take_value_pointer("/videoId")?,
+                set_video_id: r.take_value_pointer("/setVideoId")?,
+            })
+        })
+        .collect()
+}
 impl<'a> ParseFrom<EditPlaylistQuery<'a>> for ApiOutcome {
     fn parse_from(p: ProcessedResult<EditPlaylistQuery<'a>>) -> crate::Result<Self> {
         let json_crawler: JsonCrawlerOwned = p.into();
@@ -334,8 +473,10 @@ pub(crate) fn parse_playlist_song(
         "/0/menuNavigationItemRenderer",
         NAVIGATION_PLAYLIST_ID
     ))?;
+    let set_video_id = data.take_value_pointer(PLAYLIST_ITEM_SET_VIDEO_ID).ok();
     Ok(PlaylistSong {
         video_id,
+        set_video_id,
         track_no,
         duration,
         library_management,
@@ -367,6 +508,7 @@ pub(crate) fn parse_playlist_upload_song(
     let artists = parse_upload_song_artists(data.borrow_mut(), 1).unwrap_or_default();
     // An uploaded song may not have artists metadata
     let album = parse_upload_song_album(data.borrow_mut(), 2).ok();
+    let set_video_id = data.take_value_pointer(PLAYLIST_ITEM_SET_VIDEO_ID).ok();
     let mut menu = data.navigate_pointer(MENU_ITEMS)?;
     let entity_id = menu
         .try_iter_mut()?
@@ -375,6 +517,7 @@ pub(crate) fn parse_playlist_upload_song(
     Ok(PlaylistUploadSong {
         entity_id,
         video_id,
+        set_video_id,
         album,
         duration,
         like_status,
@@ -422,8 +565,10 @@ pub(crate) fn parse_playlist_episode(
         .take_value_pointer::<String>("/musicItemRendererDisplayPolicy")
         .map(|m| m != "MUSIC_ITEM_RENDERER_DISPLAY_POLICY_GREY_OUT")
         .unwrap_or(true);
+    let set_video_id = data.take_value_pointer(PLAYLIST_ITEM_SET_VIDEO_ID).ok();
     Ok(PlaylistEpisode {
         episode_id: video_id,
+        set_video_id,
         duration,
         title,
         like_status,
@@ -464,8 +609,10 @@ pub(crate) fn parse_playlist_video(
         "/0/menuNavigationItemRenderer",
         NAVIGATION_PLAYLIST_ID
     ))?;
+    let set_video_id = data.take_value_pointer(PLAYLIST_ITEM_SET_VIDEO_ID).ok();
     Ok(PlaylistVideo {
         video_id,
+        set_video_id,
         track_no,
         duration,
         title,
@@ -631,8 +778,9 @@ fn get_playlist_details(json_crawler: JsonCrawlerOwned) -> Result<GetPlaylistDet
 #[cfg(test)]
 mod tests {
     use crate::auth::BrowserToken;
-    use crate::common::{ApiOutcome, PlaylistID, VideoID, YoutubeID};
-    use crate::query::playlist::GetPlaylistDetailsQuery;
+    use crate::common::{ApiOutcome, PlaylistID, SetVideoID, VideoID, YoutubeID};
+    use crate::parse::{AddPlaylistItem, AddPlaylistVideoResult};
+    use crate::query::playlist::{DuplicateHandlingMode, GetPlaylistDetailsQuery};
     use crate::query::{
         AddPlaylistItemsQuery, EditPlaylistQuery, GetPlaylistTracksQuery, GetWatchPlaylistQuery,
     };
@@ -656,6 +804,43 @@ mod tests {
         assert_eq!(format!("{:?}", err), format!("{:?}", output));
     }
     #[tokio::test]
+    async fn test_add_playlist_items_query_duplicates() {
+        let source_path = Path::new("./test_json/add_playlist_items_failure_20240626.json");
+        let source = tokio::fs::read_to_string(source_path)
+            .await
+            .expect("Expect file read to pass during tests");
+        let query = AddPlaylistItemsQuery::new_from_videos(
+            PlaylistID::from_raw(""),
+            [
+                VideoID::from_raw("sx7VtSDIqmQ"),
+                VideoID::from_raw("AWCVHllzG9Y"),
+            ],
+            DuplicateHandlingMode::ReturnError,
+        );
+        let output = process_json::<_, BrowserToken>(source, query);
+        let err: crate::Result<()> = Err(Error::duplicate_playlist_items(vec![
+            VideoID::from_raw("sx7VtSDIqmQ"),
+            VideoID::from_raw("AWCVHllzG9Y"),
+        ]));
+        assert_eq!(format!("{:?}", err), format!("{:?}", output));
+    }
+    #[tokio::test]
+    async fn test_add_playlist_items_query_unhandled_duplicates_failure() {
+        // Without duplicate checking, a failure can't be attributed to duplicates.
+        let source_path = Path::new("./test_json/add_playlist_items_failure_20240626.json");
+        let source = tokio::fs::read_to_string(source_path)
+            .await
+            .expect("Expect file read to pass during tests");
+        let query = AddPlaylistItemsQuery::new_from_videos(
+            PlaylistID::from_raw(""),
+            [VideoID::from_raw("sx7VtSDIqmQ")],
+            DuplicateHandlingMode::Unhandled,
+        );
+        let output = process_json::<_, BrowserToken>(source, query);
+        let err: crate::Result<()> = Err(Error::status_failed());
+        assert_eq!(format!("{:?}", err), format!("{:?}", output));
+    }
+    #[tokio::test]
     async fn test_add_playlist_items_query() {
         parse_test!(
             "./test_json/add_playlist_items_20240626.json",
@@ -668,6 +853,30 @@ mod tests {
         );
     }
     #[tokio::test]
+    async fn test_add_playlist_items_query_videos() {
+        // Videos are reported in the order requested, with any video YouTube didn't
+        // report as added marked as not added.
+        parse_test_value!(
+            "./test_json/add_playlist_items_20240626.json",
+            vec![
+                AddPlaylistVideoResult::NotAdded(VideoID::from_raw("sx7VtSDIqmQ")),
+                AddPlaylistVideoResult::Added(AddPlaylistItem {
+                    video_id: VideoID::from_raw("AWCVHllzG9Y"),
+                    set_video_id: SetVideoID::from_raw("F3D73C336952E57D"),
+                }),
+            ],
+            AddPlaylistItemsQuery::new_from_videos(
+                PlaylistID::from_raw(""),
+                [
+                    VideoID::from_raw("sx7VtSDIqmQ"),
+                    VideoID::from_raw("AWCVHllzG9Y"),
+                ],
+                DuplicateHandlingMode::ReturnError,
+            ),
+            BrowserToken
+        );
+    }
+    #[tokio::test]
     async fn test_edit_playlist_title_query() {
         parse_test_value!(
             "./test_json/edit_playlist_title_20240626.json",
@@ -1,27 +1,30 @@
 use super::{
     ParseFrom, RUN_TEXT, SECONDARY_SECTION_LIST_ITEM, STRAPLINE_RUNS, TAB_CONTENT,
-    THUMBNAIL_RENDERER, THUMBNAILS, TITLE_TEXT, VISUAL_HEADER,
+    THUMBNAIL_RENDERER, THUMBNAILS, TITLE_TEXT, VISUAL_HEADER, parse_feedback_outcome,
 };
 use crate::Result;
 use crate::common::{
-    EpisodeID, LibraryStatus, PlaylistID, PodcastChannelID, PodcastChannelParams, PodcastID,
-    Thumbnail,
+    ApiOutcome, EpisodeID, FeedbackTokenMarkPlayed, FeedbackTokenMarkUnplayed, LibraryStatus,
+    PlaylistID, PodcastChannelID, PodcastChannelParams, PodcastID, Thumbnail,
 };
 use crate::nav_consts::{
-    CAROUSEL, CAROUSEL_TITLE, DESCRIPTION, DESCRIPTION_SHELF, GRID_ITEMS, MMRLIR, MTRIR,
-    MUSIC_SHELF, NAVIGATION_BROWSE, NAVIGATION_BROWSE_ID, PLAYBACK_DURATION_TEXT,
+    CAROUSEL, CAROUSEL_TITLE, DEFAULT_ENDPOINT, DESCRIPTION, DESCRIPTION_SHELF, GRID_ITEMS, MMRLIR,
+    MTRIR, MUSIC_SHELF, NAVIGATION_BROWSE, NAVIGATION_BROWSE_ID, PLAYBACK_DURATION_TEXT,
     PLAYBACK_PROGRESS_TEXT, RESPONSIVE_HEADER, SECTION_LIST, SECTION_LIST_ITEM, SINGLE_COLUMN_TAB,
-    SUBTITLE, SUBTITLE_RUNS, SUBTITLE3, TITLE, TWO_COLUMN,
+    SUBTITLE, SUBTITLE_RUNS, SUBTITLE3, TITLE, TOGGLE_MENU, TOGGLED_ENDPOINT, TWO_COLUMN,
 };
 use crate::query::{
-    GetChannelEpisodesQuery, GetChannelQuery, GetEpisodeQuery, GetNewEpisodesQuery, GetPodcastQuery,
+    GetChannelEpisodesQuery, GetChannelQuery, GetEpisodeQuery, GetNewEpisodesQuery,
+    GetPodcastQuery, MarkEpisodePlayedQuery, MarkEpisodeUnplayedQuery, SubscribePodcastQuery,
+    UnsubscribePodcastQuery,
 };
 use const_format::concatcp;
 use itertools::Itertools;
-use json_crawler::{JsonCrawler, JsonCrawlerOwned};
+use json_crawler::{JsonCrawler, JsonCrawlerIterator, JsonCrawlerOwned};
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetPodcastChannel {
     pub title: String,
@@ -32,6 +35,7 @@ pub struct GetPodcastChannel {
     pub playlists: Vec<GetPodcastChannelPlaylist>,
 }
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Episode {
     pub title: String,
@@ -42,7 +46,11 @@ pub struct Episode {
     pub episode_id: EpisodeID<'static>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(Episode {
+    parsed_remaining_duration: remaining_duration as duration,
+});
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetPodcastChannelPodcast {
     pub title: String,
@@ -51,6 +59,7 @@ pub struct GetPodcastChannelPodcast {
     pub thumbnails: Vec<Thumbnail>,
 }
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetPodcastChannelPlaylist {
     pub title: String,
@@ -59,19 +68,42 @@ pub struct GetPodcastChannelPlaylist {
     pub views: String,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(GetPodcastChannelPlaylist {
+    parsed_views: views as count,
+});
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 // Intentionally not marked non_exhaustive - not expected to change.
 pub struct ParsedPodcastChannel {
     pub name: String,
     pub id: Option<PodcastChannelID<'static>>,
 }
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 // Intentionally not marked non_exhaustive - not expected to change.
 pub enum IsSaved {
     Saved,
     NotSaved,
 }
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+// Intentionally not marked non_exhaustive - not expected to change.
+pub enum IsPlayed {
+    Played,
+    NotPlayed,
+}
+/// Collection of required fields to identify and change whether an episode has
+/// been played.
+// Intentionally not marked non_exhaustive - not expecting this to change.
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EpisodePlayedManager {
+    pub status: IsPlayed,
+    pub mark_played_token: FeedbackTokenMarkPlayed<'static>,
+    pub mark_unplayed_token: FeedbackTokenMarkUnplayed<'static>,
+}
 #[derive(Eq, PartialEq, Debug, Clone, Deserialize, Serialize, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 // Intentionally not marked non_exhaustive - not expected to change.
 pub enum PodcastChannelTopResult {
     #[serde(rename = "Latest episodes")]
@@ -80,6 +112,7 @@ pub enum PodcastChannelTopResult {
     Playlists,
 }
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetPodcast {
     pub channels: Vec<ParsedPodcastChannel>,
@@ -90,6 +123,7 @@ pub struct GetPodcast {
     pub episodes: Vec<Episode>,
 }
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetEpisode {
     pub podcast_name: String,
@@ -99,8 +133,32 @@ pub struct GetEpisode {
     pub total_duration: String,
     pub remaining_duration: String,
     pub saved: IsSaved,
+    /// Not returned when logged out.
+    pub played_manager: Option<EpisodePlayedManager>,
+    /// Resume position, as a percentage of the total duration.
+    /// Not returned when logged out.
+    pub progress_percentage: Option<u8>,
     pub description: String,
 }
+impl_parsed_fields!(GetEpisode {
+    parsed_total_duration: total_duration as duration,
+    parsed_remaining_duration: remaining_duration as duration,
+});
+impl GetEpisode {
+    /// Approximate resume position from the start of the episode, if the
+    /// durations can be parsed.
+    ///
+    /// # Precision
+    /// YouTube Music displays these durations rounded, e.g "1 hr" or "23 min",
+    /// so the result is only accurate to the coarsest unit shown - typically
+    /// a minute, but up to an hour for long episodes.
+    /// [`GetEpisode::progress_percentage`] may be finer grained for long
+    /// episodes. Returns `None` if either duration can't be parsed.
+    pub fn resume_position(&self) -> Option<std::time::Duration> {
+        self.parsed_total_duration()?
+            .checked_sub(self.parsed_remaining_duration()?)
+    }
+}
 
 // NOTE: This is technically the same page as the GetArtist page. It's possible
 // this could be generalised.
@@ -268,6 +326,36 @@ impl ParseFrom<GetEpisodeQuery<'_>> for GetEpisode {
         let remaining_duration = responsive_header.take_value_pointer(
             "/progress/musicPlaybackProgressRenderer/durationText/runs/1/text",
         )?;
+        let progress_percentage = responsive_header
+            .take_value_pointer(
+                "/progress/musicPlaybackProgressRenderer/playbackProgressPercentage",
+            )
+            .ok();
+        let played_manager = responsive_header
+            .borrow_pointer("/buttons")?
+            .try_into_iter()?
+            .find_path("/menuRenderer/items")
+            .ok()
+            .map(|items| -> Result<Option<EpisodePlayedManager>> {
+                items
+                    .try_into_iter()?
+                    .find(|item| item.path_exists(concatcp!(TOGGLE_MENU, DEFAULT_ENDPOINT)))
+                    .map(|item| {
+                        let mut toggle = item.navigate_pointer(TOGGLE_MENU)?;
+                        let status = match toggle.take_value_pointer::<bool>("/isToggled")? {
+                            true => IsPlayed::Played,
+                            false => IsPlayed::NotPlayed,
+                        };
+                        Ok(EpisodePlayedManager {
+                            status,
+                            mark_played_token: toggle.take_value_pointer(DEFAULT_ENDPOINT)?,
+                            mark_unplayed_token: toggle.take_value_pointer(TOGGLED_ENDPOINT)?,
+                        })
+                    })
+                    .transpose()
+            })
+            .transpose()?
+            .flatten();
         let saved = match responsive_header
             .take_value_pointer::<bool>("/buttons/0/toggleButtonRenderer/isToggled")?
         {
@@ -292,6 +380,8 @@ impl ParseFrom<GetEpisodeQuery<'_>> for GetEpisode {
             total_duration,
             remaining_duration,
             saved,
+            played_manager,
+            progress_percentage,
             description,
             podcast_name,
             podcast_id,
@@ -314,6 +404,37 @@ impl ParseFrom<GetNewEpisodesQuery> for Vec<Episode> {
             .collect()
     }
 }
+impl ParseFrom<SubscribePodcastQuery<'_>> for () {
+    fn parse_from(p: crate::ProcessedResult<SubscribePodcastQuery>) -> Result<Self> {
+        parse_podcast_rating(p.into())
+    }
+}
+impl ParseFrom<UnsubscribePodcastQuery<'_>> for () {
+    fn parse_from(p: crate::ProcessedResult<UnsubscribePodcastQuery>) -> Result<Self> {
+        parse_podcast_rating(p.into())
+    }
+}
+impl ParseFrom<MarkEpisodePlayedQuery<'_>> for ApiOutcome {
+    fn parse_from(p: crate::ProcessedResult<MarkEpisodePlayedQuery>) -> Result<Self> {
+        parse_feedback_outcome(p.into())
+    }
+}
+impl ParseFrom<MarkEpisodeUnplayedQuery<'_>> for ApiOutcome {
+    fn parse_from(p: crate::ProcessedResult<MarkEpisodeUnplayedQuery>) -> Result<Self> {
+        parse_feedback_outcome(p.into())
+    }
+}
+
+// Same response as RatePlaylistQuery - an invalid PodcastID is caught by
+// AuthToken as a 404 error.
+fn parse_podcast_rating(json_crawler: JsonCrawlerOwned) -> Result<()> {
+    json_crawler
+        .navigate_pointer("/actions")?
+        .try_into_iter()?
+        .find_path("/addToToastAction")
+        .map(|_| ())
+        .map_err(Into::into)
+}
 
 pub(crate) fn parse_podcast_channel(mut data: impl JsonCrawler) -> Result<ParsedPodcastChannel> {
     Ok(ParsedPodcastChannel {
@@ -346,11 +467,16 @@ fn parse_episode(crawler: impl JsonCrawler) -> Result<Episode> {
 #[cfg(test)]
 mod tests {
     use crate::auth::BrowserToken;
-    use crate::common::{EpisodeID, PodcastChannelID, PodcastChannelParams, PodcastID, YoutubeID};
+    use crate::common::{
+        ApiOutcome, EpisodeID, FeedbackTokenMarkPlayed, FeedbackTokenMarkUnplayed,
+        PodcastChannelID, PodcastChannelParams, PodcastID, YoutubeID,
+    };
     use crate::query::{
         GetChannelEpisodesQuery, GetChannelQuery, GetEpisodeQuery, GetNewEpisodesQuery,
-        GetPodcastQuery,
+        GetPodcastQuery, MarkEpisodePlayedQuery, MarkEpisodeUnplayedQuery, SubscribePodcastQuery,
+        UnsubscribePodcastQuery,
     };
+    use std::time::Duration;
 
     #[tokio::test]
     async fn test_get_channel() {
@@ -392,6 +518,31 @@ mod tests {
         );
     }
     #[tokio::test]
+    async fn test_get_episode_resume_position() {
+        let source = tokio::fs::read_to_string("./test_json/get_episode_20240830.json")
+            .await
+            .expect("Expect file read to pass during tests");
+        let episode = crate::process_json::<_, BrowserToken>(
+            source,
+            GetEpisodeQuery::new(EpisodeID::from_raw("")),
+        )
+        .unwrap();
+        assert_eq!(episode.resume_position(), Some(Duration::ZERO));
+    }
+    #[tokio::test]
+    async fn test_get_episode_logged_out() {
+        let source = tokio::fs::read_to_string("./test_json/get_episode_logged_out_mock.json")
+            .await
+            .expect("Expect file read to pass during tests");
+        let episode = crate::process_json::<_, BrowserToken>(
+            source,
+            GetEpisodeQuery::new(EpisodeID::from_raw("")),
+        )
+        .unwrap();
+        assert_eq!(episode.progress_percentage, None);
+        assert_eq!(episode.played_manager, None);
+    }
+    #[tokio::test]
     async fn test_get_new_episodes() {
         parse_test!(
             "./test_json/get_new_episodes_20240830.json",
@@ -400,4 +551,64 @@ mod tests {
             BrowserToken
         );
     }
+    #[tokio::test]
+    async fn test_subscribe_podcast() {
+        parse_test_value!(
+            "./test_json/rate_playlist_like_20240710.json",
+            (),
+            SubscribePodcastQuery::new(PodcastID::from_raw("")),
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_unsubscribe_podcast() {
+        parse_test_value!(
+            "./test_json/rate_playlist_indifferent_20240710.json",
+            (),
+            UnsubscribePodcastQuery::new(PodcastID::from_raw("")),
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_mark_episode_played() {
+        // Note - same file as remove_history_items, as both use the feedback endpoint.
+        // The first feedback response in that capture was not processed.
+        parse_test_value!(
+            "./test_json/remove_history_items_20240704.json",
+            ApiOutcome::Failure,
+            MarkEpisodePlayedQuery::new(FeedbackTokenMarkPlayed::from_raw("")),
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_mark_episode_unplayed() {
+        // Note - same file as remove_history_items, as both use the feedback endpoint.
+        // The first feedback response in that capture was not processed.
+        parse_test_value!(
+            "./test_json/remove_history_items_20240704.json",
+            ApiOutcome::Failure,
+            MarkEpisodeUnplayedQuery::new(FeedbackTokenMarkUnplayed::from_raw("")),
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_mark_episode_played_success() {
+        let source = crate::parse::processed_feedback_response().await;
+        let parsed = crate::process_json::<_, BrowserToken>(
+            source,
+            MarkEpisodePlayedQuery::new(FeedbackTokenMarkPlayed::from_raw("")),
+        )
+        .unwrap();
+        assert_eq!(parsed, ApiOutcome::Success);
+    }
+    #[tokio::test]
+    async fn test_mark_episode_unplayed_success() {
+        let source = crate::parse::processed_feedback_response().await;
+        let parsed = crate::process_json::<_, BrowserToken>(
+            source,
+            MarkEpisodeUnplayedQuery::new(FeedbackTokenMarkUnplayed::from_raw("")),
+        )
+        .unwrap();
+        assert_eq!(parsed, ApiOutcome::Success);
+    }
 }
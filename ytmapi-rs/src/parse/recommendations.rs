@@ -1,45 +1,61 @@
 use super::{
-    CATEGORY_TITLE, GRID, ParseFrom, RUN_TEXT, TASTE_ITEM_CONTENTS, TASTE_PROFILE_ARTIST,
-    TASTE_PROFILE_IMPRESSION, TASTE_PROFILE_ITEMS, TASTE_PROFILE_SELECTION,
+    CATEGORY_TITLE, CONTINUATION_PARAMS, GRID, ParseFrom, ParsedSongArtist, ProcessedResult,
+    RUN_TEXT, TASTE_ITEM_CONTENTS, TASTE_PROFILE_ARTIST, TASTE_PROFILE_IMPRESSION,
+    TASTE_PROFILE_ITEMS, TASTE_PROFILE_SELECTION, parse_feedback_outcome, parse_song_artist,
 };
 use crate::Result;
-use crate::common::{MoodCategoryParams, PlaylistID, TasteToken, Thumbnail};
+use crate::common::{
+    AlbumID, AlbumType, ApiOutcome, ArtistChannelID, ContinuationParams, Explicit,
+    ListenAgainPinManager, MoodCategoryParams, PinStatus, PlaylistID, TasteToken, Thumbnail,
+    VideoID, YoutubeID,
+};
+use crate::continuations::ParseFromContinuable;
 use crate::nav_consts::{
-    CAROUSEL, CAROUSEL_TITLE, CATEGORY_PARAMS, MTRIR, NAVIGATION_BROWSE_ID, SECTION_LIST,
-    SINGLE_COLUMN_TAB, SUBTITLE_RUNS, THUMBNAIL_RENDERER, TITLE_TEXT,
+    CAROUSEL, CAROUSEL_TITLE, CATEGORY_PARAMS, DEFAULT_ENDPOINT, GRID_CONTINUATION, MENU_ITEMS,
+    MTRIR, NAVIGATION_BROWSE, NAVIGATION_BROWSE_ID, NAVIGATION_VIDEO_ID, PAGE_TYPE, SECTION_LIST,
+    SECTION_LIST_ITEM, SINGLE_COLUMN_TAB, SUBTITLE, SUBTITLE_BADGE_LABEL, SUBTITLE_RUNS,
+    THUMBNAIL_RENDERER, TITLE, TITLE_TEXT, TOGGLE_MENU, TOGGLED_ENDPOINT,
 };
 use crate::query::{
-    GetMoodCategoriesQuery, GetMoodPlaylistsQuery, GetTasteProfileQuery, SetTasteProfileQuery,
+    GetContinuationsQuery, GetExploreQuery, GetListenAgainQuery, GetMoodCategoriesQuery,
+    GetMoodPlaylistsQuery, GetNewMusicVideosQuery, GetNewReleasesQuery, GetTasteProfileQuery,
+    PinToListenAgainQuery, SetTasteProfileQuery, UnpinFromListenAgainQuery,
 };
+use crate::youtube_enums::YoutubeMusicEntityPageType;
 use const_format::concatcp;
 use itertools::Itertools;
 use json_crawler::{CrawlerResult, JsonCrawler, JsonCrawlerBorrowed, JsonCrawlerOwned};
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TasteProfileArtist {
     pub artist: String,
     pub taste_tokens: TasteToken<'static>,
 }
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MoodCategorySection {
     pub section_name: String,
     pub mood_categories: Vec<MoodCategory>,
 }
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MoodCategory {
     pub title: String,
     pub params: MoodCategoryParams<'static>,
 }
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MoodPlaylistCategory {
     pub category_name: String,
     pub playlists: Vec<MoodPlaylist>,
 }
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MoodPlaylist {
     pub playlist_id: PlaylistID<'static>,
     pub title: String,
@@ -47,6 +63,71 @@ pub struct MoodPlaylist {
     pub author: String,
 }
 
+/// The Explore page.
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct GetExplore {
+    /// New albums & singles - see also `GetNewReleasesQuery`.
+    pub new_releases: Vec<NewReleaseAlbum>,
+    /// Entry points to the moods & genres categories - see also
+    /// `GetMoodPlaylistsQuery`.
+    pub moods_and_genres: Vec<MoodCategory>,
+    /// New music videos - see also `GetNewMusicVideosQuery`.
+    pub new_music_videos: Vec<NewMusicVideo>,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct NewReleaseAlbum {
+    pub title: String,
+    pub album_type: AlbumType,
+    pub artists: Vec<ParsedSongArtist>,
+    pub album_id: AlbumID<'static>,
+    pub thumbnails: Vec<Thumbnail>,
+    pub explicit: Explicit,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct NewMusicVideo {
+    pub title: String,
+    pub artists: Vec<ParsedSongArtist>,
+    pub views: String,
+    pub video_id: VideoID<'static>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+impl_parsed_fields!(NewMusicVideo {
+    parsed_views: views as count,
+});
+
+/// An item from the "Listen again" shelf on the Home page.
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct ListenAgainItem {
+    pub title: String,
+    /// e.g "Album • Artist" - format depends on the type of item.
+    pub subtitle: String,
+    pub id: ListenAgainItemID,
+    pub thumbnails: Vec<Thumbnail>,
+    /// None if the item is not able to be pinned.
+    pub pin_manager: Option<ListenAgainPinManager>,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub enum ListenAgainItemID {
+    /// Songs and videos.
+    Song(VideoID<'static>),
+    Album(AlbumID<'static>),
+    Playlist(PlaylistID<'static>),
+    Artist(ArtistChannelID<'static>),
+}
+
 impl<'a> ParseFrom<SetTasteProfileQuery<'a>> for () {
     fn parse_from(_: super::ProcessedResult<SetTasteProfileQuery<'a>>) -> Result<Self> {
         // Doesn't seem to be an identifier in the response to determine if success or
@@ -161,6 +242,293 @@ impl<'a> ParseFrom<GetMoodPlaylistsQuery<'a>> for Vec<MoodPlaylistCategory> {
     }
 }
 
+impl ParseFrom<GetExploreQuery> for GetExplore {
+    fn parse_from(p: ProcessedResult<GetExploreQuery>) -> Result<Self> {
+        let json_crawler: JsonCrawlerOwned = p.into();
+        let mut new_releases = Vec::new();
+        let mut moods_and_genres = Vec::new();
+        let mut new_music_videos = Vec::new();
+        for section in json_crawler
+            .navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, SECTION_LIST))?
+            .try_into_iter()?
+        {
+            let Ok(mut carousel) = section.navigate_pointer(CAROUSEL) else {
+                continue;
+            };
+            // Carousels are identified by the page their title links to, as the titles
+            // themselves are localised. Carousels with no link (e.g trending) are skipped.
+            let Ok(browse_id): CrawlerResult<String> =
+                carousel.take_value_pointer(concatcp!(CAROUSEL_TITLE, NAVIGATION_BROWSE_ID))
+            else {
+                continue;
+            };
+            let contents = carousel.navigate_pointer("/contents")?.try_into_iter()?;
+            match browse_id.as_str() {
+                "FEmusic_new_releases_albums" => {
+                    new_releases = contents
+                        .map(|item| parse_new_release_album(item.navigate_pointer(MTRIR)?))
+                        .collect::<Result<_>>()?
+                }
+                "FEmusic_moods_and_genres" => {
+                    moods_and_genres = contents.map(parse_mood_categories).collect::<Result<_>>()?
+                }
+                "FEmusic_new_releases_videos" => {
+                    new_music_videos = contents
+                        .map(|item| parse_new_music_video(item.navigate_pointer(MTRIR)?))
+                        .collect::<Result<_>>()?
+                }
+                _ => (),
+            }
+        }
+        Ok(GetExplore {
+            new_releases,
+            moods_and_genres,
+            new_music_videos,
+        })
+    }
+}
+impl ParseFrom<GetListenAgainQuery> for Vec<ListenAgainItem> {
+    fn parse_from(p: ProcessedResult<GetListenAgainQuery>) -> Result<Self> {
+        let json_crawler: JsonCrawlerOwned = p.into();
+        for section in json_crawler
+            .navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, SECTION_LIST))?
+            .try_into_iter()?
+        {
+            let Ok(carousel) = section.navigate_pointer(CAROUSEL) else {
+                continue;
+            };
+            // As the carousel titles are localised, the shelf is identified by the
+            // target of its header thumbnail, or its items being able to be pinned.
+            let is_listen_again = carousel
+                .borrow_value_pointer::<String>(LISTEN_AGAIN_HEADER_TARGET_ID)
+                .is_ok_and(|target_id| target_id == "music-identity-on-listen-again-shelf");
+            let mut contents = carousel.navigate_pointer("/contents")?;
+            let is_listen_again = is_listen_again
+                || contents
+                    .try_iter_mut()?
+                    .any(|mut item| item.borrow_pointer(MTRIR).is_ok_and(has_pin_toggle));
+            if !is_listen_again {
+                continue;
+            }
+            return contents
+                .try_into_iter()?
+                .map(|item| parse_listen_again_item(item.navigate_pointer(MTRIR)?))
+                .filter_map(Result::transpose)
+                .collect();
+        }
+        // The shelf is not shown to users without enough listening history.
+        Ok(Vec::new())
+    }
+}
+impl ParseFrom<PinToListenAgainQuery<'_>> for ApiOutcome {
+    fn parse_from(p: ProcessedResult<PinToListenAgainQuery>) -> Result<Self> {
+        parse_feedback_outcome(p.into())
+    }
+}
+impl ParseFrom<UnpinFromListenAgainQuery<'_>> for ApiOutcome {
+    fn parse_from(p: ProcessedResult<UnpinFromListenAgainQuery>) -> Result<Self> {
+        parse_feedback_outcome(p.into())
+    }
+}
+impl ParseFromContinuable<GetNewReleasesQuery> for Vec<NewReleaseAlbum> {
+    fn parse_from_continuable(
+        p: ProcessedResult<GetNewReleasesQuery>,
+    ) -> Result<(Self, Option<ContinuationParams<'static>>)> {
+        let json_crawler: JsonCrawlerOwned = p.into();
+        let grid =
+            json_crawler.navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, SECTION_LIST_ITEM, GRID))?;
+        parse_explore_grid(grid, parse_new_release_album)
+    }
+    fn parse_continuation(
+        p: ProcessedResult<GetContinuationsQuery<'_, GetNewReleasesQuery>>,
+    ) -> Result<(Self, Option<ContinuationParams<'static>>)> {
+        let json_crawler: JsonCrawlerOwned = p.into();
+        let grid = json_crawler.navigate_pointer(GRID_CONTINUATION)?;
+        parse_explore_grid(grid, parse_new_release_album)
+    }
+}
+impl ParseFromContinuable<GetNewMusicVideosQuery> for Vec<NewMusicVideo> {
+    fn parse_from_continuable(
+        p: ProcessedResult<GetNewMusicVideosQuery>,
+    ) -> Result<(Self, Option<ContinuationParams<'static>>)> {
+        let json_crawler: JsonCrawlerOwned = p.into();
+        let grid =
+            json_crawler.navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, SECTION_LIST_ITEM, GRID))?;
+        parse_explore_grid(grid, parse_new_music_video)
+    }
+    fn parse_continuation(
+        p: ProcessedResult<GetContinuationsQuery<'_, GetNewMusicVideosQuery>>,
+    ) -> Result<(Self, Option<ContinuationParams<'static>>)> {
+        let json_crawler: JsonCrawlerOwned = p.into();
+        let grid = json_crawler.navigate_pointer(GRID_CONTINUATION)?;
+        parse_explore_grid(grid, parse_new_music_video)
+    }
+}
+
+fn parse_explore_grid<T>(
+    mut grid: JsonCrawlerOwned,
+    parse_item: fn(JsonCrawlerOwned) -> Result<T>,
+) -> Result<(Vec<T>, Option<ContinuationParams<'static>>)> {
+    let continuation_params = grid.take_value_pointer(CONTINUATION_PARAMS).ok();
+    let items = grid
+        .navigate_pointer("/items")?
+        .try_into_iter()?
+        .map(|item| parse_item(item.navigate_pointer(MTRIR)?))
+        .collect::<Result<_>>()?;
+    Ok((items, continuation_params))
+}
+// Subtitle is in the format: "Album • Artist 1 & Artist 2".
+fn parse_new_release_album(mut item: JsonCrawlerOwned) -> Result<NewReleaseAlbum> {
+    let title = item.take_value_pointer(TITLE_TEXT)?;
+    let album_type = item.take_value_pointer(SUBTITLE)?;
+    let album_id = item.take_value_pointer(concatcp!(TITLE, NAVIGATION_BROWSE_ID))?;
+    let thumbnails = item.take_value_pointer(THUMBNAIL_RENDERER)?;
+    let explicit = if item.path_exists(SUBTITLE_BADGE_LABEL) {
+        Explicit::IsExplicit
+    } else {
+        Explicit::NotExplicit
+    };
+    let artists = item
+        .borrow_pointer(SUBTITLE_RUNS)?
+        .try_into_iter()?
+        .skip(2)
+        .step_by(2)
+        .map(|mut run| parse_song_artist(&mut run))
+        .collect::<Result<_>>()?;
+    Ok(NewReleaseAlbum {
+        title,
+        album_type,
+        artists,
+        album_id,
+        thumbnails,
+        explicit,
+    })
+}
+// Subtitle is in the format: "Artist 1 & Artist 2 • 1.2M views".
+fn parse_new_music_video(mut item: JsonCrawlerOwned) -> Result<NewMusicVideo> {
+    let title = item.take_value_pointer(TITLE_TEXT)?;
+    let video_id = item.take_value_pointer(NAVIGATION_VIDEO_ID)?;
+    let thumbnails = item.take_value_pointer(THUMBNAIL_RENDERER)?;
+    let views = item.borrow_pointer(SUBTITLE_RUNS)?.try_expect(
+        "Subtitle runs should contain at least 1 item",
+        |subtitle_runs| {
+            subtitle_runs
+                .try_iter_mut()?
+                .next_back()
+                .map(|mut run| run.take_value_pointer("/text"))
+                .transpose()
+        },
+    )?;
+    let subtitle_runs = item.borrow_pointer(SUBTITLE_RUNS)?.try_into_iter()?;
+    // The last two runs are the separator and views.
+    let artist_runs = subtitle_runs.len().saturating_sub(2);
+    let artists = subtitle_runs
+        .take(artist_runs)
+        .step_by(2)
+        .map(|mut run| parse_song_artist(&mut run))
+        .collect::<Result<_>>()?;
+    Ok(NewMusicVideo {
+        title,
+        artists,
+        views,
+        video_id,
+        thumbnails,
+    })
+}
+
+/// Returns None if the item is of a type that's not supported, e.g a podcast.
+fn parse_listen_again_item(mut item: JsonCrawlerOwned) -> Result<Option<ListenAgainItem>> {
+    let id = if let Ok(mut browse_endpoint) = item.borrow_pointer(NAVIGATION_BROWSE) {
+        let browse_id: String = browse_endpoint.take_value_pointer("/browseId")?;
+        match browse_endpoint.take_value_pointer(PAGE_TYPE)? {
+            YoutubeMusicEntityPageType::Artist => {
+                ListenAgainItemID::Artist(ArtistChannelID::from_raw(browse_id))
+            }
+            YoutubeMusicEntityPageType::Album => {
+                ListenAgainItemID::Album(AlbumID::from_raw(browse_id))
+            }
+            YoutubeMusicEntityPageType::Playlist => {
+                let playlist_id = match browse_id.strip_prefix("VL") {
+                    Some(playlist_id) => playlist_id.to_string(),
+                    None => browse_id,
+                };
+                ListenAgainItemID::Playlist(PlaylistID::from_raw(playlist_id))
+            }
+            YoutubeMusicEntityPageType::Podcast
+            | YoutubeMusicEntityPageType::Profile
+            | YoutubeMusicEntityPageType::Other => return Ok(None),
+        }
+    } else {
+        ListenAgainItemID::Song(item.take_value_pointer(NAVIGATION_VIDEO_ID)?)
+    };
+    let title = item.take_value_pointer(TITLE_TEXT)?;
+    let subtitle = item
+        .borrow_pointer(SUBTITLE_RUNS)?
+        .try_into_iter()?
+        .map(|mut run| run.take_value_pointer::<String>("/text"))
+        .collect::<CrawlerResult<String>>()?;
+    let thumbnails = item.take_value_pointer(THUMBNAIL_RENDERER)?;
+    let pin_manager = match item.borrow_pointer(MENU_ITEMS) {
+        Ok(menu) => parse_pin_manager_from_menu(menu)?,
+        Err(_) => None,
+    };
+    Ok(Some(ListenAgainItem {
+        title,
+        subtitle,
+        id,
+        thumbnails,
+        pin_manager,
+    }))
+}
+const LISTEN_AGAIN_HEADER_TARGET_ID: &str =
+    "/header/musicCarouselShelfBasicHeaderRenderer/thumbnail/musicThumbnailRenderer/targetId";
+fn has_pin_toggle(mut item: JsonCrawlerBorrowed) -> bool {
+    item.borrow_pointer(MENU_ITEMS)
+        .and_then(|menu| menu.try_into_iter())
+        .is_ok_and(|mut menu_items| {
+            menu_items.any(|menu_item| {
+                menu_item
+                    .borrow_value_pointer::<PinStatus>(concatcp!(
+                        TOGGLE_MENU,
+                        "/defaultIcon/iconType"
+                    ))
+                    .is_ok()
+            })
+        })
+}
+// The menu can contain other toggles, e.g add to library, so the pin toggle is
+// identified by its icon.
+fn parse_pin_manager_from_menu(menu: JsonCrawlerBorrowed) -> Result<Option<ListenAgainPinManager>> {
+    let Some(mut toggle_menu) = menu.try_into_iter()?.find_map(|menu_item| {
+        menu_item
+            .navigate_pointer(TOGGLE_MENU)
+            .ok()
+            .filter(|toggle_menu| {
+                toggle_menu
+                    .borrow_value_pointer::<PinStatus>("/defaultIcon/iconType")
+                    .is_ok()
+            })
+    }) else {
+        return Ok(None);
+    };
+    let status = toggle_menu.take_value_pointer("/defaultIcon/iconType")?;
+    let (pin_token, unpin_token) = match status {
+        PinStatus::Pinned => (
+            toggle_menu.take_value_pointer(TOGGLED_ENDPOINT)?,
+            toggle_menu.take_value_pointer(DEFAULT_ENDPOINT)?,
+        ),
+        PinStatus::NotPinned => (
+            toggle_menu.take_value_pointer(DEFAULT_ENDPOINT)?,
+            toggle_menu.take_value_pointer(TOGGLED_ENDPOINT)?,
+        ),
+    };
+    Ok(Some(ListenAgainPinManager {
+        status,
+        pin_token,
+        unpin_token,
+    }))
+}
+
 fn parse_mood_category_sections(crawler: JsonCrawlerOwned) -> Result<MoodCategorySection> {
     let mut crawler = crawler.navigate_pointer(GRID)?;
     let section_name =
@@ -200,10 +568,13 @@ fn get_taste_profile_artist(mut crawler: JsonCrawlerOwned) -> Result<TasteProfil
 mod tests {
     use crate::auth::BrowserToken;
     use crate::common::{
+        ApiOutcome, FeedbackTokenPinToListenAgain, FeedbackTokenUnpinFromListenAgain,
         MoodCategoryParams, TasteToken, TasteTokenImpression, TasteTokenSelection, YoutubeID,
     };
     use crate::query::{
-        GetMoodCategoriesQuery, GetMoodPlaylistsQuery, GetTasteProfileQuery, SetTasteProfileQuery,
+        GetExploreQuery, GetListenAgainQuery, GetMoodCategoriesQuery, GetMoodPlaylistsQuery,
+        GetNewMusicVideosQuery, GetNewReleasesQuery, GetTasteProfileQuery, PinToListenAgainQuery,
+        SetTasteProfileQuery, UnpinFromListenAgainQuery,
     };
 
     #[tokio::test]
@@ -225,6 +596,95 @@ mod tests {
         );
     }
     #[tokio::test]
+    async fn test_get_explore() {
+        parse_test!(
+            "./test_json/get_explore_mock.json",
+            "./test_json/get_explore_mock_output.txt",
+            GetExploreQuery,
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_get_new_releases() {
+        parse_test!(
+            "./test_json/get_new_releases_mock.json",
+            "./test_json/get_new_releases_mock_output.txt",
+            GetNewReleasesQuery,
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_get_new_releases_continuation() {
+        parse_continuations_test!(
+            "./test_json/get_new_releases_continuation_mock.json",
+            "./test_json/get_new_releases_continuation_mock_output.txt",
+            GetNewReleasesQuery,
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_get_new_music_videos() {
+        parse_test!(
+            "./test_json/get_new_music_videos_mock.json",
+            "./test_json/get_new_music_videos_mock_output.txt",
+            GetNewMusicVideosQuery,
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_get_new_music_videos_continuation() {
+        parse_continuations_test!(
+            "./test_json/get_new_music_videos_continuation_mock.json",
+            "./test_json/get_new_music_videos_continuation_mock_output.txt",
+            GetNewMusicVideosQuery,
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_get_listen_again() {
+        parse_test!(
+            "./test_json/get_listen_again_mock.json",
+            "./test_json/get_listen_again_mock_output.txt",
+            GetListenAgainQuery,
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_get_listen_again_home() {
+        // Note - SetTasteProfile responds with the home page, so shares a capture with
+        // set_taste_profile.
+        parse_test!(
+            "./test_json/set_taste_profile_20240723.json",
+            "./test_json/get_listen_again_20240723_output.txt",
+            GetListenAgainQuery,
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_pin_to_listen_again() {
+        // Note - uses the feedback endpoint, so shares a capture with
+        // remove_history_items.
+        let source = crate::parse::processed_feedback_response().await;
+        let parsed = crate::process_json::<_, BrowserToken>(
+            source,
+            PinToListenAgainQuery::new(FeedbackTokenPinToListenAgain::from_raw("")),
+        )
+        .unwrap();
+        assert_eq!(parsed, ApiOutcome::Success);
+    }
+    #[tokio::test]
+    async fn test_unpin_from_listen_again() {
+        // Note - uses the feedback endpoint, so shares a capture with
+        // remove_history_items.
+        let source = crate::parse::processed_feedback_response().await;
+        let parsed = crate::process_json::<_, BrowserToken>(
+            source,
+            UnpinFromListenAgainQuery::new(FeedbackTokenUnpinFromListenAgain::from_raw("")),
+        )
+        .unwrap();
+        assert_eq!(parsed, ApiOutcome::Success);
+    }
+    #[tokio::test]
     async fn test_get_taste_profile() {
         parse_test!(
             "./test_json/get_taste_profile_20240722.json",
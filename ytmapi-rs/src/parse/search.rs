@@ -2,14 +2,16 @@ use super::{
     DISPLAY_POLICY, ParseFrom, ProcessedResult, flex_column_item_pointer, parse_flex_column_item,
 };
 use crate::common::{
-    AlbumID, AlbumType, ArtistChannelID, ContinuationParams, EpisodeID, Explicit, PlaylistID,
-    PodcastID, SearchSuggestion, SuggestionType, TextRun, Thumbnail, UserChannelID, VideoID,
+    AlbumID, AlbumType, ApiOutcome, ArtistChannelID, ContinuationParams, EpisodeID, Explicit,
+    PlaylistID, PodcastID, SearchSuggestion, SuggestionType, TextRun, Thumbnail, UserChannelID,
+    VideoID, YoutubeID,
 };
 use crate::continuations::ParseFromContinuable;
 use crate::nav_consts::{
-    BADGE_LABEL, CONTINUATION_PARAMS, LIVE_BADGE_LABEL, MRLIR, MUSIC_CARD_SHELF, MUSIC_SHELF,
-    MUSIC_SHELF_CONTINUATION, NAVIGATION_BROWSE, NAVIGATION_BROWSE_ID, PAGE_TYPE, PLAY_BUTTON,
-    PLAYLIST_ITEM_VIDEO_ID, SECTION_LIST, SUBTITLE, SUBTITLE2, TAB_CONTENT, THUMBNAILS, TITLE_TEXT,
+    BADGE_LABEL, CONTINUATION_PARAMS, FEEDBACK_TOKEN, LIVE_BADGE_LABEL, MRLIR, MUSIC_CARD_SHELF,
+    MUSIC_SHELF, MUSIC_SHELF_CONTINUATION, NAVIGATION_BROWSE, NAVIGATION_BROWSE_ID,
+    NAVIGATION_VIDEO_TYPE, PAGE_TYPE, PLAY_BUTTON, PLAYLIST_ITEM_VIDEO_ID, SECTION_LIST, SUBTITLE,
+    SUBTITLE2, TAB_CONTENT, THUMBNAILS, TITLE_TEXT, WATCH_VIDEO_ID,
 };
 use crate::parse::{EpisodeDate, ParsedSongAlbum};
 use crate::query::search::UnfilteredSearchType;
@@ -19,7 +21,9 @@ use crate::query::search::filteredsearch::{
     SongsFilter, VideosFilter,
 };
 use crate::query::*;
-use crate::youtube_enums::{PlaylistEndpointParams, YoutubeMusicPageType};
+use crate::youtube_enums::{
+    PlaylistEndpointParams, YoutubeMusicEntityPageType, YoutubeMusicPageType, YoutubeMusicVideoType,
+};
 use crate::{Error, Result};
 use const_format::concatcp;
 use itertools::Itertools;
@@ -31,6 +35,7 @@ use serde::{Deserialize, Serialize};
 mod tests;
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct SearchResults {
     pub top_results: Vec<TopResult>,
@@ -45,6 +50,7 @@ pub struct SearchResults {
     pub profiles: Vec<SearchResultProfile>,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 /// Each Top Result has it's own type.
 pub enum TopResultType {
     Artist,
@@ -57,6 +63,7 @@ pub enum TopResultType {
     Album(AlbumType),
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 // Helper enum for parsing different search result types.
 enum SearchResultType {
     #[serde(alias = "Top result")]
@@ -75,6 +82,7 @@ enum SearchResultType {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 /// Dynamically defined top result.
 /// Some fields are optional as they are not defined for all result types.
@@ -96,7 +104,14 @@ pub struct TopResult {
     /// Generic tagline that can appear on top results
     pub byline: Option<String>,
 }
+impl_parsed_fields!(TopResult {
+    parsed_duration: duration as duration,
+    parsed_year: year as year,
+    parsed_subscribers: subscribers as count,
+    parsed_plays: plays as count,
+});
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 /// An artist search result.
 pub struct SearchResultArtist {
@@ -106,7 +121,11 @@ pub struct SearchResultArtist {
     pub browse_id: ArtistChannelID<'static>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(SearchResultArtist {
+    parsed_subscribers: subscribers as count,
+});
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 /// A podcast search result.
 pub struct SearchResultPodcast {
@@ -116,6 +135,7 @@ pub struct SearchResultPodcast {
     pub thumbnails: Vec<Thumbnail>,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 /// A podcast episode search result.
 pub struct SearchResultEpisode {
@@ -127,6 +147,7 @@ pub struct SearchResultEpisode {
     pub thumbnails: Vec<Thumbnail>,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 /// A video search result. May be a video or a video episode of a podcast.
 pub enum SearchResultVideo {
     #[non_exhaustive]
@@ -153,6 +174,7 @@ pub enum SearchResultVideo {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 /// A profile search result.
 pub struct SearchResultProfile {
@@ -162,6 +184,7 @@ pub struct SearchResultProfile {
     pub thumbnails: Vec<Thumbnail>,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 /// An album search result.
 pub struct SearchResultAlbum {
@@ -173,7 +196,11 @@ pub struct SearchResultAlbum {
     pub album_type: AlbumType,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(SearchResultAlbum {
+    parsed_year: year as year,
+});
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct SearchResultSong {
     // Potentially can include links to artist and album.
@@ -187,7 +214,12 @@ pub struct SearchResultSong {
     pub video_id: VideoID<'static>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(SearchResultSong {
+    parsed_duration: duration as duration,
+    parsed_plays: plays as count,
+});
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 // A playlist search result may be a featured or community playlist or even a
 // podcast.
@@ -198,6 +230,7 @@ pub enum SearchResultPlaylist {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 // When doing a basic search, community playlists might actually be podcasts.
 pub enum BasicSearchResultCommunityPlaylist {
@@ -206,6 +239,7 @@ pub enum BasicSearchResultCommunityPlaylist {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 /// A community playlist search result.
 pub struct SearchResultCommunityPlaylist {
@@ -215,7 +249,11 @@ pub struct SearchResultCommunityPlaylist {
     pub playlist_id: PlaylistID<'static>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(SearchResultCommunityPlaylist {
+    parsed_views: views as count,
+});
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 /// A featured playlist search result.
 pub struct SearchResultFeaturedPlaylist {
@@ -1345,39 +1383,179 @@ impl<'a> ParseFromContinuable<SearchQuery<'a, FilteredSearch<PlaylistsFilter>>>
     }
 }
 
-impl<'a> ParseFrom<GetSearchSuggestionsQuery<'a>> for Vec<SearchSuggestion> {
+/// Search suggestions returned by YouTube Music as you type.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct SearchSuggestions {
+    /// Text suggestions - either predicted searches, or searches from your
+    /// search history.
+    pub suggestions: Vec<SearchSuggestion>,
+    /// Suggested artists, albums, songs etc that can be navigated to directly.
+    pub entities: Vec<SearchSuggestionEntity>,
+}
+
+/// An artist, album, song etc suggested as you type a search.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct SearchSuggestionEntity {
+    pub title: String,
+    /// e.g "Song • The Beatles • Abbey Road".
+    pub subtitle: String,
+    pub thumbnails: Vec<Thumbnail>,
+    pub id: SearchSuggestionEntityID,
+}
+
+/// The ID of the item a [`SearchSuggestionEntity`] links to.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum SearchSuggestionEntityID {
+    Artist(ArtistChannelID<'static>),
+    Album(AlbumID<'static>),
+    Playlist(PlaylistID<'static>),
+    Podcast(PodcastID<'static>),
+    Profile(UserChannelID<'static>),
+    Song(VideoID<'static>),
+    Video(VideoID<'static>),
+    Episode(EpisodeID<'static>),
+}
+
+impl<'a> ParseFrom<GetSearchSuggestionsQuery<'a>> for SearchSuggestions {
     fn parse_from(p: ProcessedResult<GetSearchSuggestionsQuery<'a>>) -> crate::Result<Self> {
+        let mut json_crawler: JsonCrawlerOwned = p.into();
+        let mut suggestions_section =
+            json_crawler.borrow_pointer("/contents/0/searchSuggestionsSectionRenderer/contents")?;
+        let suggestions = suggestions_section
+            .try_iter_mut()?
+            .map(parse_search_suggestion)
+            .collect::<Result<_>>()?;
+        // Entity suggestions are not always returned, e.g if the search is too
+        // short.
+        let entities = match json_crawler
+            .borrow_pointer("/contents/1/searchSuggestionsSectionRenderer/contents")
+        {
+            Ok(mut entities_section) => entities_section
+                .try_iter_mut()?
+                .map(|item| parse_search_suggestion_entity(item.navigate_pointer(MRLIR)?))
+                .filter_map(Result::transpose)
+                .collect::<Result<_>>()?,
+            Err(_) => Vec::new(),
+        };
+        Ok(SearchSuggestions {
+            suggestions,
+            entities,
+        })
+    }
+}
+
+impl ParseFrom<RemoveSearchSuggestionsQuery<'_>> for Vec<ApiOutcome> {
+    fn parse_from(p: ProcessedResult<RemoveSearchSuggestionsQuery>) -> Result<Self> {
         let json_crawler: JsonCrawlerOwned = p.into();
-        let mut suggestions = json_crawler
-            .navigate_pointer("/contents/0/searchSuggestionsSectionRenderer/contents")?;
-        let mut results = Vec::new();
-        for mut s in suggestions.try_iter_mut()? {
-            let mut runs = Vec::new();
-            if let Ok(mut search_suggestion) =
-                s.borrow_pointer("/searchSuggestionRenderer/suggestion/runs")
-            {
-                for mut r in search_suggestion.try_iter_mut()? {
-                    if let Ok(true) = r.take_value_pointer("/bold") {
-                        runs.push(r.take_value_pointer("/text").map(TextRun::Bold)?)
-                    } else {
-                        runs.push(r.take_value_pointer("/text").map(TextRun::Normal)?)
-                    }
-                }
-                results.push(SearchSuggestion::new(SuggestionType::Prediction, runs))
+        json_crawler
+            .navigate_pointer("/feedbackResponses")?
+            .try_into_iter()?
+            .map(|mut response| {
+                response
+                    .take_value_pointer::<bool>("/isProcessed")
+                    .map(|processed| {
+                        if processed {
+                            ApiOutcome::Success
+                        } else {
+                            ApiOutcome::Failure
+                        }
+                    })
+            })
+            .collect::<json_crawler::CrawlerResult<_>>()
+            .map_err(Into::into)
+    }
+}
+
+fn parse_search_suggestion_runs(mut runs: JsonCrawlerBorrowed) -> Result<Vec<TextRun>> {
+    runs.try_iter_mut()?
+        .map(|mut r| {
+            if let Ok(true) = r.take_value_pointer("/bold") {
+                r.take_value_pointer("/text").map(TextRun::Bold)
             } else {
-                for mut r in s
-                    .borrow_pointer("/historySuggestionRenderer/suggestion/runs")?
-                    .try_iter_mut()?
-                {
-                    if let Ok(true) = r.take_value_pointer("/bold") {
-                        runs.push(r.take_value_pointer("/text").map(TextRun::Bold)?)
-                    } else {
-                        runs.push(r.take_value_pointer("/text").map(TextRun::Normal)?)
-                    }
-                }
-                results.push(SearchSuggestion::new(SuggestionType::History, runs))
+                r.take_value_pointer("/text").map(TextRun::Normal)
             }
-        }
-        Ok(results)
+        })
+        .collect::<std::result::Result<_, _>>()
+        .map_err(Into::into)
+}
+
+fn parse_search_suggestion(mut s: JsonCrawlerBorrowed) -> Result<SearchSuggestion> {
+    if let Ok(runs) = s.borrow_pointer("/searchSuggestionRenderer/suggestion/runs") {
+        let runs = parse_search_suggestion_runs(runs)?;
+        return Ok(SearchSuggestion::new(SuggestionType::Prediction, runs));
     }
+    let mut history_suggestion = s.borrow_pointer("/historySuggestionRenderer")?;
+    let runs =
+        parse_search_suggestion_runs(history_suggestion.borrow_pointer("/suggestion/runs")?)?;
+    let feedback_token_remove = history_suggestion
+        .take_value_pointer(concatcp!("/serviceEndpoint", FEEDBACK_TOKEN))
+        .ok();
+    Ok(SearchSuggestion::new_history(runs, feedback_token_remove))
+}
+
+/// Returns None if the entity is of an unrecognised type.
+fn parse_search_suggestion_entity(
+    mut item: JsonCrawlerBorrowed,
+) -> Result<Option<SearchSuggestionEntity>> {
+    let id = if let Ok(mut browse_endpoint) = item.borrow_pointer(NAVIGATION_BROWSE) {
+        let browse_id: String = browse_endpoint.take_value_pointer("/browseId")?;
+        match browse_endpoint.take_value_pointer(PAGE_TYPE)? {
+            YoutubeMusicEntityPageType::Artist => {
+                SearchSuggestionEntityID::Artist(ArtistChannelID::from_raw(browse_id))
+            }
+            YoutubeMusicEntityPageType::Album => {
+                SearchSuggestionEntityID::Album(AlbumID::from_raw(browse_id))
+            }
+            YoutubeMusicEntityPageType::Playlist => {
+                let playlist_id = match browse_id.strip_prefix("VL") {
+                    Some(playlist_id) => playlist_id.to_string(),
+                    None => browse_id,
+                };
+                SearchSuggestionEntityID::Playlist(PlaylistID::from_raw(playlist_id))
+            }
+            YoutubeMusicEntityPageType::Podcast => {
+                SearchSuggestionEntityID::Podcast(PodcastID::from_raw(browse_id))
+            }
+            YoutubeMusicEntityPageType::Profile => {
+                SearchSuggestionEntityID::Profile(UserChannelID::from_raw(browse_id))
+            }
+            YoutubeMusicEntityPageType::Other => return Ok(None),
+        }
+    } else {
+        let mut navigation_endpoint = item.borrow_pointer("/navigationEndpoint")?;
+        let video_id: String = navigation_endpoint.take_value_pointer(WATCH_VIDEO_ID)?;
+        match navigation_endpoint.take_value_pointer(NAVIGATION_VIDEO_TYPE)? {
+            YoutubeMusicVideoType::Atv => {
+                SearchSuggestionEntityID::Song(VideoID::from_raw(video_id))
+            }
+            YoutubeMusicVideoType::Episode => {
+                SearchSuggestionEntityID::Episode(EpisodeID::from_raw(video_id))
+            }
+            YoutubeMusicVideoType::Ugc
+            | YoutubeMusicVideoType::Omv
+            | YoutubeMusicVideoType::Shoulder
+            | YoutubeMusicVideoType::OfficialSourceMusic => {
+                SearchSuggestionEntityID::Video(VideoID::from_raw(video_id))
+            }
+            YoutubeMusicVideoType::Upload => return Ok(None),
+        }
+    };
+    let title = parse_flex_column_item(&mut item, 0, 0)?;
+    let subtitle = item
+        .borrow_pointer(format!("{}/text/runs", flex_column_item_pointer(1)))?
+        .try_into_iter()?
+        .map(|mut run| run.take_value_pointer::<String>("/text"))
+        .collect::<std::result::Result<String, _>>()?;
+    let thumbnails = item.take_value_pointer(THUMBNAILS)?;
+    Ok(Some(SearchSuggestionEntity {
+        title,
+        subtitle,
+        thumbnails,
+        id,
+    }))
 }
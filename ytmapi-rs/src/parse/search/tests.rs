@@ -1,10 +1,12 @@
 use crate::auth::BrowserToken;
+use crate::common::{FeedbackTokenRemoveSearchSuggestion, YoutubeID};
 use crate::parse::SearchResults;
 use crate::process_json;
 use crate::query::search::{
     AlbumsFilter, ArtistsFilter, CommunityPlaylistsFilter, EpisodesFilter, FeaturedPlaylistsFilter,
     PlaylistsFilter, PodcastsFilter, ProfilesFilter, SearchQuery, SongsFilter, VideosFilter,
 };
+use crate::query::{GetSearchSuggestionsQuery, RemoveSearchSuggestionsQuery};
 use pretty_assertions::assert_eq;
 use std::path::Path;
 
@@ -283,3 +285,21 @@ async fn test_search_profiles() {
         BrowserToken
     );
 }
+#[tokio::test]
+async fn test_get_search_suggestions() {
+    parse_test!(
+        "./test_json/get_search_suggestions_mock.json",
+        "./test_json/get_search_suggestions_mock_output.txt",
+        GetSearchSuggestionsQuery::new(""),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_remove_search_suggestions() {
+    parse_test!(
+        "./test_json/remove_search_suggestions_mock.json",
+        "./test_json/remove_search_suggestions_mock_output.txt",
+        RemoveSearchSuggestionsQuery::new([FeedbackTokenRemoveSearchSuggestion::from_raw("")]),
+        BrowserToken
+    );
+}
@@ -1,19 +1,302 @@
 use super::{ParseFrom, ProcessedResult};
-use crate::common::{LyricsID, SongTrackingUrl};
+use crate::common::{ArtistChannelID, LyricsID, SongTrackingUrl, Thumbnail, VideoID};
+use crate::decipher::Decipher;
 use crate::nav_consts::{DESCRIPTION, DESCRIPTION_SHELF, RUN_TEXT, SECTION_LIST_ITEM};
 use crate::query::GetLyricsQuery;
-use crate::query::song::{GetLyricsIDQuery, GetSongTrackingUrlQuery};
+use crate::query::song::{GetLyricsIDQuery, GetSongQuery, GetSongTrackingUrlQuery};
+use crate::{Error, Result};
 use const_format::concatcp;
-use json_crawler::{JsonCrawler, JsonCrawlerOwned};
+use json_crawler::{CrawlerResult, JsonCrawler, JsonCrawlerOwned};
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Lyrics {
     pub lyrics: String,
     pub source: String,
 }
 
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+/// Details and streaming formats for a song.
+pub struct Song {
+    pub playability_status: PlayabilityStatus,
+    pub video_details: SongVideoDetails,
+    pub microformat: SongMicroformat,
+    /// Number of seconds the stream urls remain valid for, from when the query
+    /// was made. Not present if the song is not playable.
+    pub expires_in_seconds: Option<u64>,
+    /// Adaptive audio-only formats. Empty if the song is not playable.
+    pub audio_formats: Vec<AudioFormat>,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PlayabilityStatus {
+    /// e.g "OK", "UNPLAYABLE", "LOGIN_REQUIRED".
+    pub status: String,
+    pub reason: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct SongVideoDetails {
+    pub video_id: VideoID<'static>,
+    pub title: String,
+    pub author: String,
+    pub channel_id: ArtistChannelID<'static>,
+    pub length_seconds: String,
+    pub view_count: String,
+    pub thumbnails: Vec<Thumbnail>,
+}
+impl_parsed_fields!(SongVideoDetails {
+    parsed_view_count: view_count as count,
+});
+impl SongVideoDetails {
+    /// Parse `length_seconds` as a Duration, if possible.
+    pub fn parsed_length(&self) -> Option<std::time::Duration> {
+        self.length_seconds
+            .parse()
+            .ok()
+            .map(std::time::Duration::from_secs)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct SongMicroformat {
+    pub url_canonical: String,
+    pub title: String,
+    pub description: String,
+    pub category: Option<String>,
+    pub publish_date: Option<String>,
+    pub upload_date: Option<String>,
+    pub family_safe: bool,
+    pub unlisted: bool,
+    pub available_countries: Vec<String>,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct AudioFormat {
+    pub itag: u64,
+    /// e.g `audio/webm; codecs="opus"`.
+    pub mime_type: String,
+    pub bitrate: u64,
+    pub average_bitrate: Option<u64>,
+    pub content_length: Option<u64>,
+    /// e.g "AUDIO_QUALITY_MEDIUM".
+    pub audio_quality: Option<String>,
+    pub audio_sample_rate: Option<u64>,
+    pub audio_channels: Option<u64>,
+    pub approx_duration_ms: Option<u64>,
+    pub loudness_db: Option<f64>,
+    pub url: StreamUrl,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// A stream url, that may need deciphering before use - see
+/// [`StreamUrl::resolve`].
+pub enum StreamUrl {
+    Url(String),
+    /// The url is missing a signature, which must be deciphered and added as
+    /// query parameter `signature_param`.
+    SignatureCipher {
+        url: String,
+        signature: String,
+        signature_param: String,
+    },
+}
+
+impl Song {
+    /// The audio format with the highest bitrate.
+    pub fn best_audio_format(&self) -> Option<&AudioFormat> {
+        self.best_audio_format_where(|_| true)
+    }
+    /// The audio format with the highest bitrate, out of those matching
+    /// `filter`.
+    /// ```no_run
+    /// use ytmapi_rs::common::{VideoID, YoutubeID};
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::new_unauthenticated().await?;
+    /// let song = yt.get_song(VideoID::from_raw("FZ8BxMU3BYc")).await?;
+    /// let best_opus = song.best_audio_format_where(|f| f.mime_type.contains("opus"));
+    /// # Ok::<(), ytmapi_rs::Error>(())
+    /// # };
+    /// ```
+    pub fn best_audio_format_where(
+        &self,
+        mut filter: impl FnMut(&AudioFormat) -> bool,
+    ) -> Option<&AudioFormat> {
+        self.audio_formats
+            .iter()
+            .filter(|f| filter(f))
+            .max_by_key(|f| f.bitrate)
+    }
+}
+
+impl StreamUrl {
+    /// Resolve a playable url, deciphering the signature and `n` parameter
+    /// using `decipher`.
+    pub fn resolve(&self, decipher: &impl Decipher) -> Result<String> {
+        let (url, signature) = match self {
+            StreamUrl::Url(url) => (url, None),
+            StreamUrl::SignatureCipher {
+                url,
+                signature,
+                signature_param,
+            } => (
+                url,
+                Some((signature_param, decipher.decipher_signature(signature)?)),
+            ),
+        };
+        let mut url = url::Url::parse(url)
+            .map_err(|e| Error::decipher(format!("Invalid stream url {url}: {e}")))?;
+        let pairs = url
+            .query_pairs()
+            .map(|(k, v)| match k.as_ref() {
+                "n" => Ok((k.into_owned(), decipher.decipher_n(&v)?)),
+                _ => Ok((k.into_owned(), v.into_owned())),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        url.query_pairs_mut()
+            .clear()
+            .extend_pairs(pairs)
+            .extend_pairs(signature);
+        Ok(url.into())
+    }
+}
+
+impl<'a> ParseFrom<GetSongQuery<'a>> for Song {
+    fn parse_from(p: ProcessedResult<GetSongQuery<'a>>) -> crate::Result<Self> {
+        let mut crawler = JsonCrawlerOwned::from(p);
+        let playability_status = PlayabilityStatus {
+            status: crawler.take_value_pointer("/playabilityStatus/status")?,
+            reason: crawler.take_value_pointer("/playabilityStatus/reason").ok(),
+        };
+        let mut video_details = crawler.borrow_pointer("/videoDetails")?;
+        let video_details = SongVideoDetails {
+            video_id: video_details.take_value_pointer("/videoId")?,
+            title: video_details.take_value_pointer("/title")?,
+            author: video_details.take_value_pointer("/author")?,
+            channel_id: video_details.take_value_pointer("/channelId")?,
+            length_seconds: video_details.take_value_pointer("/lengthSeconds")?,
+            view_count: video_details.take_value_pointer("/viewCount")?,
+            thumbnails: video_details.take_value_pointer("/thumbnail/thumbnails")?,
+        };
+        let mut microformat = crawler.borrow_pointer("/microformat/microformatDataRenderer")?;
+        let microformat = SongMicroformat {
+            url_canonical: microformat.take_value_pointer("/urlCanonical")?,
+            title: microformat.take_value_pointer("/title")?,
+            description: microformat.take_value_pointer("/description")?,
+            category: microformat.take_value_pointer("/category").ok(),
+            publish_date: microformat.take_value_pointer("/publishDate").ok(),
+            upload_date: microformat.take_value_pointer("/uploadDate").ok(),
+            family_safe: microformat.take_value_pointer("/familySafe")?,
+            unlisted: microformat.take_value_pointer("/unlisted")?,
+            available_countries: microformat
+                .take_value_pointer("/availableCountries")
+                .unwrap_or_default(),
+        };
+        if !crawler.path_exists("/streamingData") {
+            return Ok(Song {
+                playability_status,
+                video_details,
+                microformat,
+                expires_in_seconds: None,
+                audio_formats: Vec::new(),
+            });
+        }
+        let mut streaming_data = crawler.navigate_pointer("/streamingData")?;
+        let expires_in_seconds = take_parsed_str(&mut streaming_data, "/expiresInSeconds")?;
+        let mut audio_formats = Vec::new();
+        for mut format in streaming_data
+            .navigate_pointer("/adaptiveFormats")?
+            .try_into_iter()?
+        {
+            let mime_type: String = format.take_value_pointer("/mimeType")?;
+            if !mime_type.starts_with("audio/") {
+                continue;
+            }
+            audio_formats.push(parse_audio_format(mime_type, format)?);
+        }
+        Ok(Song {
+            playability_status,
+            video_details,
+            microformat,
+            expires_in_seconds,
+            audio_formats,
+        })
+    }
+}
+
+fn parse_audio_format(mime_type: String, mut crawler: impl JsonCrawler) -> Result<AudioFormat> {
+    let url = if crawler.path_exists("/url") {
+        StreamUrl::Url(crawler.take_value_pointer("/url")?)
+    } else {
+        let cipher: String = crawler.take_value_pointer("/signatureCipher")?;
+        let mut url = None;
+        let mut signature = None;
+        let mut signature_param = None;
+        for (k, v) in url::form_urlencoded::parse(cipher.as_bytes()) {
+            match k.as_ref() {
+                "url" => url = Some(v.into_owned()),
+                "s" => signature = Some(v.into_owned()),
+                "sp" => signature_param = Some(v.into_owned()),
+                _ => (),
+            }
+        }
+        let (Some(url), Some(signature)) = (url, signature) else {
+            return Err(Error::decipher(format!(
+                "signatureCipher missing url or signature <{cipher}>"
+            )));
+        };
+        StreamUrl::SignatureCipher {
+            url,
+            signature,
+            // Per yt-dlp, `signature` is the default if not provided.
+            signature_param: signature_param.unwrap_or_else(|| "signature".to_string()),
+        }
+    };
+    Ok(AudioFormat {
+        itag: crawler.take_value_pointer("/itag")?,
+        mime_type,
+        bitrate: crawler.take_value_pointer("/bitrate")?,
+        average_bitrate: crawler.take_value_pointer("/averageBitrate").ok(),
+        content_length: take_parsed_str(&mut crawler, "/contentLength")?,
+        audio_quality: crawler.take_value_pointer("/audioQuality").ok(),
+        audio_sample_rate: take_parsed_str(&mut crawler, "/audioSampleRate")?,
+        audio_channels: crawler.take_value_pointer("/audioChannels").ok(),
+        approx_duration_ms: take_parsed_str(&mut crawler, "/approxDurationMs")?,
+        loudness_db: crawler.take_value_pointer("/loudnessDb").ok(),
+        url,
+    })
+}
+
+/// Innertube returns some numbers as strings - take and parse one of these if
+/// it exists.
+fn take_parsed_str<F: FromStr>(
+    crawler: &mut impl JsonCrawler,
+    path: &str,
+) -> CrawlerResult<Option<F>>
+where
+    F::Err: Display,
+{
+    if !crawler.path_exists(path) {
+        return Ok(None);
+    }
+    crawler.borrow_pointer(path)?.take_and_parse_str().map(Some)
+}
+
 impl<'a> ParseFrom<GetSongTrackingUrlQuery<'a>> for SongTrackingUrl<'static> {
     fn parse_from(p: super::ProcessedResult<GetSongTrackingUrlQuery<'a>>) -> crate::Result<Self> {
         let mut crawler = JsonCrawlerOwned::from(p);
@@ -54,11 +337,65 @@ impl<'a> ParseFrom<GetLyricsQuery<'a>> for Lyrics {
 mod tests {
     use crate::auth::BrowserToken;
     use crate::common::{LyricsID, SongTrackingUrl, VideoID, YoutubeID};
-    use crate::parse::song::Lyrics;
+    use crate::decipher::{Decipher, NoDecipher};
+    use crate::parse::song::{Lyrics, StreamUrl};
     use crate::process_json;
     use crate::query::song::GetSongTrackingUrlQuery;
-    use crate::query::{GetLyricsIDQuery, GetLyricsQuery};
+    use crate::query::{GetLyricsIDQuery, GetLyricsQuery, GetSongQuery};
 
+    #[tokio::test]
+    async fn test_get_song() {
+        parse_test!(
+            "./test_json/get_song_tracking_url_20240728.json",
+            "./test_json/get_song_20240728_output.txt",
+            GetSongQuery::new(VideoID::from_raw("")).unwrap(),
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_get_song_resolve_url() {
+        struct ReverseDecipher;
+        impl Decipher for ReverseDecipher {
+            fn decipher_signature(&self, signature: &str) -> crate::Result<String> {
+                Ok(signature.chars().rev().collect())
+            }
+            fn decipher_n(&self, n: &str) -> crate::Result<String> {
+                Ok(n.chars().rev().collect())
+            }
+        }
+        let source =
+            std::fs::read_to_string("./test_json/get_song_tracking_url_20240728.json").unwrap();
+        let song = process_json::<_, BrowserToken>(
+            source,
+            GetSongQuery::new(VideoID::from_raw("")).unwrap(),
+        )
+        .unwrap();
+        let format = song.best_audio_format().unwrap();
+        assert_eq!(format.itag, 774);
+        let StreamUrl::SignatureCipher {
+            signature,
+            signature_param,
+            ..
+        } = &format.url
+        else {
+            panic!("Expected captured format to have a signatureCipher");
+        };
+        assert_eq!(signature_param, "sig");
+        assert!(format.url.resolve(&NoDecipher).is_err());
+        let url = url::Url::parse(&format.url.resolve(&ReverseDecipher).unwrap()).unwrap();
+        assert_eq!(
+            url.host_str(),
+            Some("rr1---sn-fpqxc5oq-2xgs.googlevideo.com")
+        );
+        let param = |key: &str| {
+            url.query_pairs()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.into_owned())
+        };
+        // Captured n parameter is "BUObUUMbYkmoZ-k4zO".
+        assert_eq!(param("n").as_deref(), Some("Oz4k-ZomkYbMUUbOUB"));
+        assert_eq!(param("sig"), Some(signature.chars().rev().collect()));
+    }
     #[tokio::test]
     async fn test_get_song_tracking_url_query() {
         let output = SongTrackingUrl::from_raw(
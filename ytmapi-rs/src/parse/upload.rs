@@ -4,7 +4,8 @@ use super::{
 };
 use crate::Result;
 use crate::common::{
-    AlbumType, LikeStatus, Thumbnail, UploadAlbumID, UploadArtistID, UploadEntityID, VideoID,
+    AlbumType, ApiOutcome, LikeStatus, Thumbnail, UploadAlbumID, UploadArtistID, UploadEntityID,
+    VideoID,
 };
 use crate::continuations::ParseFromContinuable;
 use crate::nav_consts::{
@@ -16,8 +17,9 @@ use crate::nav_consts::{
 };
 use crate::parse::{parse_fixed_column_item, parse_flex_column_item};
 use crate::query::{
-    DeleteUploadEntityQuery, GetLibraryUploadAlbumQuery, GetLibraryUploadAlbumsQuery,
-    GetLibraryUploadArtistQuery, GetLibraryUploadArtistsQuery, GetLibraryUploadSongsQuery,
+    DeleteUploadEntityQuery, EditUploadSongQuery, GetLibraryUploadAlbumQuery,
+    GetLibraryUploadAlbumsQuery, GetLibraryUploadArtistQuery, GetLibraryUploadArtistsQuery,
+    GetLibraryUploadSongsQuery,
 };
 use crate::youtube_enums::{YoutubeMusicAnimatedIcon, YoutubeMusicBadgeRendererIcon};
 use const_format::concatcp;
@@ -25,12 +27,14 @@ use json_crawler::{JsonCrawler, JsonCrawlerIterator, JsonCrawlerOwned};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 // Intentionally not marked non_exhaustive - not expecting this to change.
 pub struct ParsedUploadArtist {
     pub name: String,
     pub id: Option<UploadArtistID<'static>>,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 // Intentionally not marked non_exhaustive - not expecting this to change.
 pub struct ParsedUploadSongAlbum {
     pub name: String,
@@ -38,6 +42,7 @@ pub struct ParsedUploadSongAlbum {
 }
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 // May need to be enum to track 'Not Available' case.
 pub struct TableListUploadSong {
@@ -50,8 +55,12 @@ pub struct TableListUploadSong {
     pub artists: Vec<ParsedUploadArtist>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(TableListUploadSong {
+    parsed_duration: duration as duration,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct UploadAlbum {
     pub title: String,
@@ -62,8 +71,12 @@ pub struct UploadAlbum {
     pub album_id: UploadAlbumID<'static>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(UploadAlbum {
+    parsed_year: year as year,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct UploadArtist {
     pub artist_name: String,
@@ -71,8 +84,12 @@ pub struct UploadArtist {
     pub artist_id: UploadArtistID<'static>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(UploadArtist {
+    parsed_song_count: song_count as count,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetLibraryUploadAlbum {
     pub title: String,
@@ -84,8 +101,13 @@ pub struct GetLibraryUploadAlbum {
     pub songs: Vec<GetLibraryUploadAlbumSong>,
     pub thumbnails: Vec<Thumbnail>,
 }
+impl_parsed_fields!(GetLibraryUploadAlbum {
+    parsed_song_count: song_count as count,
+    parsed_duration: duration as duration,
+});
 
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 // May need to be enum to track 'Not Available' case.
 pub struct GetLibraryUploadAlbumSong {
@@ -97,6 +119,9 @@ pub struct GetLibraryUploadAlbumSong {
     pub duration: String,
     pub like_status: LikeStatus,
 }
+impl_parsed_fields!(GetLibraryUploadAlbumSong {
+    parsed_duration: duration as duration,
+});
 
 impl ParseFromContinuable<GetLibraryUploadSongsQuery> for Vec<TableListUploadSong> {
     fn parse_from_continuable(
@@ -300,6 +325,15 @@ impl<'a> ParseFrom<DeleteUploadEntityQuery<'a>> for () {
             .map_err(Into::into)
     }
 }
+impl<'a> ParseFrom<EditUploadSongQuery<'a>> for ApiOutcome {
+    fn parse_from(p: super::ProcessedResult<EditUploadSongQuery<'a>>) -> crate::Result<Self> {
+        let crawler: JsonCrawlerOwned = p.into();
+        crawler
+            .navigate_pointer("/status")?
+            .take_value()
+            .map_err(Into::into)
+    }
+}
 pub(crate) fn parse_upload_song_artists(
     data: impl JsonCrawler,
     col_idx: usize,
@@ -433,7 +467,7 @@ fn get_uploads_tab(json: JsonCrawlerOwned) -> Result<JsonCrawlerOwned> {
 #[cfg(test)]
 mod tests {
     use crate::auth::BrowserToken;
-    use crate::common::{UploadAlbumID, UploadArtistID, UploadEntityID, YoutubeID};
+    use crate::common::{ApiOutcome, UploadAlbumID, UploadArtistID, UploadEntityID, YoutubeID};
     #[tokio::test]
     async fn test_get_library_upload_songs() {
         parse_with_matching_continuation_test!(
@@ -492,4 +526,16 @@ mod tests {
             BrowserToken
         );
     }
+    #[tokio::test]
+    async fn test_edit_upload_song() {
+        parse_test_value!(
+            "./test_json/edit_upload_song_mock.json",
+            ApiOutcome::Success,
+            crate::query::EditUploadSongQuery::new(UploadEntityID::from_raw(""))
+                .with_new_title("")
+                .with_new_artist("")
+                .with_new_album(""),
+            BrowserToken
+        );
+    }
 }
@@ -1,18 +1,26 @@
-use super::ParseFrom;
+use super::{AlbumResult, ParseFrom, parse_album_from_mtrir};
 use crate::Result;
-use crate::common::{PlaylistID, Thumbnail, UserPlaylistsParams, UserVideosParams, VideoID};
+use crate::common::{
+    ArtistChannelID, BrowseParams, ContinuationParams, PlaylistID, Thumbnail, UserPlaylistsParams,
+    UserVideosParams, VideoID,
+};
+use crate::continuations::ParseFromContinuable;
 use crate::nav_consts::{
-    CAROUSEL, CAROUSEL_TITLE, FOREGROUND_THUMBNAIL_RENDERER, GRID_ITEMS, MTRIR, NAVIGATION_BROWSE,
-    NAVIGATION_BROWSE_ID, NAVIGATION_VIDEO_ID, SECTION_LIST, SECTION_LIST_ITEM, SINGLE_COLUMN_TAB,
-    SUBTITLE2, SUBTITLE3, THUMBNAIL_RENDERER, TITLE_TEXT, VISUAL_HEADER,
+    CAROUSEL, CAROUSEL_TITLE, CONTINUATION_PARAMS, FOREGROUND_THUMBNAIL_RENDERER, GRID,
+    GRID_CONTINUATION, MTRIR, NAVIGATION_BROWSE, NAVIGATION_BROWSE_ID, NAVIGATION_VIDEO_ID,
+    PAGE_TYPE, SECTION_LIST, SECTION_LIST_ITEM, SINGLE_COLUMN_TAB, SUBTITLE2, SUBTITLE3,
+    THUMBNAIL_RENDERER, TITLE_TEXT, VISUAL_HEADER,
+};
+use crate::query::{
+    GetContinuationsQuery, GetUserPlaylistsQuery, GetUserQuery, GetUserVideosQuery,
 };
-use crate::query::{GetUserPlaylistsQuery, GetUserQuery, GetUserVideosQuery};
+use crate::youtube_enums::YoutubeMusicCarouselItemPageType;
 use const_format::concatcp;
 use json_crawler::{JsonCrawler, JsonCrawlerOwned};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct GetUser {
     pub name: String,
@@ -21,8 +29,25 @@ pub struct GetUser {
     pub all_videos_params: Option<UserVideosParams<'static>>,
     pub playlists: Vec<UserPlaylist>,
     pub all_playlists_params: Option<UserPlaylistsParams<'static>>,
+    /// Album shelves, such as albums and singles, only present on channels
+    /// owned by an artist.
+    pub album_shelves: Vec<UserAlbumShelf>,
+}
+/// An album shelf on a user's channel page, e.g "Albums" or "Singles".
+/// The browse_id and params can be used to get the full list of albums for the
+/// shelf, using [`GetArtistAlbumsQuery`](crate::query::GetArtistAlbumsQuery).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct UserAlbumShelf {
+    /// Display title of the shelf - language dependent.
+    pub title: String,
+    pub results: Vec<AlbumResult>,
+    pub browse_id: Option<ArtistChannelID<'static>>,
+    pub params: Option<BrowseParams<'static>>,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct UserVideo {
     pub title: String,
@@ -30,7 +55,11 @@ pub struct UserVideo {
     pub thumbnails: Vec<Thumbnail>,
     pub id: VideoID<'static>,
 }
+impl_parsed_fields!(UserVideo {
+    parsed_views: views as count,
+});
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct UserPlaylist {
     pub title: String,
@@ -38,6 +67,34 @@ pub struct UserPlaylist {
     pub thumbnails: Vec<Thumbnail>,
     pub id: PlaylistID<'static>,
 }
+impl_parsed_fields!(UserPlaylist {
+    parsed_views: views as count,
+});
+
+/// The kind of a carousel on a user's channel page. Determined from the
+/// endpoint of the first item, since carousel titles are language dependent.
+enum UserCarouselKind {
+    Videos,
+    Playlists,
+    Albums,
+    Other,
+}
+impl UserCarouselKind {
+    fn of(carousel: &impl JsonCrawler) -> Self {
+        const FIRST_ITEM: &str = concatcp!("/contents/0", MTRIR);
+        if carousel.path_exists(concatcp!(FIRST_ITEM, NAVIGATION_VIDEO_ID)) {
+            return Self::Videos;
+        }
+        match carousel
+            .borrow_value_pointer(concatcp!(FIRST_ITEM, NAVIGATION_BROWSE, PAGE_TYPE))
+            .ok()
+        {
+            Some(YoutubeMusicCarouselItemPageType::Playlist) => Self::Playlists,
+            Some(YoutubeMusicCarouselItemPageType::Album) => Self::Albums,
+            Some(YoutubeMusicCarouselItemPageType::Other) | None => Self::Other,
+        }
+    }
+}
 
 impl ParseFrom<GetUserQuery<'_>> for GetUser {
     fn parse_from(p: super::ProcessedResult<GetUserQuery>) -> Result<Self> {
@@ -46,78 +103,126 @@ impl ParseFrom<GetUserQuery<'_>> for GetUser {
         let name = header.take_value_pointer(TITLE_TEXT)?;
         let thumbnails = header.take_value_pointer(FOREGROUND_THUMBNAIL_RENDERER)?;
         let contents = json_crawler.navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, SECTION_LIST))?;
-        // TODO: i18n
-        let mut carousels: HashMap<String, _> = contents
+        let mut videos = None;
+        let mut all_videos_params = None;
+        let mut playlists = None;
+        let mut all_playlists_params = None;
+        let mut album_shelves = Vec::new();
+        // Shelves that aren't carousels (e.g descriptions) are skipped.
+        for mut carousel in contents
             .try_into_iter()?
-            .map(|crawler| {
-                let mut carousel = crawler.navigate_pointer(CAROUSEL)?;
-                let title = carousel.take_value_pointer(concatcp!(CAROUSEL_TITLE, "/text"))?;
-                Ok((title, carousel))
-            })
-            .collect::<Result<_>>()?;
-        let mut maybe_playlists_carousel = carousels.get_mut("Playlists");
-        let all_playlists_params =
-            maybe_playlists_carousel
-                .as_mut()
-                .and_then(|playlists_carousel| {
-                    playlists_carousel
+            .filter_map(|shelf| shelf.navigate_pointer(CAROUSEL).ok())
+        {
+            // Only the first videos and playlists carousels are parsed - any further
+            // carousels of the same kind are items featuring the user, rather than the
+            // user's own content.
+            match UserCarouselKind::of(&carousel) {
+                UserCarouselKind::Videos if videos.is_none() => {
+                    all_videos_params = carousel
                         .take_value_pointer(concatcp!(CAROUSEL_TITLE, NAVIGATION_BROWSE, "/params"))
-                        .ok()
-                });
-        let playlists = match maybe_playlists_carousel {
-            Some(playlists_carousel) => playlists_carousel
-                .borrow_pointer("/contents")?
-                .try_into_iter()?
-                .map(parse_user_playlist)
-                .collect::<Result<_>>()?,
-            None => vec![],
-        };
-        let mut maybe_videos_carousel = carousels.get_mut("Videos");
-        let all_videos_params = maybe_videos_carousel.as_mut().and_then(|videos_carousel| {
-            videos_carousel
-                .take_value_pointer(concatcp!(CAROUSEL_TITLE, NAVIGATION_BROWSE, "/params"))
-                .ok()
-        });
-        let videos = match maybe_videos_carousel {
-            Some(videos_carousel) => videos_carousel
-                .borrow_pointer("/contents")?
-                .try_into_iter()?
-                .map(parse_user_video)
-                .collect::<Result<_>>()?,
-            None => vec![],
-        };
-
+                        .ok();
+                    videos = Some(
+                        carousel
+                            .navigate_pointer("/contents")?
+                            .try_into_iter()?
+                            .map(parse_user_video)
+                            .collect::<Result<Vec<_>>>()?,
+                    );
+                }
+                UserCarouselKind::Playlists if playlists.is_none() => {
+                    all_playlists_params = carousel
+                        .take_value_pointer(concatcp!(CAROUSEL_TITLE, NAVIGATION_BROWSE, "/params"))
+                        .ok();
+                    playlists = Some(
+                        carousel
+                            .navigate_pointer("/contents")?
+                            .try_into_iter()?
+                            .map(parse_user_playlist)
+                            .collect::<Result<Vec<_>>>()?,
+                    );
+                }
+                UserCarouselKind::Albums => album_shelves.push(parse_user_album_shelf(carousel)?),
+                UserCarouselKind::Videos
+                | UserCarouselKind::Playlists
+                | UserCarouselKind::Other => (),
+            }
+        }
         Ok(Self {
             name,
             thumbnails,
             all_videos_params,
-            playlists,
-            videos,
+            playlists: playlists.unwrap_or_default(),
+            videos: videos.unwrap_or_default(),
             all_playlists_params,
+            album_shelves,
         })
     }
 }
-impl ParseFrom<GetUserPlaylistsQuery<'_>> for Vec<UserPlaylist> {
-    fn parse_from(p: super::ProcessedResult<GetUserPlaylistsQuery>) -> Result<Self> {
+impl<'a> ParseFromContinuable<GetUserPlaylistsQuery<'a>> for Vec<UserPlaylist> {
+    fn parse_from_continuable(
+        p: super::ProcessedResult<GetUserPlaylistsQuery<'a>>,
+    ) -> Result<(Self, Option<ContinuationParams<'static>>)> {
         let json_crawler: JsonCrawlerOwned = p.into();
-        let results = json_crawler
-            .navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, SECTION_LIST_ITEM, GRID_ITEMS))?
-            .try_into_iter()?
-            .map(parse_user_playlist)
-            .collect::<Result<_>>()?;
-        Ok(results)
+        let grid =
+            json_crawler.navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, SECTION_LIST_ITEM, GRID))?;
+        parse_user_grid(grid, parse_user_playlist)
+    }
+    fn parse_continuation(
+        p: super::ProcessedResult<GetContinuationsQuery<'_, GetUserPlaylistsQuery<'a>>>,
+    ) -> Result<(Self, Option<ContinuationParams<'static>>)> {
+        let json_crawler: JsonCrawlerOwned = p.into();
+        let grid = json_crawler.navigate_pointer(GRID_CONTINUATION)?;
+        parse_user_grid(grid, parse_user_playlist)
     }
 }
-impl ParseFrom<GetUserVideosQuery<'_>> for Vec<UserVideo> {
-    fn parse_from(p: super::ProcessedResult<GetUserVideosQuery>) -> Result<Self> {
+impl<'a> ParseFromContinuable<GetUserVideosQuery<'a>> for Vec<UserVideo> {
+    fn parse_from_continuable(
+        p: super::ProcessedResult<GetUserVideosQuery<'a>>,
+    ) -> Result<(Self, Option<ContinuationParams<'static>>)> {
         let json_crawler: JsonCrawlerOwned = p.into();
-        let results = json_crawler
-            .navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, SECTION_LIST_ITEM, GRID_ITEMS))?
-            .try_into_iter()?
-            .map(parse_user_video)
-            .collect::<Result<_>>()?;
-        Ok(results)
+        let grid =
+            json_crawler.navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, SECTION_LIST_ITEM, GRID))?;
+        parse_user_grid(grid, parse_user_video)
     }
+    fn parse_continuation(
+        p: super::ProcessedResult<GetContinuationsQuery<'_, GetUserVideosQuery<'a>>>,
+    ) -> Result<(Self, Option<ContinuationParams<'static>>)> {
+        let json_crawler: JsonCrawlerOwned = p.into();
+        let grid = json_crawler.navigate_pointer(GRID_CONTINUATION)?;
+        parse_user_grid(grid, parse_user_video)
+    }
+}
+fn parse_user_grid<T>(
+    mut grid: JsonCrawlerOwned,
+    parse_item: fn(JsonCrawlerOwned) -> Result<T>,
+) -> Result<(Vec<T>, Option<ContinuationParams<'static>>)> {
+    let continuation_params = grid.take_value_pointer(CONTINUATION_PARAMS).ok();
+    let items = grid
+        .navigate_pointer("/items")?
+        .try_into_iter()?
+        .map(parse_item)
+        .collect::<Result<_>>()?;
+    Ok((items, continuation_params))
+}
+fn parse_user_album_shelf(mut carousel: JsonCrawlerOwned) -> Result<UserAlbumShelf> {
+    let title = carousel.take_value_pointer(concatcp!(CAROUSEL_TITLE, "/text"))?;
+    let browse_id = carousel
+        .take_value_pointer(concatcp!(CAROUSEL_TITLE, NAVIGATION_BROWSE_ID))
+        .ok();
+    let params = carousel
+        .take_value_pointer(concatcp!(CAROUSEL_TITLE, NAVIGATION_BROWSE, "/params"))
+        .ok();
+    let results = carousel
+        .navigate_pointer("/contents")?
+        .try_into_iter()?
+        .map(|item| parse_album_from_mtrir(item.navigate_pointer(MTRIR)?))
+        .collect::<Result<_>>()?;
+    Ok(UserAlbumShelf {
+        title,
+        results,
+        browse_id,
+        params,
+    })
 }
 fn parse_user_video(c: impl JsonCrawler) -> Result<UserVideo> {
     let mut item = c.navigate_pointer(MTRIR)?;
@@ -161,6 +266,35 @@ mod tests {
         );
     }
     #[tokio::test]
+    async fn test_get_user_artist_channel() {
+        // No capture of an artist owned channel exists, so one is spliced together
+        // from captures: the albums and singles shelves from an artist page are
+        // inserted into a user page, as both pages use the same carousel renderer.
+        const SECTIONS: &str = "/contents/singleColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents";
+        let user = std::fs::read_to_string("./test_json/get_user_20250707.json").unwrap();
+        let artist = std::fs::read_to_string("./test_json/get_artist_20250310.json").unwrap();
+        let mut json: serde_json::Value = serde_json::from_str(&user).unwrap();
+        let artist: serde_json::Value = serde_json::from_str(&artist).unwrap();
+        let album_shelves = artist
+            .pointer(SECTIONS)
+            .and_then(serde_json::Value::as_array)
+            .unwrap()[1..3]
+            .to_vec();
+        json.pointer_mut(SECTIONS)
+            .and_then(serde_json::Value::as_array_mut)
+            .unwrap()
+            .splice(1..1, album_shelves);
+        let expected =
+            std::fs::read_to_string("./test_json/get_user_artist_channel_spliced_output.txt")
+                .unwrap();
+        let output = crate::process_json::<_, BrowserToken>(
+            json.to_string(),
+            crate::query::GetUserQuery::new(UserChannelID::from_raw("")),
+        )
+        .unwrap();
+        pretty_assertions::assert_eq!(expected.trim(), format!("{output:#?}"));
+    }
+    #[tokio::test]
     async fn test_get_user_playlists() {
         parse_test!(
             "./test_json/get_user_playlists_20250707.json",
@@ -184,4 +318,28 @@ mod tests {
             BrowserToken
         );
     }
+    #[tokio::test]
+    async fn test_get_user_playlists_continuation() {
+        parse_continuations_test!(
+            "./test_json/get_user_playlists_continuation_mock.json",
+            "./test_json/get_user_playlists_continuation_mock_output.txt",
+            crate::query::GetUserPlaylistsQuery::new(
+                UserChannelID::from_raw(""),
+                UserPlaylistsParams::from_raw("")
+            ),
+            BrowserToken
+        );
+    }
+    #[tokio::test]
+    async fn test_get_user_videos_continuation() {
+        parse_continuations_test!(
+            "./test_json/get_user_videos_continuation_mock.json",
+            "./test_json/get_user_videos_continuation_mock_output.txt",
+            crate::query::GetUserVideosQuery::new(
+                UserChannelID::from_raw(""),
+                UserVideosParams::from_raw("")
+            ),
+            BrowserToken
+        );
+    }
 }
@@ -0,0 +1,630 @@
+//! Sync a playlist to a desired ordered list of videos, using the minimum
+//! number of add, remove and move actions.
+//! # Usage
+//! ```no_run
+//! use ytmapi_rs::common::{PlaylistID, VideoID, YoutubeID};
+//! # async {
+//! let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE").await?;
+//! let playlist_id = PlaylistID::from_raw("PLAYLIST_ID");
+//! let desired = [VideoID::from_raw("VIDEO_1"), VideoID::from_raw("VIDEO_2")];
+//! // Dry run - show the plan without changing the playlist.
+//! let plan = yt.plan_playlist_sync(playlist_id, desired).await?;
+//! println!("{:#?}", plan.steps());
+//! let report = yt.execute_playlist_sync(&plan).await;
+//! assert!(report.is_success());
+//! # Ok::<(), ytmapi_rs::Error>(())
+//! # };
+//! ```
+use crate::auth::LoggedIn;
+use crate::common::{ApiOutcome, PlaylistID, SetVideoID, VideoID, YoutubeID};
+use crate::parse::{AddPlaylistVideoResult, PlaylistItem};
+use crate::query::playlist::DuplicateHandlingMode;
+use crate::query::{
+    AddPlaylistItemsQuery, EditPlaylistQuery, GetPlaylistTracksQuery, RemovePlaylistItemsQuery,
+};
+use crate::{Error, Result, YtMusic};
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Maximum number of actions sent to Innertube in a single request.
+const SYNC_BATCH_SIZE: usize = 50;
+
+/// A single action required to sync a playlist.
+/// Positions refer to the index of the item in the desired list of videos.
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+pub enum PlaylistSyncStep<'a> {
+    /// Remove an existing entry from the playlist.
+    Remove {
+        video_id: VideoID<'a>,
+        set_video_id: SetVideoID<'a>,
+    },
+    /// Add a video to the end of the playlist.
+    Add {
+        video_id: VideoID<'a>,
+        position: usize,
+    },
+    /// Move the entry at `position` so that it is directly before the entry at
+    /// `before`, or to the end of the playlist if `before` is None.
+    Move {
+        video_id: VideoID<'a>,
+        position: usize,
+        before: Option<usize>,
+    },
+}
+
+/// Plan of actions required to sync a playlist. Create using
+/// [`YtMusic::plan_playlist_sync`], and execute using
+/// [`YtMusic::execute_playlist_sync`].
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+pub struct PlaylistSyncPlan<'a> {
+    playlist_id: PlaylistID<'a>,
+    /// SetVideoIDs of the existing playlist entries that will be kept, indexed
+    /// by position. None if the entry will be added during the sync.
+    kept: Vec<Option<SetVideoID<'a>>>,
+    steps: Vec<PlaylistSyncStep<'a>>,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum PlaylistSyncOutcome {
+    Success,
+    Failure,
+    /// The step was not attempted, as an earlier step failed.
+    Skipped,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+pub struct PlaylistSyncStepResult<'a> {
+    pub step: PlaylistSyncStep<'a>,
+    pub outcome: PlaylistSyncOutcome,
+}
+
+/// Outcome of each step of an executed PlaylistSyncPlan.
+#[derive(Debug)]
+pub struct PlaylistSyncReport<'a> {
+    pub results: Vec<PlaylistSyncStepResult<'a>>,
+    /// The error that caused the sync to stop, if there was one.
+    pub error: Option<Error>,
+}
+
+impl<'a> PlaylistSyncPlan<'a> {
+    /// Compute the plan to sync the current items of a playlist to the desired
+    /// list of videos.
+    /// Duplicate videos are matched to existing entries in order, so existing
+    /// duplicates are kept where possible.
+    /// # Errors
+    /// Returns an error if an existing entry needs to be removed or moved, or
+    /// has an entry moved before it, but has no SetVideoID.
+    pub fn new(
+        playlist_id: PlaylistID<'a>,
+        current: &'a [PlaylistItem],
+        desired: impl IntoIterator<Item = VideoID<'a>>,
+    ) -> Result<Self> {
+        let current = current
+            .iter()
+            .map(|item| (item.video_id(), item.set_video_id().map(Into::into)))
+            .collect();
+        Self::new_from_entries(playlist_id, current, desired.into_iter().collect())
+    }
+    fn new_from_entries(
+        playlist_id: PlaylistID<'a>,
+        current: Vec<(VideoID<'a>, Option<SetVideoID<'a>>)>,
+        desired: Vec<VideoID<'a>>,
+    ) -> Result<Self> {
+        // Queue of indexes into current for each video, so that duplicates are
+        // matched in order.
+        let mut unmatched: HashMap<&VideoID, VecDeque<usize>> = HashMap::new();
+        for (idx, (video_id, _)) in current.iter().enumerate() {
+            unmatched.entry(video_id).or_default().push_back(idx);
+        }
+        let matches: Vec<Option<usize>> = desired
+            .iter()
+            .map(|video_id| unmatched.get_mut(video_id).and_then(VecDeque::pop_front))
+            .collect();
+        let matched: HashSet<usize> = matches.iter().flatten().copied().collect();
+        let mut steps = Vec::new();
+        for (idx, (video_id, set_video_id)) in current.iter().enumerate() {
+            if matched.contains(&idx) {
+                continue;
+            }
+            steps.push(PlaylistSyncStep::Remove {
+                video_id: video_id.clone(),
+                set_video_id: set_video_id
+                    .clone()
+                    .ok_or_else(|| Error::playlist_item_not_editable(video_id.get_raw()))?,
+            });
+        }
+        // Added videos are appended in order, so they are ordered after all
+        // existing entries.
+        let mut next_added_key = current.len();
+        let keys: Vec<usize> = matches
+            .iter()
+            .map(|m| {
+                m.unwrap_or_else(|| {
+                    next_added_key += 1;
+                    next_added_key
+                })
+            })
+            .collect();
+        for (position, video_id) in desired.iter().enumerate() {
+            if matches[position].is_none() {
+                steps.push(PlaylistSyncStep::Add {
+                    video_id: video_id.clone(),
+                    position,
+                });
+            }
+        }
+        // Entries that are already in the correct relative order don't need to
+        // move. Working backwards, every other entry is moved directly before
+        // its desired successor.
+        let in_order = longest_increasing_subsequence(&keys);
+        for position in (0..desired.len()).rev() {
+            if in_order.contains(&position) {
+                continue;
+            }
+            let before = Some(position + 1).filter(|p| *p < desired.len());
+            // Both the moved entry and the entry it's moved before must have a
+            // SetVideoID - checked here so that no requests are sent for a plan
+            // that can't be completed. Added entries receive a SetVideoID when
+            // they are added.
+            for current_idx in std::iter::once(position)
+                .chain(before)
+                .filter_map(|p| matches[p])
+            {
+                if current[current_idx].1.is_none() {
+                    return Err(Error::playlist_item_not_editable(
+                        current[current_idx].0.get_raw(),
+                    ));
+                }
+            }
+            steps.push(PlaylistSyncStep::Move {
+                video_id: desired[position].clone(),
+                position,
+                before,
+            });
+        }
+        let kept = matches
+            .into_iter()
+            .map(|m| m.and_then(|idx| current[idx].1.clone()))
+            .collect();
+        Ok(Self {
+            playlist_id,
+            kept,
+            steps,
+        })
+    }
+    pub fn playlist_id(&self) -> &PlaylistID<'a> {
+        &self.playlist_id
+    }
+    pub fn steps(&self) -> &[PlaylistSyncStep<'a>] {
+        &self.steps
+    }
+    /// Returns true if the playlist is already in sync.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl PlaylistSyncReport<'_> {
+    /// Returns true if every step succeeded.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+            && self
+                .results
+                .iter()
+                .all(|r| r.outcome == PlaylistSyncOutcome::Success)
+    }
+}
+
+/// Returns the indexes of a longest strictly increasing subsequence of keys.
+fn longest_increasing_subsequence(keys: &[usize]) -> HashSet<usize> {
+    // tails[len] is the index of the smallest tail of an increasing subsequence
+    // of length len + 1.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev: Vec<Option<usize>> = vec![None; keys.len()];
+    for (idx, key) in keys.iter().enumerate() {
+        let len = tails.partition_point(|tail| keys[*tail] < *key);
+        if len > 0 {
+            prev[idx] = Some(tails[len - 1]);
+        }
+        if len == tails.len() {
+            tails.push(idx);
+        } else {
+            tails[len] = idx;
+        }
+    }
+    std::iter::successors(tails.last().copied(), |idx| prev[*idx]).collect()
+}
+
+/// Fetch the current tracks in a playlist and compute a PlaylistSyncPlan.
+pub(crate) async fn plan_playlist_sync<'a, A: LoggedIn>(
+    yt: &YtMusic<A>,
+    playlist_id: PlaylistID<'a>,
+    desired: impl IntoIterator<Item = VideoID<'a>>,
+) -> Result<PlaylistSyncPlan<'a>> {
+    // Browsing a playlist requires the VL prefix, but editing requires it be
+    // removed.
+    let playlist_id = match playlist_id.get_raw().strip_prefix("VL") {
+        Some(stripped) => PlaylistID::from_raw(stripped.to_string()),
+        None => playlist_id,
+    };
+    let browse_id = PlaylistID::from_raw(format!("VL{}", playlist_id.get_raw()));
+    let query = GetPlaylistTracksQuery::new(browse_id);
+    let current: Vec<PlaylistItem> = yt.stream(&query).try_concat().await?;
+    let current = current
+        .iter()
+        .map(|item| {
+            (
+                VideoID::from_raw(item.video_id().get_raw().to_string()),
+                item.set_video_id().cloned(),
+            )
+        })
+        .collect();
+    PlaylistSyncPlan::new_from_entries(playlist_id, current, desired.into_iter().collect())
+}
+
+/// Execute a PlaylistSyncPlan, in batches of removals, then additions, then
+/// moves.
+/// If a batch fails, the remaining steps are skipped.
+pub(crate) async fn execute_playlist_sync<'a, A: LoggedIn>(
+    yt: &YtMusic<A>,
+    plan: &PlaylistSyncPlan<'a>,
+) -> PlaylistSyncReport<'a> {
+    let mut outcomes = vec![PlaylistSyncOutcome::Skipped; plan.steps.len()];
+    let error = execute_playlist_sync_steps(yt, plan, &mut outcomes)
+        .await
+        .err();
+    let results = plan
+        .steps
+        .iter()
+        .cloned()
+        .zip(outcomes)
+        .map(|(step, outcome)| PlaylistSyncStepResult { step, outcome })
+        .collect();
+    PlaylistSyncReport { results, error }
+}
+
+async fn execute_playlist_sync_steps<A: LoggedIn>(
+    yt: &YtMusic<A>,
+    plan: &PlaylistSyncPlan<'_>,
+    outcomes: &mut [PlaylistSyncOutcome],
+) -> Result<()> {
+    let playlist_id = &plan.playlist_id;
+    let mut set_video_ids = plan.kept.clone();
+    let (removals, rest): (Vec<_>, Vec<_>) = plan
+        .steps
+        .iter()
+        .enumerate()
+        .partition(|(_, step)| matches!(step, PlaylistSyncStep::Remove { .. }));
+    let (additions, moves): (Vec<_>, Vec<_>) = rest
+        .into_iter()
+        .partition(|(_, step)| matches!(step, PlaylistSyncStep::Add { .. }));
+    for batch in removals.chunks(SYNC_BATCH_SIZE) {
+        let set_video_ids = batch.iter().filter_map(|(_, step)| match step {
+            PlaylistSyncStep::Remove { set_video_id, .. } => Some(set_video_id.into()),
+            _ => None,
+        });
+        let query = RemovePlaylistItemsQuery::new(playlist_id.into(), set_video_ids);
+        yt.query(query)
+            .await
+            .inspect_err(|_| set_batch_outcome(outcomes, batch, PlaylistSyncOutcome::Failure))?;
+        set_batch_outcome(outcomes, batch, PlaylistSyncOutcome::Success);
+    }
+    for batch in additions.chunks(SYNC_BATCH_SIZE) {
+        let (positions, video_ids): (Vec<_>, Vec<_>) = batch
+            .iter()
+            .filter_map(|(_, step)| match step {
+                PlaylistSyncStep::Add { video_id, position } => {
+                    Some((*position, VideoID::from(video_id)))
+                }
+                _ => None,
+            })
+            .unzip();
+        // The plan deliberately keeps duplicates, so they must be added rather
+        // than rejected or dropped.
+        let query = AddPlaylistItemsQuery::new_from_videos(
+            playlist_id.into(),
+            video_ids,
+            DuplicateHandlingMode::Unhandled,
+        );
+        let added = yt
+            .query(query)
+            .await
+            .inspect_err(|_| set_batch_outcome(outcomes, batch, PlaylistSyncOutcome::Failure))?
+            .into_iter()
+            .filter_map(|item| match item {
+                AddPlaylistVideoResult::Added(item) => Some(item.set_video_id),
+                AddPlaylistVideoResult::NotAdded(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if added.len() != positions.len() {
+            set_batch_outcome(outcomes, batch, PlaylistSyncOutcome::Failure);
+            return Err(Error::playlist_sync_add_mismatch(
+                positions.len(),
+                added.len(),
+            ));
+        }
+        for (position, set_video_id) in positions.into_iter().zip(added) {
+            set_video_ids[position] = Some(set_video_id);
+        }
+        set_batch_outcome(outcomes, batch, PlaylistSyncOutcome::Success);
+    }
+    let resolve = |position: usize| {
+        set_video_ids[position]
+            .as_ref()
+            .map(SetVideoID::from)
+            .ok_or_else(|| Error::playlist_item_not_editable(position.to_string()))
+    };
+    for batch in moves.chunks(SYNC_BATCH_SIZE) {
+        let moved = batch
+            .iter()
+            .filter_map(|(_, step)| match step {
+                PlaylistSyncStep::Move {
+                    position, before, ..
+                } => Some((*position, *before)),
+                _ => None,
+            })
+            .map(|(position, before)| Ok((resolve(position)?, before.map(resolve).transpose()?)))
+            .collect::<Result<Vec<_>>>()?;
+        let query = EditPlaylistQuery::move_videos(playlist_id, moved);
+        let outcome = yt
+            .query(query)
+            .await
+            .inspect_err(|_| set_batch_outcome(outcomes, batch, PlaylistSyncOutcome::Failure))?;
+        if let ApiOutcome::Failure = outcome {
+            set_batch_outcome(outcomes, batch, PlaylistSyncOutcome::Failure);
+            return Err(Error::status_failed());
+        }
+        set_batch_outcome(outcomes, batch, PlaylistSyncOutcome::Success);
+    }
+    Ok(())
+}
+
+fn set_batch_outcome(
+    outcomes: &mut [PlaylistSyncOutcome],
+    batch: &[(usize, &PlaylistSyncStep)],
+    outcome: PlaylistSyncOutcome,
+) {
+    for (idx, _) in batch {
+        outcomes[*idx] = outcome;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PlaylistSyncOutcome, PlaylistSyncPlan, PlaylistSyncStep, execute_playlist_sync};
+    use crate::auth::BrowserToken;
+    use crate::common::{PlaylistID, SetVideoID, VideoID, YoutubeID};
+    use crate::error::ErrorKind;
+    use crate::{YtMusic, YtMusicBuilder};
+    use pretty_assertions::assert_eq;
+    use ytmapi_mock_server::{MockServer, Route, Routes};
+
+    const MOCK_COOKIE: &str = "SAPISID=ytmapi-mock-server; __Secure-3PAPISID=ytmapi-mock-server;";
+
+    fn entries(videos: &[&str]) -> Vec<(VideoID<'static>, Option<SetVideoID<'static>>)> {
+        videos
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| {
+                (
+                    VideoID::from_raw(v.to_string()),
+                    Some(SetVideoID::from_raw(format!("{v}{idx}"))),
+                )
+            })
+            .collect()
+    }
+    fn videos(videos: &[&str]) -> Vec<VideoID<'static>> {
+        videos
+            .iter()
+            .map(|v| VideoID::from_raw(v.to_string()))
+            .collect()
+    }
+    fn plan(current: &[&str], desired: &[&str]) -> PlaylistSyncPlan<'static> {
+        PlaylistSyncPlan::new_from_entries(
+            PlaylistID::from_raw(""),
+            entries(current),
+            videos(desired),
+        )
+        .unwrap()
+    }
+    /// Apply the plan to the current playlist, the same way Innertube would,
+    /// returning the resulting list of videos.
+    fn simulate(current: &[&str], plan: &PlaylistSyncPlan) -> Vec<String> {
+        // (video, position in desired list)
+        let mut playlist: Vec<(String, Option<usize>)> = entries(current)
+            .into_iter()
+            .map(|(v, s)| {
+                let position = plan.kept.iter().position(|k| k == &s);
+                (v.get_raw().to_string(), position)
+            })
+            .collect();
+        for step in plan.steps() {
+            match step {
+                PlaylistSyncStep::Remove { set_video_id, .. } => {
+                    let idx = entries(current)
+                        .iter()
+                        .find(|(_, s)| s.as_ref() == Some(set_video_id))
+                        .map(|(v, _)| v.get_raw().to_string())
+                        .and_then(|v| {
+                            playlist
+                                .iter()
+                                .position(|(pv, pos)| pv == &v && pos.is_none())
+                        })
+                        .unwrap();
+                    playlist.remove(idx);
+                }
+                PlaylistSyncStep::Add { video_id, position } => {
+                    playlist.push((video_id.get_raw().to_string(), Some(*position)))
+                }
+                PlaylistSyncStep::Move {
+                    position, before, ..
+                } => {
+                    let idx = playlist
+                        .iter()
+                        .position(|(_, p)| p == &Some(*position))
+                        .unwrap();
+                    let moved = playlist.remove(idx);
+                    match before {
+                        Some(before) => {
+                            let idx = playlist
+                                .iter()
+                                .position(|(_, p)| p == &Some(*before))
+                                .unwrap();
+                            playlist.insert(idx, moved);
+                        }
+                        None => playlist.push(moved),
+                    }
+                }
+            }
+        }
+        playlist.into_iter().map(|(v, _)| v).collect()
+    }
+    fn assert_syncs(current: &[&str], desired: &[&str]) -> PlaylistSyncPlan<'static> {
+        let plan = plan(current, desired);
+        assert_eq!(simulate(current, &plan), desired);
+        plan
+    }
+
+    #[test]
+    fn test_sync_no_changes() {
+        let plan = assert_syncs(&["a", "b", "c"], &["a", "b", "c"]);
+        assert!(plan.is_empty());
+    }
+    #[test]
+    fn test_sync_add_and_remove() {
+        let plan = assert_syncs(&["a", "b", "c"], &["a", "c", "d"]);
+        assert_eq!(
+            plan.steps(),
+            [
+                PlaylistSyncStep::Remove {
+                    video_id: VideoID::from_raw("b"),
+                    set_video_id: SetVideoID::from_raw("b1"),
+                },
+                PlaylistSyncStep::Add {
+                    video_id: VideoID::from_raw("d"),
+                    position: 2,
+                },
+            ]
+        );
+    }
+    #[test]
+    fn test_sync_minimal_moves() {
+        let plan = assert_syncs(&["a", "b", "c", "d"], &["d", "a", "b", "c"]);
+        assert_eq!(
+            plan.steps(),
+            [PlaylistSyncStep::Move {
+                video_id: VideoID::from_raw("d"),
+                position: 0,
+                before: Some(1),
+            }]
+        );
+    }
+    #[test]
+    fn test_sync_reverse() {
+        let plan = assert_syncs(&["a", "b", "c", "d"], &["d", "c", "b", "a"]);
+        assert_eq!(plan.steps().len(), 3);
+    }
+    #[test]
+    fn test_sync_added_item_moved() {
+        assert_syncs(&["a", "b"], &["c", "b", "a", "d"]);
+    }
+    #[test]
+    fn test_sync_duplicates() {
+        let plan = assert_syncs(&["a", "b", "a", "c"], &["a", "c", "a"]);
+        assert_eq!(
+            plan.steps()[0],
+            PlaylistSyncStep::Remove {
+                video_id: VideoID::from_raw("b"),
+                set_video_id: SetVideoID::from_raw("b1"),
+            },
+        );
+        assert_syncs(&["a", "a"], &["a", "b", "a", "a"]);
+        assert_syncs(&["a", "b", "a"], &["b"]);
+    }
+    #[test]
+    fn test_sync_missing_set_video_id() {
+        let plan = PlaylistSyncPlan::new_from_entries(
+            PlaylistID::from_raw(""),
+            vec![(VideoID::from_raw("a"), None)],
+            vec![],
+        );
+        assert!(plan.is_err());
+    }
+    #[test]
+    fn test_sync_missing_set_video_id_move_target() {
+        // "b" stays in place, but "a" must be moved before it.
+        let mut current = entries(&["b", "a"]);
+        current[0].1 = None;
+        let plan = PlaylistSyncPlan::new_from_entries(
+            PlaylistID::from_raw(""),
+            current,
+            videos(&["a", "b"]),
+        );
+        let Err(e) = plan else {
+            panic!("Expected plan to be rejected");
+        };
+        assert!(matches!(
+            e.into_kind(),
+            ErrorKind::PlaylistItemNotEditable { .. }
+        ));
+    }
+
+    /// Mock server that accepts removals, and responds to additions that don't
+    /// reject duplicates with a single added video.
+    async fn edit_playlist_server() -> MockServer {
+        let routes = Routes::new()
+            .with_route(
+                Route::post("browse/edit_playlist")
+                    .with_body_field("/actions/0/action", "ACTION_REMOVE_VIDEO")
+                    .respond_with_file("test_json/edit_playlist_title_20240626.json"),
+            )
+            .with_route(
+                Route::post("browse/edit_playlist")
+                    .with_body_field("/actions/0/dedupeOption", "DEDUPE_OPTION_SKIP")
+                    .respond_with_file("test_json/add_playlist_items_20240626.json"),
+            );
+        MockServer::start(routes).await.unwrap()
+    }
+    async fn mock_client(server: &MockServer) -> YtMusic<BrowserToken> {
+        YtMusicBuilder::new()
+            .with_base_url(server.base_url())
+            .with_browser_token_cookie(MOCK_COOKIE.to_string())
+            .build()
+            .await
+            .unwrap()
+    }
+    #[tokio::test]
+    async fn test_execute_sync_adds_duplicates() {
+        let server = edit_playlist_server().await;
+        let yt = mock_client(&server).await;
+        let plan = plan(&["AWCVHllzG9Y", "b"], &["AWCVHllzG9Y", "AWCVHllzG9Y"]);
+        let report = execute_playlist_sync(&yt, &plan).await;
+        assert!(report.error.is_none(), "{:?}", report.error);
+        let outcomes: Vec<_> = report.results.iter().map(|r| r.outcome).collect();
+        assert_eq!(
+            outcomes,
+            vec![PlaylistSyncOutcome::Success, PlaylistSyncOutcome::Success]
+        );
+        assert!(report.is_success());
+    }
+    #[tokio::test]
+    async fn test_execute_sync_add_mismatch() {
+        let server = edit_playlist_server().await;
+        let yt = mock_client(&server).await;
+        // The mock only reports one video as added.
+        let plan = plan(&[], &["AWCVHllzG9Y", "b"]);
+        let report = execute_playlist_sync(&yt, &plan).await;
+        let outcomes: Vec<_> = report.results.iter().map(|r| r.outcome).collect();
+        assert_eq!(
+            outcomes,
+            vec![PlaylistSyncOutcome::Failure, PlaylistSyncOutcome::Failure]
+        );
+        let Some(ErrorKind::PlaylistSyncAddMismatch { expected, added }) =
+            report.error.map(|e| e.into_kind())
+        else {
+            panic!("Expected PlaylistSyncAddMismatch error");
+        };
+        assert_eq!((expected, added), (2, 1));
+    }
+}
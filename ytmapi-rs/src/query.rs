@@ -32,6 +32,7 @@
 //! }
 //! ```
 use crate::auth::{AuthToken, raw_query_get, raw_query_post};
+use crate::client_profile::ClientProfile;
 use crate::parse::ParseFrom;
 use crate::{RawResult, Result};
 use private::Sealed;
@@ -70,7 +71,9 @@ pub use playlist::{
 pub mod podcasts;
 #[doc(inline)]
 pub use podcasts::{
-    GetChannelEpisodesQuery, GetChannelQuery, GetEpisodeQuery, GetNewEpisodesQuery, GetPodcastQuery,
+    GetChannelEpisodesQuery, GetChannelQuery, GetEpisodeQuery, GetNewEpisodesQuery,
+    GetPodcastQuery, MarkEpisodePlayedQuery, MarkEpisodeUnplayedQuery, SubscribePodcastQuery,
+    UnsubscribePodcastQuery,
 };
 pub mod rate;
 #[doc(inline)]
@@ -78,19 +81,22 @@ pub use rate::{RatePlaylistQuery, RateSongQuery};
 pub mod recommendations;
 #[doc(inline)]
 pub use recommendations::{
-    GetMoodCategoriesQuery, GetMoodPlaylistsQuery, GetTasteProfileQuery, SetTasteProfileQuery,
+    GetExploreQuery, GetListenAgainQuery, GetMoodCategoriesQuery, GetMoodPlaylistsQuery,
+    GetNewMusicVideosQuery, GetNewReleasesQuery, GetTasteProfileQuery, PinToListenAgainQuery,
+    SetTasteProfileQuery, UnpinFromListenAgainQuery,
 };
 pub mod search;
 #[doc(inline)]
-pub use search::{GetSearchSuggestionsQuery, SearchQuery};
+pub use search::{GetSearchSuggestionsQuery, RemoveSearchSuggestionsQuery, SearchQuery};
 pub mod song;
 #[doc(inline)]
-pub use song::{GetLyricsIDQuery, GetLyricsQuery, GetSongTrackingUrlQuery};
+pub use song::{GetLyricsIDQuery, GetLyricsQuery, GetSongQuery, GetSongTrackingUrlQuery};
 pub mod upload;
 #[doc(inline)]
 pub use upload::{
-    DeleteUploadEntityQuery, GetLibraryUploadAlbumQuery, GetLibraryUploadAlbumsQuery,
-    GetLibraryUploadArtistQuery, GetLibraryUploadArtistsQuery, GetLibraryUploadSongsQuery,
+    DeleteUploadEntityQuery, EditUploadSongQuery, GetLibraryUploadAlbumQuery,
+    GetLibraryUploadAlbumsQuery, GetLibraryUploadArtistQuery, GetLibraryUploadArtistsQuery,
+    GetLibraryUploadSongsQuery,
 };
 pub mod user;
 #[doc(inline)]
@@ -113,6 +119,11 @@ pub trait PostQuery {
     fn header(&self) -> serde_json::Map<String, serde_json::Value>;
     fn params(&self) -> Vec<(&str, Cow<'_, str>)>;
     fn path(&self) -> &str;
+    /// The Innertube client profile this query must be sent as. If None, the
+    /// client's default profile is used.
+    fn client_profile(&self) -> Option<ClientProfile> {
+        None
+    }
 }
 /// Represents a plain GET query that can be sent to Innertube.
 pub trait GetQuery {
@@ -142,6 +153,20 @@ where
     ) -> Result<RawResult<'a, Q, A>>;
 }
 
+/// A [`QueryMethod`] that returns a `Send` future, implemented when the query
+/// and auth token are `Sync`. Required to run a query as a `tower::Service`.
+#[cfg(feature = "tower")]
+pub trait SendQueryMethod<Q, A>: QueryMethod<Q, A>
+where
+    A: AuthToken,
+{
+    fn call_send<'a>(
+        query: &'a Q,
+        client: &'a crate::client::Client,
+        tok: &'a A,
+    ) -> impl Future<Output = Result<RawResult<'a, Q, A>>> + Send;
+}
+
 impl Sealed for GetMethod {}
 impl<Q, A> QueryMethod<Q, A> for GetMethod
 where
@@ -159,6 +184,20 @@ where
         raw_query_get(tok, client, query)
     }
 }
+#[cfg(feature = "tower")]
+impl<Q, A> SendQueryMethod<Q, A> for GetMethod
+where
+    Q: GetQuery + Sync,
+    A: AuthToken + Sync,
+{
+    fn call_send<'a>(
+        query: &'a Q,
+        client: &'a crate::client::Client,
+        tok: &'a A,
+    ) -> impl Future<Output = Result<RawResult<'a, Q, A>>> + Send {
+        raw_query_get(tok, client, query)
+    }
+}
 
 impl Sealed for PostMethod {}
 impl<Q, A> QueryMethod<Q, A> for PostMethod
@@ -177,3 +216,17 @@ where
         raw_query_post(query, tok, client)
     }
 }
+#[cfg(feature = "tower")]
+impl<Q, A> SendQueryMethod<Q, A> for PostMethod
+where
+    Q: PostQuery + Sync,
+    A: AuthToken + Sync,
+{
+    fn call_send<'a>(
+        query: &'a Q,
+        client: &'a crate::client::Client,
+        tok: &'a A,
+    ) -> impl Future<Output = Result<RawResult<'a, Q, A>>> + Send {
+        raw_query_post(query, tok, client)
+    }
+}
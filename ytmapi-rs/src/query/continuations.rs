@@ -1,6 +1,7 @@
 use super::{PostMethod, PostQuery, Query};
 use crate::ProcessedResult;
 use crate::auth::AuthToken;
+use crate::client_profile::ClientProfile;
 use crate::common::{ContinuationParams, YoutubeID};
 use crate::continuations::ParseFromContinuable;
 use std::borrow::Cow;
@@ -24,6 +25,15 @@ impl<'a, Q> GetContinuationsQuery<'a, Q> {
     }
 }
 impl<'a, Q> GetContinuationsQuery<'a, Q> {
+    pub(crate) fn new(query: &'a Q, continuation_params: ContinuationParams<'static>) -> Self {
+        GetContinuationsQuery {
+            query,
+            continuation_params,
+        }
+    }
+    pub(crate) fn into_continuation_params(self) -> ContinuationParams<'static> {
+        self.continuation_params
+    }
     pub fn from_first_result<T: ParseFromContinuable<Q>>(
         res: ProcessedResult<'a, Q>,
     ) -> crate::Result<(T, Option<GetContinuationsQuery<'a, Q>>)> {
@@ -100,4 +110,7 @@ where
     fn path(&self) -> &str {
         self.query.path()
     }
+    fn client_profile(&self) -> Option<ClientProfile> {
+        self.query.client_profile()
+    }
 }
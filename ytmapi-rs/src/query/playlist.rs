@@ -26,6 +26,7 @@ pub trait GetWatchPlaylistQueryID {
 
 //TODO: Likely Common
 #[derive(Default, PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum PrivacyStatus {
     Public,
     #[default]
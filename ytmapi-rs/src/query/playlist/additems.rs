@@ -1,15 +1,20 @@
 use super::SpecialisedQuery;
 use crate::auth::AuthToken;
 use crate::common::{PlaylistID, VideoID};
-use crate::parse::AddPlaylistItem;
+use crate::parse::{AddPlaylistItem, AddPlaylistVideoResult};
 use crate::query::{PostMethod, PostQuery, Query};
 use serde_json::json;
 use std::borrow::Cow;
 
+/// How YouTube Music should treat videos that are already in the playlist.
 #[derive(Default, Debug, Clone, PartialEq)]
 pub enum DuplicateHandlingMode {
+    /// If any video is already in the playlist, nothing is added and
+    /// `ErrorKind::DuplicatePlaylistItems` is returned.
     #[default]
     ReturnError,
+    /// Duplicate checking is not performed, videos already in the playlist are
+    /// added again.
     Unhandled,
 }
 
@@ -85,9 +90,20 @@ impl<'a> AddPlaylistItemsQuery<'a, AddVideosToPlaylist<'a>> {
             },
         }
     }
+    /// The videos requested to be added, in order.
+    pub(crate) fn video_ids(&self) -> &[VideoID<'a>] {
+        &self.query_type.video_ids
+    }
+    pub(crate) fn duplicate_handling_mode(&self) -> &DuplicateHandlingMode {
+        &self.query_type.duplicate_handling_mode
+    }
 }
 
-impl<A: AuthToken, T: SpecialisedQuery> Query<A> for AddPlaylistItemsQuery<'_, T> {
+impl<'a, A: AuthToken> Query<A> for AddPlaylistItemsQuery<'a, AddVideosToPlaylist<'a>> {
+    type Output = Vec<AddPlaylistVideoResult>;
+    type Method = PostMethod;
+}
+impl<'a, A: AuthToken> Query<A> for AddPlaylistItemsQuery<'a, AddPlaylistToPlaylist<'a>> {
     type Output = Vec<AddPlaylistItem>;
     type Method = PostMethod;
 }
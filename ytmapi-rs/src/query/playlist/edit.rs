@@ -12,6 +12,9 @@ pub struct EditPlaylistQuery<'a> {
     new_description: Option<Cow<'a, str>>,
     new_privacy_status: Option<PrivacyStatus>,
     swap_videos_order: Option<(SetVideoID<'a>, SetVideoID<'a>)>,
+    /// Videos to move, and the video to move them before. If no successor is
+    /// provided, the video is moved to the end of the playlist.
+    move_videos: Vec<(SetVideoID<'a>, Option<SetVideoID<'a>>)>,
     change_add_order: Option<AddOrder>,
     add_playlist: Option<PlaylistID<'a>>,
 }
@@ -32,6 +35,7 @@ impl<'a> EditPlaylistQuery<'a> {
             new_description: None,
             new_privacy_status: None,
             swap_videos_order: None,
+            move_videos: Vec::new(),
             change_add_order: None,
             add_playlist: None,
         }
@@ -47,6 +51,7 @@ impl<'a> EditPlaylistQuery<'a> {
             new_description: Some(new_description.into()),
             new_privacy_status: None,
             swap_videos_order: None,
+            move_videos: Vec::new(),
             change_add_order: None,
             add_playlist: None,
         }
@@ -62,6 +67,7 @@ impl<'a> EditPlaylistQuery<'a> {
             new_privacy_status: Some(new_privacy_status),
             new_description: None,
             swap_videos_order: None,
+            move_videos: Vec::new(),
             change_add_order: None,
             add_playlist: None,
         }
@@ -76,6 +82,25 @@ impl<'a> EditPlaylistQuery<'a> {
             id,
             new_title: None,
             swap_videos_order: Some((video_1, video_2)),
+            move_videos: Vec::new(),
+            new_privacy_status: None,
+            new_description: None,
+            change_add_order: None,
+            add_playlist: None,
+        }
+    }
+    /// Move each video before its successor, or to the end of the playlist if
+    /// no successor is provided. Moves are applied in the order provided.
+    pub fn move_videos<T: Into<PlaylistID<'a>>>(
+        id: T,
+        moves: impl IntoIterator<Item = (SetVideoID<'a>, Option<SetVideoID<'a>>)>,
+    ) -> Self {
+        let id = id.into();
+        Self {
+            id,
+            new_title: None,
+            move_videos: moves.into_iter().collect(),
+            swap_videos_order: None,
             new_privacy_status: None,
             new_description: None,
             change_add_order: None,
@@ -90,6 +115,7 @@ impl<'a> EditPlaylistQuery<'a> {
             change_add_order: Some(change_add_order),
             new_privacy_status: None,
             swap_videos_order: None,
+            move_videos: Vec::new(),
             new_description: None,
             add_playlist: None,
         }
@@ -102,6 +128,7 @@ impl<'a> EditPlaylistQuery<'a> {
             add_playlist: Some(add_playlist),
             new_privacy_status: None,
             swap_videos_order: None,
+            move_videos: Vec::new(),
             change_add_order: None,
             new_description: None,
         }
@@ -130,6 +157,13 @@ impl<'a> EditPlaylistQuery<'a> {
         self.swap_videos_order = Some((first_video, second_video));
         self
     }
+    pub fn with_move_videos(
+        mut self,
+        moves: impl IntoIterator<Item = (SetVideoID<'a>, Option<SetVideoID<'a>>)>,
+    ) -> Self {
+        self.move_videos.extend(moves);
+        self
+    }
 }
 
 impl<A: AuthToken> Query<A> for EditPlaylistQuery<'_> {
@@ -164,6 +198,16 @@ impl PostQuery for EditPlaylistQuery<'_> {
                 "movedSetVideoIdSuccessor" : video_2
             }))
         };
+        for (video, successor) in &self.move_videos {
+            let mut action = json!({
+                "action" : "ACTION_MOVE_VIDEO_BEFORE",
+                "setVideoId" : video,
+            });
+            if let Some(successor) = successor {
+                action["movedSetVideoIdSuccessor"] = json!(successor);
+            }
+            actions.push(action)
+        }
         if let Some(add_playlist) = &self.add_playlist {
             actions.push(json!({
                 "action" : "ACTION_ADD_PLAYLIST",
@@ -1,6 +1,9 @@
 use super::{PostMethod, PostQuery, Query};
-use crate::auth::AuthToken;
-use crate::common::{EpisodeID, PodcastChannelID, PodcastChannelParams, PodcastID};
+use crate::auth::{AuthToken, LoggedIn};
+use crate::common::{
+    ApiOutcome, EpisodeID, FeedbackTokenMarkPlayed, FeedbackTokenMarkUnplayed, PodcastChannelID,
+    PodcastChannelParams, PodcastID, YoutubeID,
+};
 use crate::parse::{Episode, GetEpisode, GetPodcast, GetPodcastChannel};
 use serde_json::json;
 
@@ -18,6 +21,24 @@ pub struct GetEpisodeQuery<'a> {
     episode_id: EpisodeID<'a>,
 }
 pub struct GetNewEpisodesQuery;
+/// Adds a podcast to your library.
+pub struct SubscribePodcastQuery<'a> {
+    podcast_id: PodcastID<'a>,
+}
+/// Removes a podcast from your library.
+pub struct UnsubscribePodcastQuery<'a> {
+    podcast_id: PodcastID<'a>,
+}
+/// Marks an episode as played. The feedback token can be obtained from
+/// `GetEpisode`.
+pub struct MarkEpisodePlayedQuery<'a> {
+    feedback_token: FeedbackTokenMarkPlayed<'a>,
+}
+/// Marks an episode as unplayed, resetting its resume position. The feedback
+/// token can be obtained from `GetEpisode`.
+pub struct MarkEpisodeUnplayedQuery<'a> {
+    feedback_token: FeedbackTokenMarkUnplayed<'a>,
+}
 
 // NOTE: This is technically the same page as the GetArtist page. It's possible
 // this could be generalised.
@@ -53,6 +74,34 @@ impl<'a> GetEpisodeQuery<'a> {
         }
     }
 }
+impl<'a> SubscribePodcastQuery<'a> {
+    pub fn new(podcast_id: impl Into<PodcastID<'a>>) -> Self {
+        Self {
+            podcast_id: podcast_id.into(),
+        }
+    }
+}
+impl<'a> UnsubscribePodcastQuery<'a> {
+    pub fn new(podcast_id: impl Into<PodcastID<'a>>) -> Self {
+        Self {
+            podcast_id: podcast_id.into(),
+        }
+    }
+}
+impl<'a> MarkEpisodePlayedQuery<'a> {
+    pub fn new(feedback_token: impl Into<FeedbackTokenMarkPlayed<'a>>) -> Self {
+        Self {
+            feedback_token: feedback_token.into(),
+        }
+    }
+}
+impl<'a> MarkEpisodeUnplayedQuery<'a> {
+    pub fn new(feedback_token: impl Into<FeedbackTokenMarkUnplayed<'a>>) -> Self {
+        Self {
+            feedback_token: feedback_token.into(),
+        }
+    }
+}
 
 impl<A: AuthToken> Query<A> for GetChannelQuery<'_> {
     type Output = GetPodcastChannel;
@@ -74,6 +123,22 @@ impl<A: AuthToken> Query<A> for GetNewEpisodesQuery {
     type Output = Vec<Episode>;
     type Method = PostMethod;
 }
+impl<A: LoggedIn> Query<A> for SubscribePodcastQuery<'_> {
+    type Output = ();
+    type Method = PostMethod;
+}
+impl<A: LoggedIn> Query<A> for UnsubscribePodcastQuery<'_> {
+    type Output = ();
+    type Method = PostMethod;
+}
+impl<A: LoggedIn> Query<A> for MarkEpisodePlayedQuery<'_> {
+    type Output = ApiOutcome;
+    type Method = PostMethod;
+}
+impl<A: LoggedIn> Query<A> for MarkEpisodeUnplayedQuery<'_> {
+    type Output = ApiOutcome;
+    type Method = PostMethod;
+}
 
 impl PostQuery for GetChannelQuery<'_> {
     fn header(&self) -> serde_json::Map<String, serde_json::Value> {
@@ -138,3 +203,60 @@ impl PostQuery for GetNewEpisodesQuery {
         "browse"
     }
 }
+// Subscribing to a podcast is the same as liking its underlying playlist.
+impl PostQuery for SubscribePodcastQuery<'_> {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        FromIterator::from_iter([(
+            "target".into(),
+            json!({"playlistId" : podcast_playlist_id(&self.podcast_id)}),
+        )])
+    }
+    fn params(&self) -> std::vec::Vec<(&str, std::borrow::Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "like/like"
+    }
+}
+impl PostQuery for UnsubscribePodcastQuery<'_> {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        FromIterator::from_iter([(
+            "target".into(),
+            json!({"playlistId" : podcast_playlist_id(&self.podcast_id)}),
+        )])
+    }
+    fn params(&self) -> std::vec::Vec<(&str, std::borrow::Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "like/removelike"
+    }
+}
+impl PostQuery for MarkEpisodePlayedQuery<'_> {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        FromIterator::from_iter([("feedbackTokens".into(), json!([self.feedback_token]))])
+    }
+    fn params(&self) -> std::vec::Vec<(&str, std::borrow::Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "feedback"
+    }
+}
+impl PostQuery for MarkEpisodeUnplayedQuery<'_> {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        FromIterator::from_iter([("feedbackTokens".into(), json!([self.feedback_token]))])
+    }
+    fn params(&self) -> std::vec::Vec<(&str, std::borrow::Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "feedback"
+    }
+}
+
+/// A PodcastID is the podcast's PlaylistID with an `MPSP` prefix.
+fn podcast_playlist_id<'a>(podcast_id: &'a PodcastID) -> &'a str {
+    let raw = podcast_id.get_raw();
+    raw.strip_prefix("MPSP").unwrap_or(raw)
+}
@@ -1,7 +1,13 @@
 use super::{PostMethod, PostQuery, Query};
-use crate::auth::AuthToken;
-use crate::common::{MoodCategoryParams, TasteToken};
-use crate::parse::{MoodCategorySection, MoodPlaylistCategory, TasteProfileArtist};
+use crate::auth::{AuthToken, LoggedIn};
+use crate::common::{
+    ApiOutcome, FeedbackTokenPinToListenAgain, FeedbackTokenUnpinFromListenAgain,
+    MoodCategoryParams, TasteToken,
+};
+use crate::parse::{
+    GetExplore, ListenAgainItem, MoodCategorySection, MoodPlaylistCategory, NewMusicVideo,
+    NewReleaseAlbum, TasteProfileArtist,
+};
 use serde_json::{Value, json};
 use std::borrow::Cow;
 
@@ -21,6 +27,39 @@ pub struct GetMoodPlaylistsQuery<'a> {
     params: MoodCategoryParams<'a>,
 }
 
+/// Get the Explore page - new releases, moods & genres and new music videos.
+#[derive(Clone)]
+pub struct GetExploreQuery;
+
+/// Get the full list of new albums & singles from the Explore page.
+#[derive(Clone)]
+pub struct GetNewReleasesQuery;
+
+/// Get the full list of new music videos from the Explore page.
+#[derive(Clone)]
+pub struct GetNewMusicVideosQuery;
+
+/// Get the "Listen again" shelf from the Home page.
+/// # NOTE
+/// Only "Listen again" is supported - pinning to "Speed dial" is not currently
+/// implemented.
+#[derive(Clone)]
+pub struct GetListenAgainQuery;
+
+/// Pin an item to "Listen again". The feedback token can be obtained from
+/// `ListenAgainItem`.
+#[derive(Clone)]
+pub struct PinToListenAgainQuery<'a> {
+    feedback_token: FeedbackTokenPinToListenAgain<'a>,
+}
+
+/// Unpin an item from "Listen again". The feedback token can be obtained from
+/// `ListenAgainItem`.
+#[derive(Clone)]
+pub struct UnpinFromListenAgainQuery<'a> {
+    feedback_token: FeedbackTokenUnpinFromListenAgain<'a>,
+}
+
 impl<'a> SetTasteProfileQuery<'a> {
     pub fn new(taste_tokens: impl IntoIterator<Item = TasteToken<'a>>) -> Self {
         let taste_tokens = taste_tokens.into_iter().collect();
@@ -34,6 +73,22 @@ impl<'a> GetMoodPlaylistsQuery<'a> {
     }
 }
 
+impl<'a> PinToListenAgainQuery<'a> {
+    pub fn new(feedback_token: impl Into<FeedbackTokenPinToListenAgain<'a>>) -> Self {
+        Self {
+            feedback_token: feedback_token.into(),
+        }
+    }
+}
+
+impl<'a> UnpinFromListenAgainQuery<'a> {
+    pub fn new(feedback_token: impl Into<FeedbackTokenUnpinFromListenAgain<'a>>) -> Self {
+        Self {
+            feedback_token: feedback_token.into(),
+        }
+    }
+}
+
 impl<A: AuthToken> Query<A> for GetTasteProfileQuery {
     type Output = Vec<TasteProfileArtist>;
     type Method = PostMethod;
@@ -120,3 +175,99 @@ impl PostQuery for GetMoodPlaylistsQuery<'_> {
         "browse"
     }
 }
+
+impl<A: AuthToken> Query<A> for GetExploreQuery {
+    type Output = GetExplore;
+    type Method = PostMethod;
+}
+impl PostQuery for GetExploreQuery {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        serde_json::Map::from_iter([("browseId".to_string(), json!("FEmusic_explore"))])
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "browse"
+    }
+}
+
+impl<A: AuthToken> Query<A> for GetNewReleasesQuery {
+    type Output = Vec<NewReleaseAlbum>;
+    type Method = PostMethod;
+}
+impl PostQuery for GetNewReleasesQuery {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        serde_json::Map::from_iter([("browseId".to_string(), json!("FEmusic_new_releases_albums"))])
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "browse"
+    }
+}
+
+impl<A: AuthToken> Query<A> for GetNewMusicVideosQuery {
+    type Output = Vec<NewMusicVideo>;
+    type Method = PostMethod;
+}
+impl PostQuery for GetNewMusicVideosQuery {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        serde_json::Map::from_iter([("browseId".to_string(), json!("FEmusic_new_releases_videos"))])
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "browse"
+    }
+}
+
+impl<A: LoggedIn> Query<A> for GetListenAgainQuery {
+    type Output = Vec<ListenAgainItem>;
+    type Method = PostMethod;
+}
+impl PostQuery for GetListenAgainQuery {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        serde_json::Map::from_iter([("browseId".to_string(), json!("FEmusic_home"))])
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "browse"
+    }
+}
+
+impl<A: LoggedIn> Query<A> for PinToListenAgainQuery<'_> {
+    type Output = ApiOutcome;
+    type Method = PostMethod;
+}
+impl PostQuery for PinToListenAgainQuery<'_> {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        FromIterator::from_iter([("feedbackTokens".into(), json!([self.feedback_token]))])
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "feedback"
+    }
+}
+
+impl<A: LoggedIn> Query<A> for UnpinFromListenAgainQuery<'_> {
+    type Output = ApiOutcome;
+    type Method = PostMethod;
+}
+impl PostQuery for UnpinFromListenAgainQuery<'_> {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        FromIterator::from_iter([("feedbackTokens".into(), json!([self.feedback_token]))])
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "feedback"
+    }
+}
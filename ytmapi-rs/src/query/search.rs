@@ -1,6 +1,7 @@
 use super::*;
-use crate::common::SearchSuggestion;
-use crate::parse::SearchResults;
+use crate::auth::LoggedIn;
+use crate::common::{ApiOutcome, FeedbackTokenRemoveSearchSuggestion};
+use crate::parse::{SearchResults, SearchSuggestions};
 pub use filteredsearch::*;
 use std::borrow::Cow;
 
@@ -254,7 +255,7 @@ impl<'a, S: Into<Cow<'a, str>>> From<S> for GetSearchSuggestionsQuery<'a> {
 }
 
 impl<A: AuthToken> Query<A> for GetSearchSuggestionsQuery<'_> {
-    type Output = Vec<SearchSuggestion>;
+    type Output = SearchSuggestions;
     type Method = PostMethod;
 }
 impl PostQuery for GetSearchSuggestionsQuery<'_> {
@@ -270,6 +271,41 @@ impl PostQuery for GetSearchSuggestionsQuery<'_> {
     }
 }
 
+/// Remove suggestions from your search history, using the
+/// `feedback_token_remove` of a history [`SearchSuggestion`](crate::common::SearchSuggestion).
+pub struct RemoveSearchSuggestionsQuery<'a> {
+    feedback_tokens: Vec<FeedbackTokenRemoveSearchSuggestion<'a>>,
+}
+
+impl<'a> RemoveSearchSuggestionsQuery<'a> {
+    pub fn new(
+        feedback_tokens: impl IntoIterator<Item = FeedbackTokenRemoveSearchSuggestion<'a>>,
+    ) -> Self {
+        Self {
+            feedback_tokens: feedback_tokens.into_iter().collect(),
+        }
+    }
+}
+
+impl<A: LoggedIn> Query<A> for RemoveSearchSuggestionsQuery<'_> {
+    type Output = Vec<ApiOutcome>;
+    type Method = PostMethod;
+}
+impl PostQuery for RemoveSearchSuggestionsQuery<'_> {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        serde_json::Map::from_iter([(
+            "feedbackTokens".to_string(),
+            serde_json::json!(self.feedback_tokens),
+        )])
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "feedback"
+    }
+}
+
 fn search_query_header<S: SearchType>(
     query: &SearchQuery<S>,
 ) -> serde_json::Map<String, serde_json::Value> {
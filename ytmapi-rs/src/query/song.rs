@@ -1,8 +1,9 @@
 use super::{PostMethod, PostQuery, Query};
 use crate::Result;
 use crate::auth::AuthToken;
+use crate::client_profile::ClientProfile;
 use crate::common::{LyricsID, SongTrackingUrl, VideoID, YoutubeID};
-use crate::parse::Lyrics;
+use crate::parse::{Lyrics, Song};
 use serde_json::json;
 use std::borrow::Cow;
 use std::time::SystemTime;
@@ -20,6 +21,17 @@ pub struct GetSongTrackingUrlQuery<'a> {
     signature_timestamp: u64,
 }
 
+/// Get the details and streaming formats for a song, using the `player`
+/// endpoint.
+/// # NOTE
+/// Stream urls returned by this query generally need deciphering before use -
+/// see [`crate::decipher`].
+pub struct GetSongQuery<'a> {
+    video_id: VideoID<'a>,
+    signature_timestamp: u64,
+    client_profile: Option<ClientProfile>,
+}
+
 impl<'a> GetLyricsIDQuery<'a> {
     pub fn new(video_id: VideoID<'a>) -> GetLyricsIDQuery<'a> {
         GetLyricsIDQuery { video_id }
@@ -120,6 +132,68 @@ impl PostQuery for GetSongTrackingUrlQuery<'_> {
     }
 }
 
+impl GetSongQuery<'_> {
+    /// # NOTE
+    /// A GetSongQuery stores a timestamp, it's not recommended to store these
+    /// for a long period of time. The constructor can fail due to a System
+    /// Time error.
+    pub fn new(video_id: VideoID<'_>) -> Result<GetSongQuery<'_>> {
+        let signature_timestamp = get_signature_timestamp()?;
+        Ok(GetSongQuery {
+            video_id,
+            signature_timestamp,
+            client_profile: None,
+        })
+    }
+    /// Send this query as a different Innertube client to the client's
+    /// default, e.g to request the streaming formats offered to the YouTube
+    /// Music apps.
+    /// # NOTE
+    /// The output is parsed assuming the `player` response has the same
+    /// structure for all clients - this has only been tested against
+    /// `WEB_REMIX` responses.
+    pub fn with_client_profile(mut self, client_profile: ClientProfile) -> Self {
+        self.client_profile = Some(client_profile);
+        self
+    }
+    /// Override the approximated signatureTimestamp with the one from the
+    /// current player JS. Signatures are only guaranteed to decipher
+    /// correctly using the player JS matching this timestamp.
+    pub fn with_signature_timestamp(mut self, signature_timestamp: u64) -> Self {
+        self.signature_timestamp = signature_timestamp;
+        self
+    }
+}
+
+impl<A: AuthToken> Query<A> for GetSongQuery<'_> {
+    type Output = Song;
+    type Method = PostMethod;
+}
+impl PostQuery for GetSongQuery<'_> {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        let serde_json::Value::Object(map) = json!({
+            "playbackContext": {
+                "contentPlaybackContext": {
+                    "signatureTimestamp": self.signature_timestamp
+                }
+            },
+            "videoId": self.video_id.get_raw(),
+        }) else {
+            unreachable!()
+        };
+        map
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "player"
+    }
+    fn client_profile(&self) -> Option<ClientProfile> {
+        self.client_profile.clone()
+    }
+}
+
 // Original: https://github.com/sigma67/ytmusicapi/blob/a15d90c4f356a530c6b2596277a9d70c0b117a0c/ytmusicapi/mixins/_utils.py#L42
 /// Approximation for google's signatureTimestamp which would normally be
 /// extracted from base.js.
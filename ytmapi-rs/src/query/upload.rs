@@ -1,7 +1,7 @@
 use super::library::{GetLibrarySortOrder, get_sort_order_params};
 use super::{PostMethod, PostQuery, Query};
 use crate::auth::LoggedIn;
-use crate::common::{UploadAlbumID, UploadArtistID, UploadEntityID};
+use crate::common::{ApiOutcome, UploadAlbumID, UploadArtistID, UploadEntityID};
 use crate::parse::{GetLibraryUploadAlbum, TableListUploadSong, UploadAlbum, UploadArtist};
 use serde_json::json;
 use std::borrow::Cow;
@@ -31,6 +31,20 @@ pub struct GetLibraryUploadAlbumQuery<'a> {
 pub struct DeleteUploadEntityQuery<'a> {
     upload_entity_id: UploadEntityID<'a>,
 }
+#[derive(Clone)]
+/// Edits the metadata of a previously uploaded song. Only the fields that are
+/// set are changed, so at least one of `with_new_title`, `with_new_artist` or
+/// `with_new_album` should be called.
+/// # NOTE
+/// The edit actions sent by this query have not been verified against a
+/// request captured from YouTube Music. Setting custom album art for an
+/// uploaded album is not supported.
+pub struct EditUploadSongQuery<'a> {
+    upload_entity_id: UploadEntityID<'a>,
+    new_title: Option<Cow<'a, str>>,
+    new_artist: Option<Cow<'a, str>>,
+    new_album: Option<Cow<'a, str>>,
+}
 
 impl GetLibraryUploadSongsQuery {
     pub fn new(sort_order: GetLibrarySortOrder) -> Self {
@@ -62,6 +76,28 @@ impl<'a> DeleteUploadEntityQuery<'a> {
         Self { upload_entity_id }
     }
 }
+impl<'a> EditUploadSongQuery<'a> {
+    pub fn new(upload_entity_id: UploadEntityID<'a>) -> Self {
+        Self {
+            upload_entity_id,
+            new_title: None,
+            new_artist: None,
+            new_album: None,
+        }
+    }
+    pub fn with_new_title<S: Into<Cow<'a, str>>>(mut self, new_title: S) -> Self {
+        self.new_title = Some(new_title.into());
+        self
+    }
+    pub fn with_new_artist<S: Into<Cow<'a, str>>>(mut self, new_artist: S) -> Self {
+        self.new_artist = Some(new_artist.into());
+        self
+    }
+    pub fn with_new_album<S: Into<Cow<'a, str>>>(mut self, new_album: S) -> Self {
+        self.new_album = Some(new_album.into());
+        self
+    }
+}
 // Auth required
 impl<A: LoggedIn> Query<A> for GetLibraryUploadAlbumQuery<'_> {
     type Output = GetLibraryUploadAlbum;
@@ -200,3 +236,41 @@ impl PostQuery for DeleteUploadEntityQuery<'_> {
         "music/delete_privately_owned_entity"
     }
 }
+// Auth required
+impl<A: LoggedIn> Query<A> for EditUploadSongQuery<'_> {
+    type Output = ApiOutcome;
+    type Method = PostMethod;
+}
+impl PostQuery for EditUploadSongQuery<'_> {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        let mut actions = Vec::new();
+        if let Some(new_title) = &self.new_title {
+            actions.push(json!({
+                "action" : "ACTION_SET_TITLE",
+                "title" : new_title
+            }))
+        };
+        if let Some(new_artist) = &self.new_artist {
+            actions.push(json!({
+                "action" : "ACTION_SET_ARTIST_NAME",
+                "artistName" : new_artist
+            }))
+        };
+        if let Some(new_album) = &self.new_album {
+            actions.push(json!({
+                "action" : "ACTION_SET_ALBUM_TITLE",
+                "albumTitle" : new_album
+            }))
+        };
+        serde_json::Map::from_iter([
+            ("entityId".to_string(), json!(self.upload_entity_id)),
+            ("actions".to_string(), json!(actions)),
+        ])
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "music/edit_privately_owned_entity"
+    }
+}
@@ -0,0 +1,265 @@
+//! JSON Schemas for the outputs of each [`Query`], and for the ID types in
+//! [`common`](crate::common).
+//!
+//! Schemas for the current version are committed to the `schemas` directory of
+//! this crate, so that non-Rust consumers of serialized outputs can use them
+//! without building ytmapi-rs. Schemas describe the serialized form of each
+//! type, as produced by `serde_json::to_string`.
+//! # Versioning
+//! Each schema contains an `x-ytmapi-schema-version` key, set to
+//! [`OUTPUT_SCHEMA_VERSION`]. This is bumped whenever the shape of any output
+//! or ID changes, so consumers can detect changes by comparing versions.
+//! # Usage
+//! ```
+//! let schemas = ytmapi_rs::schema::query_output_schemas();
+//! let get_album_schema = &schemas["GetAlbumQuery"];
+//! assert_eq!(
+//!     get_album_schema.get(ytmapi_rs::schema::SCHEMA_VERSION_KEY),
+//!     Some(&ytmapi_rs::schema::OUTPUT_SCHEMA_VERSION.into())
+//! );
+//! ```
+//! # Optional
+//! To enable this module, feature `schemars` must be enabled.
+use crate::auth::BrowserToken;
+use crate::common::{
+    AlbumID, ArtistChannelID, BrowseParams, ContinuationParams, EpisodeID,
+    FeedbackTokenAddToLibrary, FeedbackTokenMarkPlayed, FeedbackTokenMarkUnplayed,
+    FeedbackTokenPinToListenAgain, FeedbackTokenRemoveFromHistory, FeedbackTokenRemoveFromLibrary,
+    FeedbackTokenRemoveSearchSuggestion, FeedbackTokenUnpinFromListenAgain, LyricsID,
+    MoodCategoryParams, PlaylistID, PodcastChannelID, PodcastChannelParams, PodcastID, SetVideoID,
+    SongTrackingUrl, TasteTokenImpression, TasteTokenSelection, UploadAlbumID, UploadArtistID,
+    UploadEntityID, UserChannelID, UserPlaylistsParams, UserVideosParams, VideoID,
+};
+use crate::query::playlist::{AddPlaylistToPlaylist, AddVideosToPlaylist, BasicCreatePlaylist};
+use crate::query::search::{
+    AlbumsFilter, ArtistsFilter, BasicSearch, CommunityPlaylistsFilter, EpisodesFilter,
+    FeaturedPlaylistsFilter, FilteredSearch, PlaylistsFilter, PodcastsFilter, ProfilesFilter,
+    SongsFilter, VideosFilter,
+};
+use crate::query::*;
+use schemars::generate::SchemaSettings;
+use schemars::{JsonSchema, Schema};
+use std::collections::BTreeMap;
+
+/// Version of the schemas generated by this module. Bumped whenever the shape
+/// of any query output or ID changes.
+pub const OUTPUT_SCHEMA_VERSION: u64 = 1;
+/// Key in each generated schema containing [`OUTPUT_SCHEMA_VERSION`].
+pub const SCHEMA_VERSION_KEY: &str = "x-ytmapi-schema-version";
+
+macro_rules! query_output_schemas {
+    ($($name:literal => $query:ty),* $(,)?) => {
+        BTreeMap::from([$((
+            $name,
+            versioned_schema_for::<<$query as Query<BrowserToken>>::Output>(),
+        )),*])
+    };
+}
+
+macro_rules! id_schemas {
+    ($($id:ident),* $(,)?) => {
+        BTreeMap::from([$((stringify!($id), versioned_schema_for::<$id<'static>>())),*])
+    };
+}
+
+/// Get the JSON Schema of the output of each query, keyed by the name of the
+/// query. Where the output of a query depends on its type parameters, the
+/// name includes the type parameter, e.g `SearchQuery-SongsFilter`.
+pub fn query_output_schemas() -> BTreeMap<&'static str, Schema> {
+    query_output_schemas!(
+        "AddHistoryItemQuery" => AddHistoryItemQuery<'static>,
+        "AddPlaylistItemsQuery-AddPlaylistToPlaylist" =>
+            AddPlaylistItemsQuery<'static, AddPlaylistToPlaylist<'static>>,
+        "AddPlaylistItemsQuery-AddVideosToPlaylist" =>
+            AddPlaylistItemsQuery<'static, AddVideosToPlaylist<'static>>,
+        "CreatePlaylistQuery" => CreatePlaylistQuery<'static, BasicCreatePlaylist>,
+        "DeletePlaylistQuery" => DeletePlaylistQuery<'static>,
+        "DeleteUploadEntityQuery" => DeleteUploadEntityQuery<'static>,
+        "EditPlaylistQuery" => EditPlaylistQuery<'static>,
+        "EditSongLibraryStatusQuery" => EditSongLibraryStatusQuery<'static>,
+        "EditUploadSongQuery" => EditUploadSongQuery<'static>,
+        "GetAlbumQuery" => GetAlbumQuery<'static>,
+        "GetArtistAlbumsQuery" => GetArtistAlbumsQuery<'static>,
+        "GetArtistQuery" => GetArtistQuery<'static>,
+        "GetChannelEpisodesQuery" => GetChannelEpisodesQuery<'static>,
+        "GetChannelQuery" => GetChannelQuery<'static>,
+        "GetEpisodeQuery" => GetEpisodeQuery<'static>,
+        "GetExploreQuery" => GetExploreQuery,
+        "GetHistoryQuery" => GetHistoryQuery,
+        "GetLibraryAlbumsQuery" => GetLibraryAlbumsQuery,
+        "GetLibraryArtistSubscriptionsQuery" => GetLibraryArtistSubscriptionsQuery,
+        "GetLibraryArtistsQuery" => GetLibraryArtistsQuery,
+        "GetLibraryChannelsQuery" => GetLibraryChannelsQuery,
+        "GetLibraryPlaylistsQuery" => GetLibraryPlaylistsQuery,
+        "GetLibraryPodcastsQuery" => GetLibraryPodcastsQuery,
+        "GetLibrarySongsQuery" => GetLibrarySongsQuery,
+        "GetLibraryUploadAlbumQuery" => GetLibraryUploadAlbumQuery<'static>,
+        "GetLibraryUploadAlbumsQuery" => GetLibraryUploadAlbumsQuery,
+        "GetLibraryUploadArtistQuery" => GetLibraryUploadArtistQuery<'static>,
+        "GetLibraryUploadArtistsQuery" => GetLibraryUploadArtistsQuery,
+        "GetLibraryUploadSongsQuery" => GetLibraryUploadSongsQuery,
+        "GetListenAgainQuery" => GetListenAgainQuery,
+        "GetLyricsIDQuery" => GetLyricsIDQuery<'static>,
+        "GetLyricsQuery" => GetLyricsQuery<'static>,
+        "GetMoodCategoriesQuery" => GetMoodCategoriesQuery,
+        "GetMoodPlaylistsQuery" => GetMoodPlaylistsQuery<'static>,
+        "GetNewEpisodesQuery" => GetNewEpisodesQuery,
+        "GetNewMusicVideosQuery" => GetNewMusicVideosQuery,
+        "GetNewReleasesQuery" => GetNewReleasesQuery,
+        "GetPlaylistDetailsQuery" => GetPlaylistDetailsQuery<'static>,
+        "GetPlaylistTracksQuery" => GetPlaylistTracksQuery<'static>,
+        "GetPodcastQuery" => GetPodcastQuery<'static>,
+        "GetSearchSuggestionsQuery" => GetSearchSuggestionsQuery<'static>,
+        "GetSongQuery" => GetSongQuery<'static>,
+        "GetSongTrackingUrlQuery" => GetSongTrackingUrlQuery<'static>,
+        "GetTasteProfileQuery" => GetTasteProfileQuery,
+        "GetUserPlaylistsQuery" => GetUserPlaylistsQuery<'static>,
+        "GetUserQuery" => GetUserQuery<'static>,
+        "GetUserVideosQuery" => GetUserVideosQuery<'static>,
+        "GetWatchPlaylistQuery" => GetWatchPlaylistQuery<VideoID<'static>>,
+        "MarkEpisodePlayedQuery" => MarkEpisodePlayedQuery<'static>,
+        "MarkEpisodeUnplayedQuery" => MarkEpisodeUnplayedQuery<'static>,
+        "PinToListenAgainQuery" => PinToListenAgainQuery<'static>,
+        "RatePlaylistQuery" => RatePlaylistQuery<'static>,
+        "RateSongQuery" => RateSongQuery<'static>,
+        "RemoveHistoryItemsQuery" => RemoveHistoryItemsQuery<'static>,
+        "RemovePlaylistItemsQuery" => RemovePlaylistItemsQuery<'static>,
+        "RemoveSearchSuggestionsQuery" => RemoveSearchSuggestionsQuery<'static>,
+        "SearchQuery" => SearchQuery<'static, BasicSearch>,
+        "SearchQuery-AlbumsFilter" => SearchQuery<'static, FilteredSearch<AlbumsFilter>>,
+        "SearchQuery-ArtistsFilter" => SearchQuery<'static, FilteredSearch<ArtistsFilter>>,
+        "SearchQuery-CommunityPlaylistsFilter" =>
+            SearchQuery<'static, FilteredSearch<CommunityPlaylistsFilter>>,
+        "SearchQuery-EpisodesFilter" => SearchQuery<'static, FilteredSearch<EpisodesFilter>>,
+        "SearchQuery-FeaturedPlaylistsFilter" =>
+            SearchQuery<'static, FilteredSearch<FeaturedPlaylistsFilter>>,
+        "SearchQuery-PlaylistsFilter" => SearchQuery<'static, FilteredSearch<PlaylistsFilter>>,
+        "SearchQuery-PodcastsFilter" => SearchQuery<'static, FilteredSearch<PodcastsFilter>>,
+        "SearchQuery-ProfilesFilter" => SearchQuery<'static, FilteredSearch<ProfilesFilter>>,
+        "SearchQuery-SongsFilter" => SearchQuery<'static, FilteredSearch<SongsFilter>>,
+        "SearchQuery-VideosFilter" => SearchQuery<'static, FilteredSearch<VideosFilter>>,
+        "SetTasteProfileQuery" => SetTasteProfileQuery<'static>,
+        "SubscribeArtistQuery" => SubscribeArtistQuery<'static>,
+        "SubscribePodcastQuery" => SubscribePodcastQuery<'static>,
+        "UnpinFromListenAgainQuery" => UnpinFromListenAgainQuery<'static>,
+        "UnsubscribeArtistsQuery" => UnsubscribeArtistsQuery<'static>,
+        "UnsubscribePodcastQuery" => UnsubscribePodcastQuery<'static>,
+    )
+}
+
+/// Get the JSON Schema of each ID type in [`common`](crate::common), keyed by
+/// the name of the type.
+pub fn id_schemas() -> BTreeMap<&'static str, Schema> {
+    id_schemas!(
+        AlbumID,
+        ArtistChannelID,
+        BrowseParams,
+        ContinuationParams,
+        EpisodeID,
+        FeedbackTokenAddToLibrary,
+        FeedbackTokenMarkPlayed,
+        FeedbackTokenMarkUnplayed,
+        FeedbackTokenPinToListenAgain,
+        FeedbackTokenRemoveFromHistory,
+        FeedbackTokenRemoveFromLibrary,
+        FeedbackTokenRemoveSearchSuggestion,
+        FeedbackTokenUnpinFromListenAgain,
+        LyricsID,
+        MoodCategoryParams,
+        PlaylistID,
+        PodcastChannelID,
+        PodcastChannelParams,
+        PodcastID,
+        SetVideoID,
+        SongTrackingUrl,
+        TasteTokenImpression,
+        TasteTokenSelection,
+        UploadAlbumID,
+        UploadArtistID,
+        UploadEntityID,
+        UserChannelID,
+        UserPlaylistsParams,
+        UserVideosParams,
+        VideoID,
+    )
+}
+
+fn versioned_schema_for<T: JsonSchema>() -> Schema {
+    // Outputs are only ever serialized, so generate the schema for the
+    // serialized form (e.g. serialize_with representations, and fields with
+    // #[serde(default)] still required).
+    let mut schema = SchemaSettings::default()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<T>();
+    schema.insert(SCHEMA_VERSION_KEY.into(), OUTPUT_SCHEMA_VERSION.into());
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OUTPUT_SCHEMA_VERSION, SCHEMA_VERSION_KEY, id_schemas, query_output_schemas};
+    use schemars::Schema;
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::path::Path;
+
+    /// Set this environment variable to overwrite the committed schemas with the
+    /// generated ones.
+    const UPDATE_SCHEMAS_ENV_VAR: &str = "YTMAPI_UPDATE_SCHEMAS";
+
+    fn check_committed_schemas(dir: impl AsRef<Path>, schemas: BTreeMap<&str, Schema>) {
+        let dir = Path::new("./schemas").join(dir);
+        if std::env::var_os(UPDATE_SCHEMAS_ENV_VAR).is_some() {
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            for (name, schema) in schemas {
+                let json = serde_json::to_string_pretty(&schema).unwrap();
+                std::fs::write(dir.join(format!("{name}.json")), json + "\n").unwrap();
+            }
+            return;
+        }
+        let committed = std::fs::read_dir(&dir)
+            .expect("Expect schemas directory to exist")
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+                let schema: serde_json::Value =
+                    serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+                (name, schema)
+            })
+            .collect::<BTreeMap<_, _>>();
+        let committed_version = committed
+            .values()
+            .find_map(|schema| schema.get(SCHEMA_VERSION_KEY)?.as_u64());
+        let unchanged = committed
+            .keys()
+            .map(String::as_str)
+            .collect::<BTreeSet<_>>()
+            == schemas.keys().copied().collect::<BTreeSet<_>>()
+            && schemas
+                .iter()
+                .all(|(name, schema)| &committed[*name] == schema.as_value());
+        if unchanged {
+            return;
+        }
+        if committed_version == Some(OUTPUT_SCHEMA_VERSION) {
+            panic!(
+                "Schemas in {} have changed, but OUTPUT_SCHEMA_VERSION is still {OUTPUT_SCHEMA_VERSION}. Bump it, then run `just update-schemas`",
+                dir.display()
+            );
+        }
+        panic!(
+            "Schemas in {} are out of date for OUTPUT_SCHEMA_VERSION {OUTPUT_SCHEMA_VERSION}. Run `just update-schemas`",
+            dir.display()
+        );
+    }
+
+    #[test]
+    fn test_query_output_schemas_committed() {
+        check_committed_schemas("outputs", query_output_schemas());
+    }
+    #[test]
+    fn test_id_schemas_committed() {
+        check_committed_schemas("ids", id_schemas());
+    }
+}
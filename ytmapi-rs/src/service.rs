@@ -0,0 +1,150 @@
+//! Implementation of `tower::Service` for [`YtMusic`], allowing `tower`
+//! middleware to be layered over queries.
+//! # Usage
+//! ```no_run
+//! use std::time::Duration;
+//! use tower::{ServiceBuilder, ServiceExt};
+//! # async {
+//! let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE").await?;
+//! let service = ServiceBuilder::new()
+//!     .timeout(Duration::from_secs(10))
+//!     .service(yt);
+//! let query = ytmapi_rs::query::SearchQuery::new("Beatles")
+//!     .with_filter(ytmapi_rs::query::search::ArtistsFilter);
+//! let artists = service.oneshot(query).await.unwrap();
+//! # Ok::<(), ytmapi_rs::Error>(())
+//! # };
+//! ```
+use crate::auth::AuthToken;
+use crate::parse::ParseFrom;
+use crate::query::{Query, SendQueryMethod};
+use crate::{Error, Result, YtMusic, instrument};
+use futures::future::BoxFuture;
+use std::task::{Context, Poll};
+
+/// Runs a [`Query`] the same way as [`YtMusic::query`] and returns its output.
+/// The service is always ready.
+/// The query and auth token must be `Send` and `Sync`, so that the returned
+/// future is `Send`.
+impl<A, Q> tower::Service<Q> for YtMusic<A>
+where
+    A: AuthToken + Clone + Send + Sync + 'static,
+    Q: Query<A> + Send + Sync + 'static,
+    Q::Method: SendQueryMethod<Q, A>,
+    Q::Output: Send,
+{
+    type Response = Q::Output;
+    type Error = Error;
+    type Future = BoxFuture<'static, Result<Q::Output>>;
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+    fn call(&mut self, query: Q) -> Self::Future {
+        let yt = self.clone();
+        Box::pin(async move {
+            instrument::in_query_span::<Q, A, _>(None, async {
+                Q::Output::parse_from(
+                    Q::Method::call_send(&query, &yt.client, &yt.token)
+                        .await?
+                        .process()?,
+                )
+            })
+            .await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth::{BrowserToken, CustomToken, HeaderProvider};
+    use crate::common::{SongTrackingUrl, YoutubeID};
+    use crate::query::{AddHistoryItemQuery, GetLibrarySongsQuery};
+    use crate::{Client, YtMusicBuilder};
+    use std::borrow::Cow;
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+
+    const MOCK_COOKIE: &str = "SAPISID=ytmapi-mock-server; __Secure-3PAPISID=ytmapi-mock-server;";
+    const LIBRARY_SONGS: &str = "test_json/get_library_songs_20240701.json";
+
+    type RequestLog = Arc<Mutex<Vec<(reqwest::Method, String)>>>;
+
+    /// Client using a tower transport that records the method and path of each
+    /// request.
+    fn recording_client() -> (Client, RequestLog) {
+        let requests = RequestLog::default();
+        let transport = tower::service_fn({
+            let requests = requests.clone();
+            move |request: reqwest::Request| {
+                requests
+                    .lock()
+                    .unwrap()
+                    .push((request.method().clone(), request.url().path().to_owned()));
+                async move {
+                    // GET requests are either the browser token obtaining the client
+                    // version from the YouTube Music homepage, or a tracking url that
+                    // returns an empty body. All other requests are queries.
+                    let body = match (request.method(), request.url().path()) {
+                        (&reqwest::Method::GET, "/") => {
+                            r#"{"INNERTUBE_CLIENT_VERSION":"1.20240701"}"#.to_string()
+                        }
+                        (&reqwest::Method::GET, _) => String::new(),
+                        _ => tokio::fs::read_to_string(LIBRARY_SONGS).await?,
+                    };
+                    Ok::<_, std::io::Error>(reqwest::Response::from(http::Response::new(body)))
+                }
+            }
+        });
+        (Client::new_from_tower_service(transport).unwrap(), requests)
+    }
+
+    #[derive(Clone)]
+    struct MockHeaders;
+    impl HeaderProvider for MockHeaders {
+        fn headers(&self) -> crate::Result<impl IntoIterator<Item = (&str, Cow<'_, str>)>> {
+            Ok([("Cookie", MOCK_COOKIE.into())])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_through_tower_transport() {
+        let (client, requests) = recording_client();
+        let token = BrowserToken::from_str(MOCK_COOKIE, &client).await.unwrap();
+        let yt = YtMusicBuilder::new()
+            .with_client(client)
+            .with_auth_token(token)
+            .build()
+            .unwrap();
+        let output = yt.oneshot(GetLibrarySongsQuery::default()).await.unwrap();
+        let expected = crate::process_json::<_, BrowserToken>(
+            tokio::fs::read_to_string(LIBRARY_SONGS).await.unwrap(),
+            GetLibrarySongsQuery::default(),
+        )
+        .unwrap();
+        pretty_assertions::assert_eq!(output, expected);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].0, reqwest::Method::GET);
+        assert_eq!(
+            requests[1],
+            (reqwest::Method::POST, "/youtubei/v1/browse".to_owned())
+        );
+    }
+    #[tokio::test]
+    async fn test_get_query_with_custom_token_through_tower_transport() {
+        let (client, requests) = recording_client();
+        let yt = YtMusicBuilder::new()
+            .with_client(client)
+            .with_auth_token(CustomToken::new(MockHeaders))
+            .build()
+            .unwrap();
+        let query = AddHistoryItemQuery::new(SongTrackingUrl::from_raw(
+            "https://music.youtube.com/api/stats/playback?docid=abc",
+        ));
+        yt.oneshot(query).await.unwrap();
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [(reqwest::Method::GET, "/api/stats/playback".to_owned())]
+        );
+    }
+}
@@ -7,18 +7,23 @@
 //! by default)
 use crate::auth::{AuthToken, LoggedIn};
 use crate::common::{
-    AlbumID, ApiOutcome, ArtistChannelID, BrowseParams, EpisodeID, FeedbackTokenRemoveFromHistory,
-    LikeStatus, LyricsID, MoodCategoryParams, PlaylistID, PodcastChannelID, PodcastChannelParams,
-    PodcastID, SearchSuggestion, SetVideoID, SongTrackingUrl, TasteToken, UploadAlbumID,
-    UploadArtistID, UploadEntityID, UserChannelID, UserPlaylistsParams, UserVideosParams, VideoID,
+    AlbumID, ApiOutcome, ArtistChannelID, BrowseParams, EpisodeID, FeedbackTokenMarkPlayed,
+    FeedbackTokenMarkUnplayed, FeedbackTokenPinToListenAgain, FeedbackTokenRemoveFromHistory,
+    FeedbackTokenRemoveSearchSuggestion, FeedbackTokenUnpinFromListenAgain, LikeStatus, LyricsID,
+    MoodCategoryParams, PlaylistID, PodcastChannelID, PodcastChannelParams, PodcastID, SetVideoID,
+    SongTrackingUrl, TasteToken, UploadAlbumID, UploadArtistID, UploadEntityID, UserChannelID,
+    UserPlaylistsParams, UserVideosParams, VideoID,
 };
+use crate::continuations::{self, ParseFromContinuable};
 use crate::parse::{
-    AddPlaylistItem, GetAlbum, GetArtist, GetArtistAlbumsAlbum, GetPlaylistDetails, GetUser,
-    HistoryPeriod, LibraryArtist, LibraryArtistSubscription, LibraryPlaylist, Lyrics, PlaylistItem,
-    SearchResultAlbum, SearchResultArtist, SearchResultEpisode, SearchResultFeaturedPlaylist,
-    SearchResultPlaylist, SearchResultPodcast, SearchResultProfile, SearchResultSong,
-    SearchResultVideo, SearchResults, UserPlaylist, UserVideo, WatchPlaylistTrack,
+    AddPlaylistItem, AddPlaylistVideoResult, GetAlbum, GetArtist, GetArtistAlbumsAlbum,
+    GetPlaylistDetails, GetUser, HistoryPeriod, LibraryArtist, LibraryArtistSubscription,
+    LibraryPlaylist, ListenAgainItem, Lyrics, PlaylistItem, SearchResultAlbum, SearchResultArtist,
+    SearchResultEpisode, SearchResultFeaturedPlaylist, SearchResultPlaylist, SearchResultPodcast,
+    SearchResultProfile, SearchResultSong, SearchResultVideo, SearchResults, SearchSuggestions,
+    Song, UserPlaylist, UserVideo, WatchPlaylistTrack,
 };
+use crate::query::PostQuery;
 use crate::query::playlist::{CreatePlaylistType, DuplicateHandlingMode, GetPlaylistDetailsQuery};
 use crate::query::rate::{RatePlaylistQuery, RateSongQuery};
 use crate::query::search::BasicSearch;
@@ -26,22 +31,27 @@ use crate::query::search::filteredsearch::{
     AlbumsFilter, ArtistsFilter, CommunityPlaylistsFilter, EpisodesFilter, FeaturedPlaylistsFilter,
     FilteredSearch, PlaylistsFilter, PodcastsFilter, ProfilesFilter, SongsFilter, VideosFilter,
 };
-use crate::query::song::{GetLyricsQuery, GetSongTrackingUrlQuery};
+use crate::query::song::{GetLyricsQuery, GetSongQuery, GetSongTrackingUrlQuery};
 use crate::query::{
     AddHistoryItemQuery, AddPlaylistItemsQuery, CreatePlaylistQuery, DeletePlaylistQuery,
-    DeleteUploadEntityQuery, EditPlaylistQuery, EditSongLibraryStatusQuery, GetAlbumQuery,
-    GetArtistAlbumsQuery, GetArtistQuery, GetChannelEpisodesQuery, GetChannelQuery,
-    GetEpisodeQuery, GetHistoryQuery, GetLibraryAlbumsQuery, GetLibraryArtistSubscriptionsQuery,
-    GetLibraryArtistsQuery, GetLibraryChannelsQuery, GetLibraryPlaylistsQuery,
-    GetLibraryPodcastsQuery, GetLibrarySongsQuery, GetLibraryUploadAlbumQuery,
-    GetLibraryUploadAlbumsQuery, GetLibraryUploadArtistQuery, GetLibraryUploadArtistsQuery,
-    GetLibraryUploadSongsQuery, GetLyricsIDQuery, GetMoodCategoriesQuery, GetMoodPlaylistsQuery,
-    GetNewEpisodesQuery, GetPlaylistTracksQuery, GetPodcastQuery, GetSearchSuggestionsQuery,
-    GetTasteProfileQuery, GetUserPlaylistsQuery, GetUserQuery, GetUserVideosQuery,
-    GetWatchPlaylistQuery, Query, RemoveHistoryItemsQuery, RemovePlaylistItemsQuery, SearchQuery,
-    SetTasteProfileQuery, SubscribeArtistQuery, UnsubscribeArtistsQuery,
+    DeleteUploadEntityQuery, EditPlaylistQuery, EditSongLibraryStatusQuery, EditUploadSongQuery,
+    GetAlbumQuery, GetArtistAlbumsQuery, GetArtistQuery, GetChannelEpisodesQuery, GetChannelQuery,
+    GetEpisodeQuery, GetExploreQuery, GetHistoryQuery, GetLibraryAlbumsQuery,
+    GetLibraryArtistSubscriptionsQuery, GetLibraryArtistsQuery, GetLibraryChannelsQuery,
+    GetLibraryPlaylistsQuery, GetLibraryPodcastsQuery, GetLibrarySongsQuery,
+    GetLibraryUploadAlbumQuery, GetLibraryUploadAlbumsQuery, GetLibraryUploadArtistQuery,
+    GetLibraryUploadArtistsQuery, GetLibraryUploadSongsQuery, GetListenAgainQuery,
+    GetLyricsIDQuery, GetMoodCategoriesQuery, GetMoodPlaylistsQuery, GetNewEpisodesQuery,
+    GetNewMusicVideosQuery, GetNewReleasesQuery, GetPlaylistTracksQuery, GetPodcastQuery,
+    GetSearchSuggestionsQuery, GetTasteProfileQuery, GetUserPlaylistsQuery, GetUserQuery,
+    GetUserVideosQuery, GetWatchPlaylistQuery, MarkEpisodePlayedQuery, MarkEpisodeUnplayedQuery,
+    PinToListenAgainQuery, Query, RemoveHistoryItemsQuery, RemovePlaylistItemsQuery,
+    RemoveSearchSuggestionsQuery, SearchQuery, SetTasteProfileQuery, SubscribeArtistQuery,
+    SubscribePodcastQuery, UnpinFromListenAgainQuery, UnsubscribeArtistsQuery,
+    UnsubscribePodcastQuery,
 };
 use crate::{Result, YtMusic};
+use futures::Stream;
 
 impl<A: AuthToken> YtMusic<A> {
     /// API Search Query that returns results for each category if available.
@@ -78,6 +88,26 @@ impl<A: AuthToken> YtMusic<A> {
         let query = query.into();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::search_artists`],
+    /// returning each page of results as it's received.
+    pub fn search_artists_stream<'a, Q: Into<SearchQuery<'a, FilteredSearch<ArtistsFilter>>>>(
+        &'a self,
+        query: Q,
+    ) -> impl Stream<Item = Result<Vec<SearchResultArtist>>> {
+        let query = query.into();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::search_artists`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn search_artists_all<'a, Q: Into<SearchQuery<'a, FilteredSearch<ArtistsFilter>>>>(
+        &self,
+        query: Q,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultArtist>> {
+        let query = query.into();
+        self.query_all(query, max_items).await
+    }
     /// API Search Query for Albums only.
     /// ```no_run
     /// # async {
@@ -91,6 +121,26 @@ impl<A: AuthToken> YtMusic<A> {
         let query = query.into();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::search_albums`],
+    /// returning each page of results as it's received.
+    pub fn search_albums_stream<'a, Q: Into<SearchQuery<'a, FilteredSearch<AlbumsFilter>>>>(
+        &'a self,
+        query: Q,
+    ) -> impl Stream<Item = Result<Vec<SearchResultAlbum>>> {
+        let query = query.into();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::search_albums`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn search_albums_all<'a, Q: Into<SearchQuery<'a, FilteredSearch<AlbumsFilter>>>>(
+        &self,
+        query: Q,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultAlbum>> {
+        let query = query.into();
+        self.query_all(query, max_items).await
+    }
     /// API Search Query for Songs only.
     /// ```no_run
     /// # async {
@@ -107,6 +157,26 @@ impl<A: AuthToken> YtMusic<A> {
         let query = query.into();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::search_songs`],
+    /// returning each page of results as it's received.
+    pub fn search_songs_stream<'a, Q: Into<SearchQuery<'a, FilteredSearch<SongsFilter>>>>(
+        &'a self,
+        query: Q,
+    ) -> impl Stream<Item = Result<Vec<SearchResultSong>>> {
+        let query = query.into();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::search_songs`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn search_songs_all<'a, Q: Into<SearchQuery<'a, FilteredSearch<SongsFilter>>>>(
+        &self,
+        query: Q,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultSong>> {
+        let query = query.into();
+        self.query_all(query, max_items).await
+    }
     /// API Search Query for Playlists only.
     /// ```no_run
     /// # async {
@@ -123,6 +193,32 @@ impl<A: AuthToken> YtMusic<A> {
         let query = query.into();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::search_playlists`],
+    /// returning each page of results as it's received.
+    pub fn search_playlists_stream<
+        'a,
+        Q: Into<SearchQuery<'a, FilteredSearch<PlaylistsFilter>>>,
+    >(
+        &'a self,
+        query: Q,
+    ) -> impl Stream<Item = Result<Vec<SearchResultPlaylist>>> {
+        let query = query.into();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::search_playlists`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn search_playlists_all<
+        'a,
+        Q: Into<SearchQuery<'a, FilteredSearch<PlaylistsFilter>>>,
+    >(
+        &self,
+        query: Q,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultPlaylist>> {
+        let query = query.into();
+        self.query_all(query, max_items).await
+    }
     /// API Search Query for Community Playlists only.
     /// ```no_run
     /// # async {
@@ -142,6 +238,32 @@ impl<A: AuthToken> YtMusic<A> {
         let query = query.into();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::search_community_playlists`],
+    /// returning each page of results as it's received.
+    pub fn search_community_playlists_stream<
+        'a,
+        Q: Into<SearchQuery<'a, FilteredSearch<CommunityPlaylistsFilter>>>,
+    >(
+        &'a self,
+        query: Q,
+    ) -> impl Stream<Item = Result<Vec<SearchResultPlaylist>>> {
+        let query = query.into();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::search_community_playlists`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn search_community_playlists_all<
+        'a,
+        Q: Into<SearchQuery<'a, FilteredSearch<CommunityPlaylistsFilter>>>,
+    >(
+        &self,
+        query: Q,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultPlaylist>> {
+        let query = query.into();
+        self.query_all(query, max_items).await
+    }
     /// API Search Query for Featured Playlists only.
     /// ```no_run
     /// # async {
@@ -161,6 +283,32 @@ impl<A: AuthToken> YtMusic<A> {
         let query = query.into();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::search_featured_playlists`],
+    /// returning each page of results as it's received.
+    pub fn search_featured_playlists_stream<
+        'a,
+        Q: Into<SearchQuery<'a, FilteredSearch<FeaturedPlaylistsFilter>>>,
+    >(
+        &'a self,
+        query: Q,
+    ) -> impl Stream<Item = Result<Vec<SearchResultFeaturedPlaylist>>> {
+        let query = query.into();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::search_featured_playlists`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn search_featured_playlists_all<
+        'a,
+        Q: Into<SearchQuery<'a, FilteredSearch<FeaturedPlaylistsFilter>>>,
+    >(
+        &self,
+        query: Q,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultFeaturedPlaylist>> {
+        let query = query.into();
+        self.query_all(query, max_items).await
+    }
     /// API Search Query for Episodes only.
     /// ```no_run
     /// # async {
@@ -177,6 +325,29 @@ impl<A: AuthToken> YtMusic<A> {
         let query = query.into();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::search_episodes`],
+    /// returning each page of results as it's received.
+    pub fn search_episodes_stream<'a, Q: Into<SearchQuery<'a, FilteredSearch<EpisodesFilter>>>>(
+        &'a self,
+        query: Q,
+    ) -> impl Stream<Item = Result<Vec<SearchResultEpisode>>> {
+        let query = query.into();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::search_episodes`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn search_episodes_all<
+        'a,
+        Q: Into<SearchQuery<'a, FilteredSearch<EpisodesFilter>>>,
+    >(
+        &self,
+        query: Q,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultEpisode>> {
+        let query = query.into();
+        self.query_all(query, max_items).await
+    }
     /// API Search Query for Podcasts only.
     /// ```no_run
     /// # async {
@@ -193,6 +364,29 @@ impl<A: AuthToken> YtMusic<A> {
         let query = query.into();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::search_podcasts`],
+    /// returning each page of results as it's received.
+    pub fn search_podcasts_stream<'a, Q: Into<SearchQuery<'a, FilteredSearch<PodcastsFilter>>>>(
+        &'a self,
+        query: Q,
+    ) -> impl Stream<Item = Result<Vec<SearchResultPodcast>>> {
+        let query = query.into();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::search_podcasts`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn search_podcasts_all<
+        'a,
+        Q: Into<SearchQuery<'a, FilteredSearch<PodcastsFilter>>>,
+    >(
+        &self,
+        query: Q,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultPodcast>> {
+        let query = query.into();
+        self.query_all(query, max_items).await
+    }
     /// API Search Query for Videos only.
     /// ```no_run
     /// # async {
@@ -209,6 +403,26 @@ impl<A: AuthToken> YtMusic<A> {
         let query = query.into();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::search_videos`],
+    /// returning each page of results as it's received.
+    pub fn search_videos_stream<'a, Q: Into<SearchQuery<'a, FilteredSearch<VideosFilter>>>>(
+        &'a self,
+        query: Q,
+    ) -> impl Stream<Item = Result<Vec<SearchResultVideo>>> {
+        let query = query.into();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::search_videos`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn search_videos_all<'a, Q: Into<SearchQuery<'a, FilteredSearch<VideosFilter>>>>(
+        &self,
+        query: Q,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultVideo>> {
+        let query = query.into();
+        self.query_all(query, max_items).await
+    }
     /// API Search Query for Profiles only.
     /// ```no_run
     /// # async {
@@ -225,6 +439,29 @@ impl<A: AuthToken> YtMusic<A> {
         let query = query.into();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::search_profiles`],
+    /// returning each page of results as it's received.
+    pub fn search_profiles_stream<'a, Q: Into<SearchQuery<'a, FilteredSearch<ProfilesFilter>>>>(
+        &'a self,
+        query: Q,
+    ) -> impl Stream<Item = Result<Vec<SearchResultProfile>>> {
+        let query = query.into();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::search_profiles`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn search_profiles_all<
+        'a,
+        Q: Into<SearchQuery<'a, FilteredSearch<ProfilesFilter>>>,
+    >(
+        &self,
+        query: Q,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultProfile>> {
+        let query = query.into();
+        self.query_all(query, max_items).await
+    }
     /// Gets information about an artist and their top releases.
     /// ```no_run
     /// # async {
@@ -314,6 +551,27 @@ impl<A: AuthToken> YtMusic<A> {
         let query = GetWatchPlaylistQuery::new_from_video_id(video_id.into());
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_watch_playlist_from_video_id`],
+    /// returning each page of results as it's received.
+    pub fn get_watch_playlist_from_video_id_stream<'a, S: Into<VideoID<'a>>>(
+        &'a self,
+        video_id: S,
+    ) -> impl Stream<Item = Result<Vec<WatchPlaylistTrack>>> {
+        let query = GetWatchPlaylistQuery::new_from_video_id(video_id.into());
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_watch_playlist_from_video_id`] that
+    /// fetches pages of results until `max_items` have been received.
+    /// Unlike the other `_all` functions `max_items` is required, as a watch
+    /// playlist can continue indefinitely.
+    pub async fn get_watch_playlist_from_video_id_all<'a, S: Into<VideoID<'a>>>(
+        &self,
+        video_id: S,
+        max_items: usize,
+    ) -> Result<Vec<WatchPlaylistTrack>> {
+        let query = GetWatchPlaylistQuery::new_from_video_id(video_id.into());
+        self.query_all(query, Some(max_items)).await
+    }
     /// Gets the `LyricsID` required to get lyrics.
     /// ```no_run
     /// # async {
@@ -369,6 +627,26 @@ impl<A: AuthToken> YtMusic<A> {
         let query = GetPlaylistTracksQuery::new(playlist_id.into());
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_playlist_tracks`],
+    /// returning each page of results as it's received.
+    pub fn get_playlist_tracks_stream<'a, T: Into<PlaylistID<'a>>>(
+        &'a self,
+        playlist_id: T,
+    ) -> impl Stream<Item = Result<Vec<PlaylistItem>>> {
+        let query = GetPlaylistTracksQuery::new(playlist_id.into());
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_playlist_tracks`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_playlist_tracks_all<'a, T: Into<PlaylistID<'a>>>(
+        &self,
+        playlist_id: T,
+        max_items: Option<usize>,
+    ) -> Result<Vec<PlaylistItem>> {
+        let query = GetPlaylistTracksQuery::new(playlist_id.into());
+        self.query_all(query, max_items).await
+    }
     /// Gets information about a playlist.
     /// ```no_run
     /// # async {
@@ -398,7 +676,7 @@ impl<A: AuthToken> YtMusic<A> {
     pub async fn get_search_suggestions<'a, S: Into<GetSearchSuggestionsQuery<'a>>>(
         &self,
         query: S,
-    ) -> Result<Vec<SearchSuggestion>> {
+    ) -> Result<SearchSuggestions> {
         let query = query.into();
         self.query(query).await
     }
@@ -466,6 +744,77 @@ impl<A: AuthToken> YtMusic<A> {
         self.query(GetMoodPlaylistsQuery::new(mood_params.into()))
             .await
     }
+    /// Fetches the Explore page - new albums & singles, 'Moods & Genres'
+    /// categories and new music videos.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// yt.get_explore().await
+    /// # };
+    /// ```
+    pub async fn get_explore(&self) -> Result<<GetExploreQuery as Query<A>>::Output> {
+        self.query(GetExploreQuery).await
+    }
+    /// Fetches new albums & singles from the Explore page.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// yt.get_new_releases().await
+    /// # };
+    /// ```
+    pub async fn get_new_releases(&self) -> Result<<GetNewReleasesQuery as Query<A>>::Output> {
+        self.query(GetNewReleasesQuery).await
+    }
+    /// Streaming version of [`YtMusic::get_new_releases`],
+    /// returning each page of results as it's received.
+    pub fn get_new_releases_stream(
+        &self,
+    ) -> impl Stream<Item = Result<<GetNewReleasesQuery as Query<A>>::Output>> {
+        self.stream_owned(GetNewReleasesQuery)
+    }
+    /// Version of [`YtMusic::get_new_releases`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_new_releases_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<<GetNewReleasesQuery as Query<A>>::Output> {
+        self.query_all(GetNewReleasesQuery, max_items).await
+    }
+    /// Fetches new music videos from the Explore page.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// yt.get_new_music_videos().await
+    /// # };
+    /// ```
+    pub async fn get_new_music_videos(
+        &self,
+    ) -> Result<<GetNewMusicVideosQuery as Query<A>>::Output> {
+        self.query(GetNewMusicVideosQuery).await
+    }
+    /// Streaming version of [`YtMusic::get_new_music_videos`],
+    /// returning each page of results as it's received.
+    pub fn get_new_music_videos_stream(
+        &self,
+    ) -> impl Stream<Item = Result<<GetNewMusicVideosQuery as Query<A>>::Output>> {
+        self.stream_owned(GetNewMusicVideosQuery)
+    }
+    /// Version of [`YtMusic::get_new_music_videos`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_new_music_videos_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<<GetNewMusicVideosQuery as Query<A>>::Output> {
+        self.query_all(GetNewMusicVideosQuery, max_items).await
+    }
     /// Get the 'SongTrackingUrl' for a song. This is used to add items to
     /// history using `add_history_item()`.
     /// ```no_run
@@ -490,6 +839,25 @@ impl<A: AuthToken> YtMusic<A> {
         let query = GetSongTrackingUrlQuery::new(video_id.into())?;
         self.query(query).await
     }
+    /// Get the details and streaming formats for a song. Stream urls generally
+    /// need deciphering before use - see [`crate::decipher`].
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::new_unauthenticated().await.unwrap();
+    /// let song = yt
+    ///     .search_songs("While My Guitar Gently Weeps")
+    ///     .await
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .next()
+    ///     .unwrap();
+    /// yt.get_song(song.video_id).await
+    /// # };
+    /// ```
+    pub async fn get_song<'a, T: Into<VideoID<'a>>>(&self, video_id: T) -> Result<Song> {
+        let query = GetSongQuery::new(video_id.into())?;
+        self.query(query).await
+    }
     /// Gets information about a Channel of Podcasts.
     /// ```no_run
     /// # async {
@@ -613,6 +981,32 @@ impl<A: AuthToken> YtMusic<A> {
         let query = GetUserVideosQuery::new(channel_id.into(), browse_params.into());
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_user_videos`],
+    /// returning each page of results as it's received.
+    pub fn get_user_videos_stream<'a, T: Into<UserChannelID<'a>>, U: Into<UserVideosParams<'a>>>(
+        &'a self,
+        channel_id: T,
+        browse_params: U,
+    ) -> impl Stream<Item = Result<Vec<UserVideo>>> {
+        let query = GetUserVideosQuery::new(channel_id.into(), browse_params.into());
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_user_videos`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_user_videos_all<
+        'a,
+        T: Into<UserChannelID<'a>>,
+        U: Into<UserVideosParams<'a>>,
+    >(
+        &self,
+        channel_id: T,
+        browse_params: U,
+        max_items: Option<usize>,
+    ) -> Result<Vec<UserVideo>> {
+        let query = GetUserVideosQuery::new(channel_id.into(), browse_params.into());
+        self.query_all(query, max_items).await
+    }
     /// Gets a full list of playlists for a user.
     /// ```no_run
     /// # async {
@@ -637,6 +1031,36 @@ impl<A: AuthToken> YtMusic<A> {
         let query = GetUserPlaylistsQuery::new(channel_id.into(), browse_params.into());
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_user_playlists`],
+    /// returning each page of results as it's received.
+    pub fn get_user_playlists_stream<
+        'a,
+        T: Into<UserChannelID<'a>>,
+        U: Into<UserPlaylistsParams<'a>>,
+    >(
+        &'a self,
+        channel_id: T,
+        browse_params: U,
+    ) -> impl Stream<Item = Result<Vec<UserPlaylist>>> {
+        let query = GetUserPlaylistsQuery::new(channel_id.into(), browse_params.into());
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_user_playlists`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_user_playlists_all<
+        'a,
+        T: Into<UserChannelID<'a>>,
+        U: Into<UserPlaylistsParams<'a>>,
+    >(
+        &self,
+        channel_id: T,
+        browse_params: U,
+        max_items: Option<usize>,
+    ) -> Result<Vec<UserPlaylist>> {
+        let query = GetUserPlaylistsQuery::new(channel_id.into(), browse_params.into());
+        self.query_all(query, max_items).await
+    }
 }
 
 impl<A: LoggedIn> YtMusic<A> {
@@ -707,6 +1131,24 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibraryUploadSongsQuery::default();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_upload_songs`],
+    /// returning each page of results as it's received.
+    pub fn get_library_upload_songs_stream(
+        &self,
+    ) -> impl Stream<Item = Result<<GetLibraryUploadSongsQuery as Query<A>>::Output>> {
+        let query = GetLibraryUploadSongsQuery::default();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_upload_songs`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_upload_songs_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<<GetLibraryUploadSongsQuery as Query<A>>::Output> {
+        let query = GetLibraryUploadSongsQuery::default();
+        self.query_all(query, max_items).await
+    }
     /// Gets a list of all uploaded artists in your Library.
     /// # Additional functionality
     /// See [`GetLibraryUploadArtistsQuery`] and [`YtMusic.query()`]
@@ -729,6 +1171,24 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibraryUploadArtistsQuery::default();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_upload_artists`],
+    /// returning each page of results as it's received.
+    pub fn get_library_upload_artists_stream(
+        &self,
+    ) -> impl Stream<Item = Result<<GetLibraryUploadArtistsQuery as Query<A>>::Output>> {
+        let query = GetLibraryUploadArtistsQuery::default();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_upload_artists`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_upload_artists_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<<GetLibraryUploadArtistsQuery as Query<A>>::Output> {
+        let query = GetLibraryUploadArtistsQuery::default();
+        self.query_all(query, max_items).await
+    }
     /// Gets a list of all uploaded albums in your Library.
     /// # Additional functionality
     /// See [`GetLibraryUploadAlbumsQuery`] and [`YtMusic.query()`]
@@ -751,6 +1211,24 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibraryUploadAlbumsQuery::default();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_upload_albums`],
+    /// returning each page of results as it's received.
+    pub fn get_library_upload_albums_stream(
+        &self,
+    ) -> impl Stream<Item = Result<<GetLibraryUploadAlbumsQuery as Query<A>>::Output>> {
+        let query = GetLibraryUploadAlbumsQuery::default();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_upload_albums`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_upload_albums_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<<GetLibraryUploadAlbumsQuery as Query<A>>::Output> {
+        let query = GetLibraryUploadAlbumsQuery::default();
+        self.query_all(query, max_items).await
+    }
     /// Gets information and tracks for an uploaded album in your Library.
     /// ```no_run
     /// # async {
@@ -785,6 +1263,26 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibraryUploadArtistQuery::new(upload_artist_id.into());
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_upload_artist`],
+    /// returning each page of results as it's received.
+    pub fn get_library_upload_artist_stream<'a, T: Into<UploadArtistID<'a>>>(
+        &'a self,
+        upload_artist_id: T,
+    ) -> impl Stream<Item = Result<<GetLibraryUploadArtistQuery<'a> as Query<A>>::Output>> {
+        let query = GetLibraryUploadArtistQuery::new(upload_artist_id.into());
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_upload_artist`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_upload_artist_all<'a, T: Into<UploadArtistID<'a>>>(
+        &self,
+        upload_artist_id: T,
+        max_items: Option<usize>,
+    ) -> Result<<GetLibraryUploadArtistQuery<'_> as Query<A>>::Output> {
+        let query = GetLibraryUploadArtistQuery::new(upload_artist_id.into());
+        self.query_all(query, max_items).await
+    }
     /// Deletes an upload entity from your library - this is either a song or an
     /// album.
     /// ```no_run
@@ -803,6 +1301,22 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = DeleteUploadEntityQuery::new(upload_entity_id.into());
         self.query(query).await
     }
+    /// Edits the title, artist or album of a song you have uploaded.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// let songs = yt.get_library_upload_songs().await.unwrap();
+    /// let query = ytmapi_rs::query::EditUploadSongQuery::new(songs[0].entity_id.clone())
+    ///     .with_new_title("Fixed title")
+    ///     .with_new_artist("Fixed artist");
+    /// yt.edit_upload_song(query).await
+    /// # };
+    /// ```
+    pub async fn edit_upload_song(&self, query: EditUploadSongQuery<'_>) -> Result<ApiOutcome> {
+        self.query(query).await
+    }
     /// Removes a list of items from your recently played history.
     /// ```no_run
     /// # async {
@@ -827,6 +1341,27 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = RemoveHistoryItemsQuery::new(feedback_tokens);
         self.query(query).await
     }
+    /// Removes a list of suggestions from your search history.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// let suggestions = yt.get_search_suggestions("The Beat").await.unwrap();
+    /// let history_tokens = suggestions
+    ///     .suggestions
+    ///     .into_iter()
+    ///     .filter_map(|s| s.feedback_token_remove);
+    /// yt.remove_search_suggestions(history_tokens).await
+    /// # };
+    /// ```
+    pub async fn remove_search_suggestions(
+        &self,
+        feedback_tokens: impl IntoIterator<Item = FeedbackTokenRemoveSearchSuggestion<'_>>,
+    ) -> Result<Vec<ApiOutcome>> {
+        let query = RemoveSearchSuggestionsQuery::new(feedback_tokens);
+        self.query(query).await
+    }
     // TODO: Docs / alternative constructors.
     pub async fn edit_song_library_status(
         &self,
@@ -931,7 +1466,7 @@ impl<A: LoggedIn> YtMusic<A> {
         &self,
         playlist_id: T,
         video_ids: impl IntoIterator<Item = VideoID<'a>>,
-    ) -> Result<Vec<AddPlaylistItem>> {
+    ) -> Result<Vec<AddPlaylistVideoResult>> {
         let query = AddPlaylistItemsQuery::new_from_videos(
             playlist_id.into(),
             video_ids,
@@ -978,6 +1513,22 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibraryPlaylistsQuery;
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_playlists`],
+    /// returning each page of results as it's received.
+    pub fn get_library_playlists_stream(&self) -> impl Stream<Item = Result<Vec<LibraryPlaylist>>> {
+        let query = GetLibraryPlaylistsQuery;
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_playlists`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_playlists_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<Vec<LibraryPlaylist>> {
+        let query = GetLibraryPlaylistsQuery;
+        self.query_all(query, max_items).await
+    }
     /// Gets a list of all artists in your Library.
     /// # Additional functionality
     /// See [`GetLibraryArtistsQuery`] and [`YtMusic.query()`]
@@ -998,6 +1549,22 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibraryArtistsQuery::default();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_artists`],
+    /// returning each page of results as it's received.
+    pub fn get_library_artists_stream(&self) -> impl Stream<Item = Result<Vec<LibraryArtist>>> {
+        let query = GetLibraryArtistsQuery::default();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_artists`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_artists_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<Vec<LibraryArtist>> {
+        let query = GetLibraryArtistsQuery::default();
+        self.query_all(query, max_items).await
+    }
     /// Gets a list of all songs in your Library.
     /// # Additional functionality
     /// See [`GetLibrarySongsQuery`] and [`YtMusic.query()`]
@@ -1018,6 +1585,24 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibrarySongsQuery::default();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_songs`],
+    /// returning each page of results as it's received.
+    pub fn get_library_songs_stream(
+        &self,
+    ) -> impl Stream<Item = Result<<GetLibrarySongsQuery as Query<A>>::Output>> {
+        let query = GetLibrarySongsQuery::default();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_songs`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_songs_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<<GetLibrarySongsQuery as Query<A>>::Output> {
+        let query = GetLibrarySongsQuery::default();
+        self.query_all(query, max_items).await
+    }
     /// Gets a list of all albums in your Library.
     /// # Additional functionality
     /// See [`GetLibraryAlbumsQuery`] and [`YtMusic.query()`]
@@ -1038,6 +1623,22 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibraryAlbumsQuery::default();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_albums`],
+    /// returning each page of results as it's received.
+    pub fn get_library_albums_stream(&self) -> impl Stream<Item = Result<Vec<SearchResultAlbum>>> {
+        let query = GetLibraryAlbumsQuery::default();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_albums`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_albums_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<Vec<SearchResultAlbum>> {
+        let query = GetLibraryAlbumsQuery::default();
+        self.query_all(query, max_items).await
+    }
     /// Gets a list of all artist subscriptions in your Library.
     /// # Additional functionality
     /// See [`GetLibraryArtistSubscriptionsQuery`] and [`YtMusic.query()`]
@@ -1058,6 +1659,24 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibraryArtistSubscriptionsQuery::default();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_artist_subscriptions`],
+    /// returning each page of results as it's received.
+    pub fn get_library_artist_subscriptions_stream(
+        &self,
+    ) -> impl Stream<Item = Result<Vec<LibraryArtistSubscription>>> {
+        let query = GetLibraryArtistSubscriptionsQuery::default();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_artist_subscriptions`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_artist_subscriptions_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<Vec<LibraryArtistSubscription>> {
+        let query = GetLibraryArtistSubscriptionsQuery::default();
+        self.query_all(query, max_items).await
+    }
     /// Gets a list of all podcasts in your Library.
     /// # Additional functionality
     /// See [`GetLibraryPodcastsQuery`] and [`YtMusic.query()`]
@@ -1080,6 +1699,24 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibraryPodcastsQuery::default();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_podcasts`],
+    /// returning each page of results as it's received.
+    pub fn get_library_podcasts_stream(
+        &self,
+    ) -> impl Stream<Item = Result<<GetLibraryPodcastsQuery as Query<A>>::Output>> {
+        let query = GetLibraryPodcastsQuery::default();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_podcasts`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_podcasts_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<<GetLibraryPodcastsQuery as Query<A>>::Output> {
+        let query = GetLibraryPodcastsQuery::default();
+        self.query_all(query, max_items).await
+    }
     /// Gets a list of all channels in your Library.
     /// # Additional functionality
     /// See [`GetLibraryChannelsQuery`] and [`YtMusic.query()`]
@@ -1102,6 +1739,24 @@ impl<A: LoggedIn> YtMusic<A> {
         let query = GetLibraryChannelsQuery::default();
         self.query(query).await
     }
+    /// Streaming version of [`YtMusic::get_library_channels`],
+    /// returning each page of results as it's received.
+    pub fn get_library_channels_stream(
+        &self,
+    ) -> impl Stream<Item = Result<<GetLibraryChannelsQuery as Query<A>>::Output>> {
+        let query = GetLibraryChannelsQuery::default();
+        self.stream_owned(query)
+    }
+    /// Version of [`YtMusic::get_library_channels`] that
+    /// fetches all pages of results, stopping early once `max_items` have
+    /// been received (if set).
+    pub async fn get_library_channels_all(
+        &self,
+        max_items: Option<usize>,
+    ) -> Result<<GetLibraryChannelsQuery as Query<A>>::Output> {
+        let query = GetLibraryChannelsQuery::default();
+        self.query_all(query, max_items).await
+    }
     /// Gets your recently played history.
     /// ```no_run
     /// # async {
@@ -1179,4 +1834,136 @@ impl<A: LoggedIn> YtMusic<A> {
         ))
         .await
     }
+    /// Subscribe to a podcast, adding it to your library.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// let podcasts = yt.search_podcasts("Rustacean").await.unwrap();
+    /// yt.subscribe_podcast(&podcasts[0].podcast_id).await
+    /// # };
+    /// ```
+    pub async fn subscribe_podcast(&self, podcast_id: impl Into<PodcastID<'_>>) -> Result<()> {
+        self.query(SubscribePodcastQuery::new(podcast_id)).await
+    }
+    /// Unsubscribe from a podcast, removing it from your library.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// let podcasts = yt.search_podcasts("Rustacean").await.unwrap();
+    /// yt.unsubscribe_podcast(&podcasts[0].podcast_id).await
+    /// # };
+    /// ```
+    pub async fn unsubscribe_podcast(&self, podcast_id: impl Into<PodcastID<'_>>) -> Result<()> {
+        self.query(UnsubscribePodcastQuery::new(podcast_id)).await
+    }
+    /// Marks an episode as played.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// let episodes = yt.search_episodes("Ratatui").await.unwrap();
+    /// let episode = yt.get_episode(&episodes[0].episode_id).await.unwrap();
+    /// let played_manager = episode.played_manager.unwrap();
+    /// yt.mark_episode_played(played_manager.mark_played_token).await
+    /// # };
+    /// ```
+    pub async fn mark_episode_played<'a>(
+        &self,
+        feedback_token: impl Into<FeedbackTokenMarkPlayed<'a>>,
+    ) -> Result<ApiOutcome> {
+        self.query(MarkEpisodePlayedQuery::new(feedback_token))
+            .await
+    }
+    /// Marks an episode as unplayed, resetting its resume position.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// let episodes = yt.search_episodes("Ratatui").await.unwrap();
+    /// let episode = yt.get_episode(&episodes[0].episode_id).await.unwrap();
+    /// let played_manager = episode.played_manager.unwrap();
+    /// yt.mark_episode_unplayed(played_manager.mark_unplayed_token).await
+    /// # };
+    /// ```
+    pub async fn mark_episode_unplayed<'a>(
+        &self,
+        feedback_token: impl Into<FeedbackTokenMarkUnplayed<'a>>,
+    ) -> Result<ApiOutcome> {
+        self.query(MarkEpisodeUnplayedQuery::new(feedback_token))
+            .await
+    }
+    /// Fetches the "Listen again" shelf from the Home page. Empty if the shelf
+    /// is not shown for this account.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// yt.get_listen_again().await
+    /// # };
+    /// ```
+    pub async fn get_listen_again(&self) -> Result<Vec<ListenAgainItem>> {
+        self.query(GetListenAgainQuery).await
+    }
+    /// Pins an item to "Listen again".
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// let items = yt.get_listen_again().await.unwrap();
+    /// let pin_manager = items[0].pin_manager.clone().unwrap();
+    /// yt.pin_to_listen_again(pin_manager.pin_token).await
+    /// # };
+    /// ```
+    pub async fn pin_to_listen_again<'a>(
+        &self,
+        feedback_token: impl Into<FeedbackTokenPinToListenAgain<'a>>,
+    ) -> Result<ApiOutcome> {
+        self.query(PinToListenAgainQuery::new(feedback_token)).await
+    }
+    /// Unpins an item from "Listen again".
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE")
+    ///     .await
+    ///     .unwrap();
+    /// let items = yt.get_listen_again().await.unwrap();
+    /// let pin_manager = items[0].pin_manager.clone().unwrap();
+    /// yt.unpin_from_listen_again(pin_manager.unpin_token).await
+    /// # };
+    /// ```
+    pub async fn unpin_from_listen_again<'a>(
+        &self,
+        feedback_token: impl Into<FeedbackTokenUnpinFromListenAgain<'a>>,
+    ) -> Result<ApiOutcome> {
+        self.query(UnpinFromListenAgainQuery::new(feedback_token))
+            .await
+    }
+}
+impl<A: AuthToken> YtMusic<A> {
+    /// Stream an owned query - the simplified queries construct their query
+    /// internally, so can't use [`YtMusic::stream`].
+    fn stream_owned<'a, Q>(&'a self, query: Q) -> impl Stream<Item = Result<Q::Output>> + 'a
+    where
+        Q: Query<A> + PostQuery + 'a,
+        Q::Output: ParseFromContinuable<Q>,
+    {
+        continuations::stream::<Q, A>(query, &self.client, &self.token)
+    }
+    /// Run an owned query, fetching all continuations until there are none
+    /// left, or `max_items` have been received.
+    async fn query_all<Q, T>(&self, query: Q, max_items: Option<usize>) -> Result<Vec<T>>
+    where
+        Q: Query<A, Output = Vec<T>> + PostQuery,
+        Vec<T>: ParseFromContinuable<Q>,
+    {
+        continuations::collect_all(self.stream_owned(query), max_items).await
+    }
 }
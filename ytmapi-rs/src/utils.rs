@@ -43,8 +43,9 @@ pub fn hash_sapisid(sapisid: &str) -> String {
 /// Macro to generate the boilerplate code that allows implementation of
 /// YoutubeID for a simple struct. In addition implements a convenient From
 /// implementation.
+/// Optionally, a function to validate the format of a raw ID can be provided.
 macro_rules! impl_youtube_id {
-    ($t:ty) => {
+    ($t:ty $(, $validate:expr)?) => {
         impl<'a> YoutubeID<'a> for $t {
             fn get_raw(&self) -> &str {
                 &self.0
@@ -52,6 +53,12 @@ macro_rules! impl_youtube_id {
             fn from_raw<S: Into<Cow<'a, str>>>(raw_str: S) -> Self {
                 Self(raw_str.into())
             }
+            $(
+            fn is_valid_raw(raw_str: &str) -> bool {
+                let validate: fn(&str) -> bool = $validate;
+                validate(raw_str)
+            }
+            )?
         }
         impl<'a> From<&'a $t> for $t {
             fn from(value: &'a $t) -> Self {
@@ -62,6 +69,37 @@ macro_rules! impl_youtube_id {
     };
 }
 
+/// Macro to generate typed accessors for numeric fields that Innertube only
+/// returns as display strings, e.g "1.2M plays". The raw field is retained.
+/// Kinds are `duration`, `count` and `year`.
+macro_rules! impl_parsed_fields {
+    ($t:ty { $($method:ident: $field:ident as $kind:ident),+ $(,)? }) => {
+        impl $t {
+            $(impl_parsed_fields!(@accessor $method, $field, $kind);)+
+        }
+    };
+    (@accessor $method:ident, $field:ident, duration) => {
+        #[doc = concat!("Parse `", stringify!($field), "` as a Duration, if possible.")]
+        pub fn $method(&self) -> Option<std::time::Duration> {
+            crate::parse::numeric::RawField::parse_raw(&self.$field, crate::parse::parse_duration)
+        }
+    };
+    (@accessor $method:ident, $field:ident, count) => {
+        #[doc = concat!(
+            "Parse `", stringify!($field), "` as a count, if possible. Only English suffixes are supported - see `parse_count_with_suffixes` for other languages."
+        )]
+        pub fn $method(&self) -> Option<u64> {
+            crate::parse::numeric::RawField::parse_raw(&self.$field, crate::parse::parse_count)
+        }
+    };
+    (@accessor $method:ident, $field:ident, year) => {
+        #[doc = concat!("Parse `", stringify!($field), "` as a year, if possible.")]
+        pub fn $method(&self) -> Option<u16> {
+            crate::parse::numeric::RawField::parse_raw(&self.$field, crate::parse::parse_year)
+        }
+    };
+}
+
 /// Macro that will print a warning to stderr if this branch is taken and
 /// ab-warning feature is enabled.
 macro_rules! ab_warn {
@@ -22,6 +22,36 @@ pub(crate) enum YoutubeMusicPageType {
     Playlist,
 }
 
+/// Used to distinguish between carousels on a user's channel page, since the
+/// carousel titles are language dependent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) enum YoutubeMusicCarouselItemPageType {
+    #[serde(rename = "MUSIC_PAGE_TYPE_ALBUM")]
+    Album,
+    #[serde(rename = "MUSIC_PAGE_TYPE_PLAYLIST")]
+    Playlist,
+    #[serde(other)]
+    Other,
+}
+
+/// Used to determine the type of a search suggestion entity from its browse
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) enum YoutubeMusicEntityPageType {
+    #[serde(rename = "MUSIC_PAGE_TYPE_ARTIST")]
+    Artist,
+    #[serde(rename = "MUSIC_PAGE_TYPE_ALBUM")]
+    Album,
+    #[serde(rename = "MUSIC_PAGE_TYPE_PLAYLIST")]
+    Playlist,
+    #[serde(rename = "MUSIC_PAGE_TYPE_PODCAST_SHOW_DETAIL_PAGE")]
+    Podcast,
+    #[serde(rename = "MUSIC_PAGE_TYPE_USER_CHANNEL")]
+    Profile,
+    #[serde(other)]
+    Other,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) enum YoutubeMusicVideoType {
     // I believe OMV is 'Official Music Video' and UGC is 'User Generated Content'
@@ -30,12 +30,16 @@ async fn test_refresh_expired_oauth() {
 
 #[tokio::test]
 async fn test_get_oauth_code() {
-    let client = crate::client::Client::new().unwrap();
+    let client = utils::new_client().unwrap();
     let (client_id, _) = get_oauth_client_id_and_secret().unwrap();
     let _code = OAuthTokenGenerator::new(&client, client_id).await.unwrap();
 }
 
 // NOTE: Internal only - due to use of error.is_oauth_expired()
+#[cfg_attr(
+    feature = "test-offline",
+    ignore = "OAuth expiry is detected client side"
+)]
 #[tokio::test]
 async fn test_expired_oauth() {
     // XXX: Assuming this error only occurs for expired headers.
@@ -53,6 +57,20 @@ async fn test_expired_oauth() {
         ErrorKind::OAuthTokenExpired { .. }
     ));
 }
+#[cfg(feature = "test-offline")]
+#[tokio::test]
+async fn test_expired_browser_auth() {
+    let api = utils::offline::new_browser_api(utils::offline::expired_auth_server_url())
+        .await
+        .unwrap();
+    let Err(error) = api.json_query(GetLibraryPlaylistsQuery).await else {
+        panic!("Expected an error")
+    };
+    assert!(matches!(
+        error.into_kind(),
+        ErrorKind::OtherErrorCodeInResponse { code: 401, .. }
+    ));
+}
 // Placeholder for future implementation.
 // #[tokio::test]
 // async fn test_expired_header() {
@@ -107,6 +125,8 @@ generate_stream_test_logged_in!(
     test_stream_get_library_podcasts,
     GetLibraryPodcastsQuery::default()
 );
+generate_stream_test_logged_in!(test_stream_get_new_releases, GetNewReleasesQuery);
+generate_stream_test_logged_in!(test_stream_get_new_music_videos, GetNewMusicVideosQuery);
 generate_stream_test_logged_in!(
     test_stream_get_library_channels,
     GetLibraryChannelsQuery::default()
@@ -182,9 +202,11 @@ generate_query_test!(
 );
 
 generate_query_test!(test_get_mood_categories, GetMoodCategoriesQuery);
+generate_query_test!(test_get_explore, GetExploreQuery);
 // NOTE: Set Taste Profile test is not implemented, to avoid impact to my YTM
 // recommendations.
 generate_query_test!(test_get_taste_profile, GetTasteProfileQuery);
+generate_query_test_logged_in!(test_get_listen_again, GetListenAgainQuery);
 generate_query_test_logged_in!(test_get_history, GetHistoryQuery);
 generate_query_test!(
     test_get_channel,
@@ -193,6 +215,7 @@ generate_query_test!(
 );
 // NOTE: Can be flaky - visiting this page on the website seems to reset it.
 generate_query_test!(
+    #[cfg_attr(feature = "test-offline", ignore = "No channel episodes fixture")]
     test_get_channel_episodes,
     // Rustacean Station
     GetChannelEpisodesQuery::new(
@@ -277,6 +300,32 @@ async fn test_get_mood_playlists() {
     browser_api.query(query.clone()).await.unwrap();
 }
 
+#[tokio::test]
+async fn test_search_songs_all() {
+    let api = utils::new_noauth_api().await.unwrap();
+    let first_page = api.search_songs("Beatles").await.unwrap();
+    // Request one more than the first page, to ensure a continuation is fetched
+    // and the result is capped.
+    let max_items = first_page.len() + 1;
+    let songs = api
+        .search_songs_all("Beatles", Some(max_items))
+        .await
+        .unwrap();
+    assert_eq!(songs.len(), max_items);
+}
+
+#[tokio::test]
+async fn test_get_library_songs_stream() {
+    let browser_api = crate::utils::new_standard_api().await.unwrap();
+    let pages = browser_api
+        .get_library_songs_stream()
+        .take(2)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert!(!pages.is_empty());
+}
+
 #[ignore = "Ignored by default due to quota"]
 #[tokio::test]
 async fn test_get_library_upload_artist() {
@@ -314,7 +363,7 @@ async fn test_get_library_upload_album() {
 
 #[tokio::test]
 async fn test_get_artist_albums() {
-    let api = YtMusic::new_unauthenticated().await.unwrap();
+    let api = utils::new_noauth_api().await.unwrap();
     let q = GetArtistQuery::new(ArtistChannelID::from_raw(
         // Metallica
         "UCGexNm_Kw4rdQjLxmpb2EKw",
@@ -325,9 +374,13 @@ async fn test_get_artist_albums() {
     let channel_id = albums.browse_id.unwrap();
     api.get_artist_albums(channel_id, params).await.unwrap();
 }
+#[cfg_attr(
+    feature = "test-offline",
+    ignore = "No user fixture with a videos shelf"
+)]
 #[tokio::test]
 async fn test_get_user_videos() {
-    let api = YtMusic::new_unauthenticated().await.unwrap();
+    let api = utils::new_noauth_api().await.unwrap();
     // Turbo
     let channel_id = UserChannelID::from_raw("UCus8EVJ7Oc9zINhs-fg8l1Q");
     let user = api.get_user(&channel_id).await.unwrap();
@@ -335,9 +388,10 @@ async fn test_get_user_videos() {
         .await
         .unwrap();
 }
+#[cfg_attr(feature = "test-offline", ignore = "No user playlists fixture")]
 #[tokio::test]
 async fn test_get_user_playlists() {
-    let api = YtMusic::new_unauthenticated().await.unwrap();
+    let api = utils::new_noauth_api().await.unwrap();
     // kamarillobrillo
     let channel_id = UserChannelID::from_raw("UCj0boSvCVfTmO9JHlclA8eQ");
     let user = api.get_user(&channel_id).await.unwrap();
@@ -348,7 +402,7 @@ async fn test_get_user_playlists() {
 
 #[tokio::test]
 async fn test_get_artist_album_songs() {
-    let api = YtMusic::new_unauthenticated().await.unwrap();
+    let api = utils::new_noauth_api().await.unwrap();
     let q = GetArtistQuery::new(ArtistChannelID::from_raw(
         // Metallica
         "UCGexNm_Kw4rdQjLxmpb2EKw",
@@ -697,7 +751,7 @@ async fn test_add_remove_playlist_items() {
         .await
         .unwrap()
         .into_iter()
-        .map(|item| item.set_video_id);
+        .filter_map(|item| item.set_video_id().cloned());
     api.remove_playlist_items(&id, set_video_ids).await.unwrap();
     api.delete_playlist(id).await.unwrap();
 }
@@ -758,6 +812,10 @@ async fn test_get_library_artists() {
     let res = api.get_library_artists().await.unwrap();
     assert!(!res.is_empty());
 }
+#[cfg_attr(
+    feature = "test-offline",
+    ignore = "Lyrics fixture is for a different song"
+)]
 #[tokio::test]
 async fn test_get_lyrics() {
     // TODO: Make more generic
@@ -1,11 +1,30 @@
 #![allow(clippy::unwrap_used)]
+#[cfg(not(feature = "test-offline"))]
 use std::env::{self, VarError};
+#[cfg(not(feature = "test-offline"))]
 use std::path::Path;
+#[cfg(not(feature = "test-offline"))]
 use tokio::sync::OnceCell;
+#[cfg(not(feature = "test-offline"))]
+use ytmapi_rs::auth::noauth::NoAuthToken;
+#[cfg(not(feature = "test-offline"))]
 use ytmapi_rs::auth::{BrowserToken, OAuthToken};
+#[cfg(not(feature = "test-offline"))]
 use ytmapi_rs::{Client, Result, YtMusic};
 
+/// When running offline, the same api constructors are provided, but connect
+/// to a local mock server.
+#[cfg(feature = "test-offline")]
+pub mod offline;
+#[cfg(feature = "test-offline")]
+pub use offline::{
+    get_oauth_client_id_and_secret, new_client, new_noauth_api, new_standard_api,
+    new_standard_oauth_api,
+};
+
+#[cfg(not(feature = "test-offline"))]
 pub const COOKIE_PATH: &str = "cookie.txt";
+#[cfg(not(feature = "test-offline"))]
 pub const EXPIRED_OAUTH_PATH: &str = "oauth.json";
 // Cookie filled with nonsense values to test this case.
 // pub const INVALID_COOKIE: &str = "HSID=abc; SSID=abc; APISID=abc; SAPISID=abc; __Secure-1PAPISID=abc; __Secure-3PAPISID=abc; YSC=abc; LOGIN_INFO=abc; VISITOR_INFO1_LIVE=abc; _gcl_au=abc; PREF=tz=Australia.Perth&f6=40000000&f7=abc; VISITOR_PRIVACY_METADATA=abc; __Secure-1PSIDTS=abc; __Secure-3PSIDTS=abc; SID=abc; __Secure-1PSID=abc; __Secure-3PSID=abc; SIDCC=abc; __Secure-1PSIDCC=abc; __Secure-3PSIDCC=abc";
@@ -24,9 +43,11 @@ pub const EXPIRED_OAUTH_PATH: &str = "oauth.json";
 
 /// To avoid refreshing OAuthToken on every API call, it's refreshed on
 /// initialization and stored here.
+#[cfg(not(feature = "test-offline"))]
 static OAUTH_TOKEN: OnceCell<OAuthToken> = OnceCell::const_new();
 
 /// (client_id, client_secret)
+#[cfg(not(feature = "test-offline"))]
 pub fn get_oauth_client_id_and_secret() -> std::result::Result<(String, String), VarError> {
     let client_id = std::env::var("youtui_client_id")?;
     let client_secret = std::env::var("youtui_client_secret")?;
@@ -38,6 +59,7 @@ pub fn get_oauth_client_id_and_secret() -> std::result::Result<(String, String),
 // The cause of the web errors is that each tokio::test has its own runtime.
 // To resolve this, we'll need a shared runtime as well as a static containing
 // the API.
+#[cfg(not(feature = "test-offline"))]
 pub async fn new_standard_oauth_api() -> Result<YtMusic<OAuthToken>> {
     let oauth_token = OAUTH_TOKEN
         .get_or_init(|| async {
@@ -61,6 +83,7 @@ pub async fn new_standard_oauth_api() -> Result<YtMusic<OAuthToken>> {
 // The cause of the web errors is that each tokio::test has its own runtime.
 // To resolve this, we'll need a shared runtime as well as a static containing
 // the API.
+#[cfg(not(feature = "test-offline"))]
 pub async fn new_standard_api() -> Result<YtMusic<BrowserToken>> {
     if let Ok(cookie) = env::var("youtui_test_cookie") {
         YtMusic::from_cookie(cookie).await
@@ -68,6 +91,14 @@ pub async fn new_standard_api() -> Result<YtMusic<BrowserToken>> {
         YtMusic::from_cookie_file(Path::new(COOKIE_PATH)).await
     }
 }
+#[cfg(not(feature = "test-offline"))]
+pub async fn new_noauth_api() -> Result<YtMusic<NoAuthToken>> {
+    YtMusic::new_unauthenticated().await
+}
+#[cfg(not(feature = "test-offline"))]
+pub fn new_client() -> Result<Client> {
+    Client::new()
+}
 
 /// Macro to generate both oauth and browser tests for provided query.
 /// Attributes like #[ignore] can be passed as the optional first argument.
@@ -119,7 +150,7 @@ macro_rules! generate_query_test {
             $(#[$m])*
             #[tokio::test]
             async fn [<$fname _noauth>]() {
-                let api = YtMusic::new_unauthenticated().await.unwrap();
+                let api = crate::utils::new_noauth_api().await.unwrap();
                 api.query($query)
                     .await
                     .expect("Expected query to run succesfully without auth");
@@ -165,7 +196,7 @@ macro_rules! generate_stream_test {
             #[tokio::test]
             async fn [<$fname _noauth>]() {
                 use futures::stream::{StreamExt, TryStreamExt};
-                let api = YtMusic::new_unauthenticated().await.unwrap();
+                let api = crate::utils::new_noauth_api().await.unwrap();
                 let query = $query;
                 let stream = api.stream(&query);
                 tokio::pin!(stream);
@@ -0,0 +1,455 @@
+//! Offline mode for the live integration tests, enabled by feature
+//! `test-offline`. Instead of YouTube Music, queries are sent to a local
+//! `ytmapi-mock-server` serving the parse test fixtures in `test_json`.
+//!
+//! Each route below corresponds to a query sent by a live test - when adding a
+//! live test, add a route for it here, or ignore it under `test-offline`.
+use std::env::VarError;
+use std::path::Path;
+use std::sync::OnceLock;
+use ytmapi_mock_server::{MockServer, Route, Routes};
+use ytmapi_rs::auth::noauth::NoAuthToken;
+use ytmapi_rs::auth::{BrowserToken, OAuthToken, OAuthTokenGenerator};
+use ytmapi_rs::builder::YtMusicBuilder;
+use ytmapi_rs::common::{
+    ArtistChannelID, EpisodeID, LikeStatus, PlaylistID, PodcastChannelID, PodcastID, UserChannelID,
+    VideoID, YoutubeID,
+};
+use ytmapi_rs::query::search::{
+    AlbumsFilter, ArtistsFilter, CommunityPlaylistsFilter, EpisodesFilter, FeaturedPlaylistsFilter,
+    PlaylistsFilter, PodcastsFilter, ProfilesFilter, SongsFilter, VideosFilter,
+};
+use ytmapi_rs::query::*;
+use ytmapi_rs::{Client, Result, YtMusic};
+
+const FIXTURE_DIR: &str = "test_json";
+/// Cookie accepted by the mock server - only the SAPISID is required.
+pub const MOCK_COOKIE: &str = "SAPISID=ytmapi-mock-server; __Secure-3PAPISID=ytmapi-mock-server;";
+pub const MOCK_CLIENT_ID: &str = "ytmapi-mock-server-client-id";
+pub const MOCK_CLIENT_SECRET: &str = "ytmapi-mock-server-client-secret";
+
+static SERVER_URL: OnceLock<String> = OnceLock::new();
+static EXPIRED_AUTH_SERVER_URL: OnceLock<String> = OnceLock::new();
+
+/// Base url of the shared mock server serving the fixtures, starting it if
+/// required.
+pub fn server_url() -> &'static str {
+    SERVER_URL.get_or_init(|| start_server(routes))
+}
+
+/// Base url of a mock server that treats all credentials as expired.
+pub fn expired_auth_server_url() -> &'static str {
+    EXPIRED_AUTH_SERVER_URL.get_or_init(|| start_server(|| routes().with_expired_auth()))
+}
+
+/// (client_id, client_secret)
+pub fn get_oauth_client_id_and_secret() -> std::result::Result<(String, String), VarError> {
+    Ok((MOCK_CLIENT_ID.to_string(), MOCK_CLIENT_SECRET.to_string()))
+}
+pub async fn new_standard_oauth_api() -> Result<YtMusic<OAuthToken>> {
+    new_oauth_api(server_url()).await
+}
+pub async fn new_standard_api() -> Result<YtMusic<BrowserToken>> {
+    new_browser_api(server_url()).await
+}
+pub async fn new_noauth_api() -> Result<YtMusic<NoAuthToken>> {
+    YtMusicBuilder::new()
+        .with_base_url(server_url())
+        .build()
+        .await
+}
+pub fn new_client() -> Result<Client> {
+    Client::new()?.with_base_url(server_url())
+}
+/// Browser auth api using the mock server at `base_url`.
+pub async fn new_browser_api(base_url: &str) -> Result<YtMusic<BrowserToken>> {
+    YtMusicBuilder::new()
+        .with_base_url(base_url)
+        .with_browser_token_cookie(MOCK_COOKIE.to_string())
+        .build()
+        .await
+}
+/// OAuth api using the mock server at `base_url`, with a token generated by
+/// the mock server.
+pub async fn new_oauth_api(base_url: &str) -> Result<YtMusic<OAuthToken>> {
+    let client = Client::new()?.with_base_url(base_url)?;
+    let code = OAuthTokenGenerator::new(&client, MOCK_CLIENT_ID).await?;
+    let token = OAuthToken::from_code(
+        &client,
+        code.device_code,
+        MOCK_CLIENT_ID,
+        MOCK_CLIENT_SECRET,
+    )
+    .await?;
+    YtMusicBuilder::new_with_client(client)
+        .with_auth_token(token)
+        .build()
+}
+
+/// Each tokio::test has its own runtime, so a server spawned on a test's
+/// runtime would stop when that test finishes. Instead, the server is run on
+/// its own runtime, in a thread that lives for the duration of the tests.
+fn start_server(routes: fn() -> Routes) -> String {
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let server = MockServer::start(routes()).await.unwrap();
+            tx.send(server.base_url()).unwrap();
+            std::future::pending::<()>().await
+        })
+    });
+    rx.recv().unwrap()
+}
+
+fn fixture(name: &str) -> String {
+    Path::new(FIXTURE_DIR)
+        .join(name)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Parse a fixture, for routes that depend on the output of a previous query.
+fn parse_fixture<Q: Query<BrowserToken>>(name: &str, query: Q) -> Q::Output {
+    let json = std::fs::read_to_string(fixture(name)).unwrap();
+    ytmapi_rs::process_json::<Q, BrowserToken>(json, query).unwrap()
+}
+
+/// Route matching every field `query` sends in the request body.
+fn query_route(query: impl PostQuery) -> Route {
+    query
+        .header()
+        .into_iter()
+        .fold(Route::post(query.path()), |route, (key, value)| {
+            route.with_body_field(format!("/{key}"), value)
+        })
+}
+
+/// Route for `query` that serves `pages` in turn as it is continued.
+fn paged_route<const N: usize>(query: impl PostQuery, pages: [&str; N]) -> Route {
+    query_route(query).respond_with_pages(pages.map(fixture))
+}
+
+// SearchQuery::new is deprecated, but is still what the live tests use.
+#[allow(deprecated)]
+fn routes() -> Routes {
+    let metallica = ArtistChannelID::from_raw("UCGexNm_Kw4rdQjLxmpb2EKw");
+    let artist_albums = parse_fixture("get_artist_20240705.json", GetArtistQuery::new(&metallica))
+        .top_releases
+        .albums
+        .unwrap();
+    let artist_albums_query = GetArtistAlbumsQuery::new(
+        ArtistChannelID::from_raw(artist_albums.browse_id.unwrap().get_raw().to_owned()),
+        artist_albums.params.unwrap(),
+    );
+    let first_album_id = parse_fixture("browse_artist_albums.json", artist_albums_query.clone())
+        .into_iter()
+        .next()
+        .unwrap()
+        .browse_id;
+    let first_mood_params =
+        parse_fixture("get_mood_categories_20240723.json", GetMoodCategoriesQuery)
+            .into_iter()
+            .next()
+            .unwrap()
+            .mood_categories
+            .into_iter()
+            .next()
+            .unwrap()
+            .params;
+    let lyrics_id = parse_fixture(
+        "get_watch_playlist_20250630.json",
+        GetLyricsIDQuery::new(VideoID::from_raw("lYBUbBu4W08")),
+    );
+    let beatles_playlist = PlaylistID::from_raw("VLPL0jp-uZ7a4g9FQWW5R_u0pz4yzV4RiOXu");
+    let search_beatles = || SearchQuery::new("Beatles");
+    let rate_song_routes = [
+        (
+            "kfSQkZuIx84",
+            LikeStatus::Liked,
+            "rate_song_like_20240710.json",
+        ),
+        (
+            "EjHzPrBCgf0",
+            LikeStatus::Disliked,
+            "rate_song_dislike_20240710.json",
+        ),
+        (
+            "kfSQkZuIx84",
+            LikeStatus::Indifferent,
+            "rate_song_indifferent_20240710.json",
+        ),
+        (
+            "EjHzPrBCgf0",
+            LikeStatus::Indifferent,
+            "rate_song_indifferent_20240710.json",
+        ),
+    ]
+    .map(|(id, status, page)| {
+        paged_route(RateSongQuery::new(VideoID::from_raw(id), status), [page])
+    });
+    let rate_playlist_routes = [
+        (
+            "RDCLAK5uy_lHIiCEeknPkpJOowyykpfBu-ECJB9Q32I",
+            LikeStatus::Liked,
+            "rate_playlist_like_20240710.json",
+        ),
+        (
+            "PL0jp-uZ7a4g9FQWW5R_u0pz4yzV4RiOXu",
+            LikeStatus::Disliked,
+            "rate_playlist_dislike_20240710.json",
+        ),
+        (
+            "RDCLAK5uy_lHIiCEeknPkpJOowyykpfBu-ECJB9Q32I",
+            LikeStatus::Indifferent,
+            "rate_playlist_indifferent_20240710.json",
+        ),
+        (
+            "PL0jp-uZ7a4g9FQWW5R_u0pz4yzV4RiOXu",
+            LikeStatus::Indifferent,
+            "rate_playlist_indifferent_20240710.json",
+        ),
+    ]
+    .map(|(id, status, page)| {
+        paged_route(
+            RatePlaylistQuery::new(PlaylistID::from_raw(id), status),
+            [page],
+        )
+    });
+    [
+        // Library
+        paged_route(
+            GetLibrarySongsQuery::default(),
+            [
+                "get_library_songs_20240701.json",
+                "get_library_songs_continuation_20240910.json",
+            ],
+        ),
+        paged_route(
+            GetLibraryArtistsQuery::default(),
+            [
+                "get_library_artists.json",
+                "get_library_artists_continuation_mock.json",
+            ],
+        ),
+        paged_route(
+            GetLibraryPlaylistsQuery,
+            [
+                "get_library_playlists.json",
+                "get_library_playlists_continuation_mock.json",
+            ],
+        ),
+        paged_route(
+            GetLibraryAlbumsQuery::default(),
+            [
+                "get_library_albums_20240701.json",
+                "get_library_albums_continuation_mock.json",
+            ],
+        ),
+        paged_route(
+            GetLibraryArtistSubscriptionsQuery::default(),
+            [
+                "get_library_artist_subscriptions_20240701.json",
+                "get_library_artist_subscriptions_continuation_mock.json",
+            ],
+        ),
+        paged_route(
+            GetLibraryPodcastsQuery::default(),
+            [
+                "get_library_podcasts_20250626.json",
+                "get_library_podcasts_continuation_20250626.json",
+            ],
+        ),
+        paged_route(
+            GetLibraryChannelsQuery::default(),
+            [
+                "get_library_channels_20250626.json",
+                "get_library_channels_continuation_20250626.json",
+            ],
+        ),
+        paged_route(GetHistoryQuery, ["get_history_20240713.json"]),
+        // Editing library status and search suggestions share the feedback
+        // endpoint and response.
+        Route::post("feedback").respond_with_file(fixture("remove_search_suggestions_mock.json")),
+        // Explore
+        paged_route(
+            GetNewReleasesQuery,
+            [
+                "get_new_releases_mock.json",
+                "get_new_releases_continuation_mock.json",
+            ],
+        ),
+        paged_route(
+            GetNewMusicVideosQuery,
+            [
+                "get_new_music_videos_mock.json",
+                "get_new_music_videos_continuation_mock.json",
+            ],
+        ),
+        paged_route(GetExploreQuery, ["get_explore_mock.json"]),
+        paged_route(
+            GetMoodCategoriesQuery,
+            ["get_mood_categories_20240723.json"],
+        ),
+        paged_route(
+            GetMoodPlaylistsQuery::new(first_mood_params),
+            ["get_mood_playlists_20240723.json"],
+        ),
+        paged_route(GetTasteProfileQuery, ["get_taste_profile_20240722.json"]),
+        paged_route(GetListenAgainQuery, ["get_listen_again_mock.json"]),
+        // Search
+        paged_route(
+            search_beatles().with_filter(ArtistsFilter),
+            [
+                "search_artists_20231226.json",
+                "search_artists_continuation_20231226.json",
+            ],
+        ),
+        paged_route(
+            search_beatles().with_filter(SongsFilter),
+            [
+                "search_songs_20231226.json",
+                "search_songs_continuation_20231226.json",
+            ],
+        ),
+        paged_route(
+            search_beatles().with_filter(AlbumsFilter),
+            [
+                "search_albums_20231226.json",
+                "search_albums_continuation_20231226.json",
+            ],
+        ),
+        paged_route(
+            search_beatles().with_filter(VideosFilter),
+            [
+                "search_videos_20240612.json",
+                "search_videos_continuation_20240612.json",
+            ],
+        ),
+        paged_route(
+            search_beatles().with_filter(EpisodesFilter),
+            [
+                "search_episodes_20231226.json",
+                "search_episodes_continuation_20231226.json",
+            ],
+        ),
+        paged_route(
+            search_beatles().with_filter(PodcastsFilter),
+            [
+                "search_podcasts_20231226.json",
+                "search_podcasts_continuation_20231226.json",
+            ],
+        ),
+        paged_route(
+            search_beatles().with_filter(ProfilesFilter),
+            [
+                "search_profiles_20231226.json",
+                "search_profiles_continuation_20231226.json",
+            ],
+        ),
+        paged_route(
+            search_beatles().with_filter(FeaturedPlaylistsFilter),
+            [
+                "search_featured_playlists_20231226.json",
+                "search_featured_playlists_continuation_20231226.json",
+            ],
+        ),
+        paged_route(
+            search_beatles().with_filter(CommunityPlaylistsFilter),
+            [
+                "search_community_playlists_20231226.json",
+                "search_community_playlists_continuation_20231226.json",
+            ],
+        ),
+        paged_route(
+            search_beatles().with_filter(PlaylistsFilter),
+            [
+                "search_playlists_20231228.json",
+                "search_playlists_continuation_20231228.json",
+            ],
+        ),
+        paged_route(search_beatles(), ["search_basic_top_result_20231228.json"]),
+        paged_route(
+            SearchQuery::new("Beaten"),
+            ["search_basic_no_top_result_20231228.json"],
+        ),
+        paged_route(
+            SearchQuery::new("Chasing scratch"),
+            ["search_basic_with_podcast_community_playlists_20250605.json"],
+        ),
+        paged_route(
+            SearchQuery::new("Metal"),
+            ["search_basic_radio_20240830.json"],
+        ),
+        paged_route(
+            SearchQuery::new("aaaaaaaaaaaaaaaaaaaabbbbbbbbbbbbbbbbbbbbbcccccccccccccccccc"),
+            ["search_basic_no_results_20240721.json"],
+        ),
+        paged_route(
+            GetSearchSuggestionsQuery::new("faded"),
+            ["get_search_suggestions_mock.json"],
+        ),
+        // Playlists and songs
+        paged_route(
+            GetPlaylistTracksQuery::new(beatles_playlist.clone()),
+            [
+                "get_playlist_20250604.json",
+                "get_playlist_continuation_20250604.json",
+            ],
+        ),
+        paged_route(
+            GetPlaylistDetailsQuery::new(beatles_playlist),
+            ["get_playlist_20250604.json"],
+        ),
+        paged_route(
+            GetWatchPlaylistQuery::new_from_video_id(VideoID::from_raw("9mWr4c_ig54")),
+            [
+                "get_watch_playlist_20250630.json",
+                "get_watch_playlist_continuation_20250630.json",
+            ],
+        ),
+        paged_route(
+            GetLyricsIDQuery::new(VideoID::from_raw("lYBUbBu4W08")),
+            ["get_watch_playlist_20250630.json"],
+        ),
+        paged_route(GetLyricsQuery::new(lyrics_id), ["get_lyrics_20231219.json"]),
+        // Artists, albums and users
+        paged_route(
+            GetArtistQuery::new(ArtistChannelID::from_raw("UC2XdaAVUannpujzv32jcouQ")),
+            ["get_artist_20250310.json"],
+        ),
+        paged_route(GetArtistQuery::new(metallica), ["get_artist_20240705.json"]),
+        paged_route(artist_albums_query, ["browse_artist_albums.json"]),
+        paged_route(
+            GetAlbumQuery::new(first_album_id),
+            ["get_album_20240724.json"],
+        ),
+        paged_route(
+            GetUserQuery::new(UserChannelID::from_raw("UCj0boSvCVfTmO9JHlclA8eQ")),
+            ["get_user_20250707.json"],
+        ),
+        // Podcasts
+        paged_route(
+            GetChannelQuery::new(PodcastChannelID::from_raw("UCzYLos4qc2oC4r0Efd-tSuw")),
+            ["get_channel_20240830.json"],
+        ),
+        paged_route(
+            GetPodcastQuery::new(PodcastID::from_raw(
+                "MPSPPLWnnGn_Lw9os50MbtFCouWYsArlq2s8ct",
+            )),
+            ["get_podcast_20240830.json"],
+        ),
+        paged_route(
+            GetEpisodeQuery::new(EpisodeID::from_raw("MPED2i5poDoWjFU")),
+            ["get_episode_20240830.json"],
+        ),
+        paged_route(GetNewEpisodesQuery, ["get_new_episodes_20240830.json"]),
+    ]
+    .into_iter()
+    .chain(rate_song_routes)
+    .chain(rate_playlist_routes)
+    .fold(Routes::new(), Routes::with_route)
+}